@@ -0,0 +1,341 @@
+//! Concurrent submission of many pre-signed transactions.
+//!
+//! [`Client::send_transactions`] submits a batch with bounded concurrency and
+//! returns one result per transaction, in input order:
+//!
+//! ```ignore
+//! let signed: Vec<SignedTransaction> = markets
+//!     .iter()
+//!     .map(|m| Transaction::builder().call_message(requote(m)).client(&client).build())
+//!     .collect::<SDKResult<_>>()?;
+//!
+//! let results = client.send_transactions(signed).concurrency(16).call().await;
+//! for result in results {
+//!     if let Err(e) = result {
+//!         eprintln!("requote failed: {e}");
+//!     }
+//! }
+//! ```
+//!
+//! Transactions using [`UniquenessData::Nonce`] must reach the rollup in nonce
+//! order, so those sharing a signer are submitted one after another in the
+//! order given. Everything else (the default `Window` uniqueness, and
+//! `Generation`) is submitted independently.
+
+use std::collections::HashMap;
+
+use bon::bon;
+use bullet_exchange_interface::transaction::{
+    Transaction as SignedTransaction, UniquenessData, UnsignedTransaction as RawUnsignedTransaction,
+};
+use futures::StreamExt;
+
+use crate::generated::types::SubmitTxResponse;
+use crate::{Client, SDKError, SDKResult, Transaction, UnsignedTransaction};
+
+/// Default number of transactions in flight at once.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Transactions that must be submitted sequentially, tagged with their
+/// position in the input.
+type Lane = Vec<(usize, SignedTransaction)>;
+
+#[bon]
+impl Client {
+    /// Submit many signed transactions with bounded concurrency.
+    ///
+    /// Returns one result per transaction, in input order; a failure never
+    /// stops the rest of the batch. Nonce-based transactions from the same
+    /// signer are sent sequentially in the given order; all others are sent
+    /// concurrently.
+    ///
+    /// If a transaction comes back as [`SDKError::TransactionOutdated`] (the
+    /// chain hash changed since it was signed) and it was signed by the
+    /// client's signer, it is re-signed against the refreshed chain hash —
+    /// keeping its call, uniqueness and fees — and submitted once more.
+    /// Transactions signed by other keys are reported as `TransactionOutdated`
+    /// for the caller to rebuild.
+    #[builder]
+    pub async fn send_transactions<I>(
+        &self,
+        #[builder(start_fn)] transactions: I,
+        /// Maximum transactions in flight at once. Default: 8.
+        concurrency: Option<usize>,
+    ) -> Vec<SDKResult<SubmitTxResponse>>
+    where
+        I: IntoIterator<Item = SignedTransaction>,
+    {
+        let concurrency = concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+
+        let mut results: Vec<_> = futures::stream::iter(into_lanes(transactions))
+            .map(|lane| self.send_lane(lane))
+            .buffer_unordered(concurrency)
+            .flat_map(futures::stream::iter)
+            .collect()
+            .await;
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+impl Client {
+    async fn send_lane(&self, lane: Lane) -> Vec<(usize, SDKResult<SubmitTxResponse>)> {
+        let mut results = Vec::with_capacity(lane.len());
+        for (index, signed) in lane {
+            results.push((index, self.send_or_resign(signed).await));
+        }
+        results
+    }
+
+    async fn send_or_resign(&self, signed: SignedTransaction) -> SDKResult<SubmitTxResponse> {
+        match self.send_transaction(&signed).await {
+            Err(SDKError::TransactionOutdated) => match self.resign(signed).await {
+                Some(resigned) => self.submit_resigned(&resigned?).await,
+                None => Err(SDKError::TransactionOutdated),
+            },
+            other => other,
+        }
+    }
+
+    /// Re-sign `signed` against the current chain hash, if it was signed by
+    /// the client's signer and passes the client's signing policy.
+    async fn resign(&self, signed: SignedTransaction) -> Option<SDKResult<SignedTransaction>> {
+        let signer = self.signer()?;
+        let SignedTransaction::V0(version_0) = signed else {
+            return None;
+        };
+        if signer.pubkey() != version_0.pub_key {
+            return None;
+        }
+        let unsigned = UnsignedTransaction {
+            inner: RawUnsignedTransaction {
+                runtime_call: version_0.runtime_call,
+                uniqueness: version_0.uniqueness,
+                details: version_0.details,
+            },
+            chain_hash: self.chain_hash(),
+            chain_name: self.chain_name(),
+        };
+        if let Err(e) = self.check_signing_policy(&unsigned) {
+            return Some(Err(e));
+        }
+        Some(Transaction::sign_async(unsigned, signer).await)
+    }
+}
+
+/// Split transactions into lanes: one per nonce-using signer, and one per
+/// transaction otherwise.
+fn into_lanes(transactions: impl IntoIterator<Item = SignedTransaction>) -> Vec<Lane> {
+    let mut lanes: Vec<Lane> = Vec::new();
+    let mut nonce_lanes: HashMap<[u8; 32], usize> = HashMap::new();
+    for (index, signed) in transactions.into_iter().enumerate() {
+        let nonce_signer = match &signed {
+            SignedTransaction::V0(v0) if matches!(v0.uniqueness, UniquenessData::Nonce(_)) => {
+                Some(v0.pub_key)
+            }
+            _ => None,
+        };
+        match nonce_signer {
+            Some(pub_key) => {
+                let lane = *nonce_lanes.entry(pub_key).or_insert_with(|| {
+                    lanes.push(Vec::new());
+                    lanes.len() - 1
+                });
+                lanes[lane].push((index, signed));
+            }
+            None => lanes.push(vec![(index, signed)]),
+        }
+    }
+    lanes
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_exchange_interface::message::PublicAction;
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+    use super::*;
+    use crate::testing::{decode_submitted, mock_client, mount_chain_mocks, mount_connect_mocks};
+    use crate::types::CallMessage;
+    use crate::{Keypair, SigningPolicy};
+
+    /// Accepts a submitted transaction only if its signature is valid for
+    /// chain hash `[chain_byte; 32]`, like the rollup does.
+    struct VerifyingSubmit {
+        chain_byte: u8,
+    }
+
+    impl Respond for VerifyingSubmit {
+        fn respond(&self, request: &Request) -> ResponseTemplate {
+            let SignedTransaction::V0(v0) = decode_submitted(request) else {
+                unreachable!()
+            };
+            let unsigned = UnsignedTransaction {
+                inner: RawUnsignedTransaction {
+                    runtime_call: v0.runtime_call,
+                    uniqueness: v0.uniqueness,
+                    details: v0.details,
+                },
+                chain_hash: [self.chain_byte; 32],
+                chain_name: String::new(),
+            };
+            let key = VerifyingKey::from_bytes(&v0.pub_key).unwrap();
+            let signature = Signature::from_bytes(&v0.signature);
+            if key
+                .verify(&unsigned.to_bytes().unwrap(), &signature)
+                .is_ok()
+            {
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "id": "0xtx",
+                    "status": "processed",
+                    "events": [],
+                }))
+            } else {
+                ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                    "status": 401,
+                    "message": "Invalid signature",
+                }))
+            }
+        }
+    }
+
+    async fn client_with_keypair(server: &MockServer, keypair: Keypair) -> Client {
+        Client::builder()
+            .network(server.uri())
+            .keypair(keypair)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    fn signed(client: &Client, signer: &Keypair, uniqueness: UniquenessData) -> SignedTransaction {
+        Transaction::builder()
+            .call_message(CallMessage::Public(PublicAction::ApplyFunding {
+                addresses: vec![],
+            }))
+            .uniqueness(uniqueness)
+            .signer(signer)
+            .client(client)
+            .build()
+            .unwrap()
+    }
+
+    async fn mount_submit(server: &MockServer, chain_byte: u8) {
+        Mock::given(method("POST"))
+            .and(path("/tx/submit"))
+            .respond_with(VerifyingSubmit { chain_byte })
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn nonce_transactions_from_one_signer_are_sent_in_order() {
+        let server = MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        mount_submit(&server, 7).await;
+        let keypair = Keypair::generate();
+        let client = client_with_keypair(&server, keypair.clone()).await;
+        let transactions: Vec<_> = (0..6)
+            .map(|nonce| signed(&client, &keypair, UniquenessData::Nonce(nonce)))
+            .collect();
+
+        let results = client.send_transactions(transactions).call().await;
+
+        assert_eq!(results.len(), 6);
+        assert!(results.iter().all(Result::is_ok));
+        let nonces: Vec<_> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/tx/submit")
+            .map(|request| match decode_submitted(request) {
+                SignedTransaction::V0(v0) => v0.uniqueness,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            nonces,
+            (0..6).map(UniquenessData::Nonce).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn lanes_group_nonce_transactions_by_signer_only() {
+        let (_server, client) = mock_client().await;
+        let alice = Keypair::generate();
+        let bob = Keypair::generate();
+
+        let lanes = into_lanes([
+            signed(&client, &alice, UniquenessData::Nonce(0)),
+            signed(&client, &alice, UniquenessData::Window(1)),
+            signed(&client, &bob, UniquenessData::Nonce(0)),
+            signed(&client, &alice, UniquenessData::Nonce(1)),
+            signed(&client, &alice, UniquenessData::Window(2)),
+        ]);
+
+        let indices: Vec<Vec<usize>> = lanes
+            .iter()
+            .map(|lane| lane.iter().map(|(index, _)| *index).collect())
+            .collect();
+        assert_eq!(indices, vec![vec![0, 3], vec![1], vec![2], vec![4]]);
+    }
+
+    #[tokio::test]
+    async fn outdated_transactions_are_resigned_only_if_ours() {
+        let server = MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        let keypair = Keypair::generate();
+        let client = client_with_keypair(&server, keypair.clone()).await;
+        let ours = signed(&client, &keypair, UniquenessData::Window(1));
+        let theirs = signed(&client, &Keypair::generate(), UniquenessData::Window(2));
+        // The chain hash moves on after signing.
+        server.reset().await;
+        mount_chain_mocks(&server, 9, serde_json::json!([])).await;
+        mount_submit(&server, 9).await;
+
+        let results = client
+            .send_transactions([ours, theirs])
+            .concurrency(1)
+            .call()
+            .await;
+
+        assert!(results[0].is_ok(), "{:?}", results[0]);
+        assert!(matches!(results[1], Err(SDKError::TransactionOutdated)));
+        assert_eq!(client.chain_hash(), [9; 32]);
+    }
+
+    #[tokio::test]
+    async fn resigning_checks_the_signing_policy() {
+        let server = MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        let keypair = Keypair::generate();
+        let unchecked = client_with_keypair(&server, keypair.clone()).await;
+        let client = Client::builder()
+            .network(server.uri())
+            .keypair(keypair.clone())
+            .signing_policy(
+                SigningPolicy::builder()
+                    .allowed_calls(vec!["User".into()])
+                    .build(),
+            )
+            .build()
+            .await
+            .unwrap();
+        // Signed outside the policy-checked client.
+        let funding = signed(&unchecked, &keypair, UniquenessData::Window(1));
+        server.reset().await;
+        mount_chain_mocks(&server, 9, serde_json::json!([])).await;
+        mount_submit(&server, 9).await;
+
+        let results = client.send_transactions([funding]).call().await;
+
+        assert!(
+            matches!(results[0], Err(SDKError::PolicyViolation(_))),
+            "{:?}",
+            results[0]
+        );
+    }
+}
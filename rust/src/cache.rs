@@ -0,0 +1,232 @@
+//! On-disk cache of connect-time chain data and exchange metadata.
+//!
+//! [`Client::builder`](crate::Client::builder) normally fetches and validates
+//! `/rollup/schema` and fetches `exchange_info` on every start. With a cache
+//! directory set, the result is persisted as JSON and reused:
+//!
+//! - **Within the TTL** the client connects with no network calls at all.
+//! - **After the TTL** the schema is fetched again; if its chain hash matches
+//!   the cached one, the (expensive) schema comparison is skipped. The cache
+//!   is rewritten with fresh exchange info either way.
+//!
+//! Entries are keyed by the network URLs, the `user_actions` filter, and the
+//! SDK's compiled schema, so upgrading the SDK or changing the filter never
+//! reuses a stale validation result. Cache I/O failures are logged and treated
+//! as a miss; they never fail client construction.
+
+use std::path::{Path, PathBuf};
+
+use bullet_exchange_interface::message::UserActionDiscriminants;
+use bullet_exchange_interface::schema::Schema;
+use bullet_exchange_interface::transaction::Transaction;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+use web_time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::ChainData;
+use crate::generated::types::Symbol;
+use crate::snapshot::chain_hash_hex;
+
+/// Default time a cache entry is reused without contacting the network.
+pub(crate) const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// A cache file location and its TTL.
+pub(crate) struct MetadataCache {
+    path: PathBuf,
+    ttl: Duration,
+}
+
+/// The persisted cache entry.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CachedChain {
+    /// Unix timestamp (seconds) when the entry was written.
+    fetched_at: u64,
+    chain_id: u64,
+    #[serde(with = "chain_hash_hex")]
+    pub(crate) chain_hash: [u8; 32],
+    chain_name: String,
+    pub(crate) symbols: Vec<Symbol>,
+}
+
+impl MetadataCache {
+    /// Locate the cache file for this connection inside `dir`.
+    ///
+    /// Returns `None` (no caching) if the local schema can't be derived for
+    /// the cache key.
+    pub(crate) fn new(
+        dir: &Path,
+        ttl: Duration,
+        urls: &[&str],
+        user_actions: &Option<Vec<UserActionDiscriminants>>,
+    ) -> Option<Self> {
+        let local_schema = Schema::of_single_type::<Transaction>().ok()?;
+        let local_schema = serde_json::to_vec(&local_schema).ok()?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(&local_schema);
+        for url in urls {
+            hasher.update(url);
+            hasher.update([0]);
+        }
+        hasher.update(format!("{user_actions:?}"));
+        let key = hex::encode(&hasher.finalize()[..16]);
+
+        Some(Self {
+            path: dir.join(format!("bullet-chain-{key}.json")),
+            ttl,
+        })
+    }
+
+    /// Read the cache entry, regardless of age.
+    pub(crate) fn load(&self) -> Option<CachedChain> {
+        let data = match std::fs::read_to_string(&self.path) {
+            Ok(data) => data,
+            Err(e) => {
+                debug!(path = %self.path.display(), error = %e, "metadata cache miss");
+                return None;
+            }
+        };
+        match serde_json::from_str(&data) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!(path = %self.path.display(), error = %e, "ignoring corrupt metadata cache");
+                None
+            }
+        }
+    }
+
+    /// Whether `entry` was written within the TTL.
+    pub(crate) fn is_fresh(&self, entry: &CachedChain) -> bool {
+        let age = now_secs().saturating_sub(entry.fetched_at);
+        Duration::from_secs(age) < self.ttl
+    }
+
+    /// Persist chain data and symbols, logging (not returning) any failure.
+    pub(crate) fn store(&self, chain_data: &ChainData, symbols: &[Symbol]) {
+        let entry = CachedChain {
+            fetched_at: now_secs(),
+            chain_id: chain_data.chain_id,
+            chain_hash: chain_data.chain_hash,
+            chain_name: chain_data.chain_name.clone(),
+            symbols: symbols.to_vec(),
+        };
+        let result = serde_json::to_string(&entry)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = self.path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                // Write-then-rename so a concurrent reader never sees a
+                // partially written file.
+                let tmp = self.path.with_extension("json.tmp");
+                std::fs::write(&tmp, json)?;
+                std::fs::rename(&tmp, &self.path)
+            });
+        if let Err(e) = result {
+            warn!(path = %self.path.display(), error = %e, "failed to write metadata cache");
+        }
+    }
+}
+
+impl CachedChain {
+    pub(crate) fn chain_data(&self) -> ChainData {
+        ChainData {
+            chain_hash: self.chain_hash,
+            chain_id: self.chain_id,
+            chain_name: self.chain_name.clone(),
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::MockServer;
+
+    use super::*;
+    use crate::Client;
+    use crate::testing::{mount_chain_mocks, mount_connect_mocks};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "bullet-cache-test-{name}-{}",
+            rand::random::<u64>()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn fresh_cache_connects_without_network() {
+        let dir = temp_dir("fresh");
+        let server = MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        let uri = server.uri();
+        Client::builder()
+            .network(uri.clone())
+            .cache_dir(dir.clone())
+            .build()
+            .await
+            .unwrap();
+        drop(server);
+
+        let client = Client::builder()
+            .network(uri)
+            .cache_dir(dir.clone())
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(client.chain_hash(), [7; 32]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn expired_cache_refetches_and_picks_up_new_chain_hash() {
+        let dir = temp_dir("expired");
+        let server = MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        Client::builder()
+            .network(server.uri())
+            .cache_dir(dir.clone())
+            .build()
+            .await
+            .unwrap();
+        server.reset().await;
+        mount_chain_mocks(&server, 9, serde_json::json!([])).await;
+
+        let client = Client::builder()
+            .network(server.uri())
+            .cache_dir(dir.clone())
+            .cache_ttl(Duration::ZERO)
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(client.chain_hash(), [9; 32]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cache_key_depends_on_user_actions() {
+        let dir = Path::new("/tmp");
+        let all = MetadataCache::new(dir, DEFAULT_CACHE_TTL, &["https://a"], &None).unwrap();
+        let some = MetadataCache::new(
+            dir,
+            DEFAULT_CACHE_TTL,
+            &["https://a"],
+            &Some(vec![UserActionDiscriminants::PlaceOrders]),
+        )
+        .unwrap();
+
+        assert_ne!(all.path, some.path);
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::Deref;
-use std::sync::Mutex;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use bon::bon;
 use bullet_exchange_interface::message::{
@@ -9,13 +9,15 @@ use bullet_exchange_interface::message::{
 };
 use bullet_exchange_interface::transaction::{Amount, Gas, PriorityFeeBips, bank, warp};
 use bullet_exchange_interface::types::MarketId;
-use url::Url;
-use web_time::{SystemTime, UNIX_EPOCH};
+use web_time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::cache::{DEFAULT_CACHE_TTL, MetadataCache};
+use crate::endpoints::{Endpoint, Endpoints};
 use crate::generated::Client as GeneratedClient;
 use crate::metadata::{ExchangeMetadata, SymbolInfo};
+use crate::snapshot::ChainSnapshot;
 use crate::types::CallMessage;
-use crate::{Keypair, SDKError, SDKResult};
+use crate::{Keypair, SDKError, SDKResult, Signer, SigningPolicy};
 
 /// The main trading API client for REST operations.
 ///
@@ -39,9 +41,10 @@ use crate::{Keypair, SDKError, SDKResult};
 /// let info = api.exchange_info().await?;
 /// ```
 pub struct Client {
-    rest_url: String,
-    ws_url: String,
-    generated_client: GeneratedClient,
+    /// REST/WebSocket endpoints in preference order, with the active one
+    /// tracked for failover. See [`Client::with_failover`]. Shared with
+    /// background tasks so they follow failovers.
+    pub(crate) endpoints: Arc<Endpoints>,
     pub(crate) ws_client: reqwest::Client,
     chain_id: u64,
     chain_hash: Mutex<[u8; 32]>,
@@ -63,9 +66,13 @@ pub struct Client {
     window_nonce: AtomicU64,
 
     keypair: Option<Keypair>,
+    /// Signs transactions: the explicit `signer`, else the keypair.
+    signer: Option<Arc<dyn Signer>>,
+    /// Checked before the client signs anything.
+    signing_policy: Option<SigningPolicy>,
 
-    // Exchange metadata (symbol lookups)
-    metadata: ExchangeMetadata,
+    // Exchange metadata (symbol lookups), shared with any `MetadataRefresher`
+    pub(crate) metadata: Arc<Mutex<ExchangeMetadata>>,
 
     // Transaction Options
     max_priority_fee_bips: PriorityFeeBips,
@@ -73,6 +80,8 @@ pub struct Client {
     max_fee: Amount,
     /// Optionally limit the number of gas to be used.
     gas_limit: Option<Gas>,
+    /// Reject orders on markets whose cached status isn't `TRADING`.
+    market_status_guard: bool,
 }
 
 /// Known network environments.
@@ -90,23 +99,58 @@ pub struct Client {
 ///
 /// // Custom URL (auto-converts via From<&str>)
 /// let client = Client::builder().network("https://custom.example.com").build().await?;
+///
+/// // Primary endpoint with ordered fallbacks
+/// let client = Client::builder()
+///     .network(Network::Mainnet.with_fallbacks(["https://backup.example.com"]))
+///     .build()
+///     .await?;
 /// ```
 #[derive(Debug, Clone)]
 pub enum Network {
     Mainnet,
     Testnet,
     Custom(String),
+    /// An ordered list of REST base URLs serving the same chain.
+    ///
+    /// The client verifies every endpoint reports the same chain hash and
+    /// chain ID, prefers the lowest-latency one, and fails over to the others
+    /// when it becomes unreachable.
+    Endpoints(Vec<String>),
 }
 
 impl Network {
     /// Get the REST API URL for this network.
+    ///
+    /// For [`Network::Endpoints`] this is the first (primary) URL, or an empty
+    /// string if the list is empty.
     pub fn url(&self) -> &str {
         match self {
             Network::Mainnet => "https://tradingapi.bullet.xyz",
             Network::Testnet => "https://tradingapi.testnet.bullet.xyz",
             Network::Custom(url) => url,
+            Network::Endpoints(urls) => urls.first().map(String::as_str).unwrap_or_default(),
+        }
+    }
+
+    /// All REST API URLs for this network, in the order they were given.
+    pub fn urls(&self) -> Vec<&str> {
+        match self {
+            Network::Endpoints(urls) => urls.iter().map(String::as_str).collect(),
+            _ => vec![self.url()],
         }
     }
+
+    /// Append fallback URLs to this network, producing [`Network::Endpoints`].
+    pub fn with_fallbacks<I, S>(self, fallbacks: I) -> Network
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut urls: Vec<String> = self.urls().into_iter().map(String::from).collect();
+        urls.extend(fallbacks.into_iter().map(Into::into));
+        Network::Endpoints(urls)
+    }
 }
 
 impl From<&str> for Network {
@@ -125,6 +169,12 @@ impl From<String> for Network {
     }
 }
 
+impl<S: Into<String>> From<Vec<S>> for Network {
+    fn from(urls: Vec<S>) -> Self {
+        Network::Endpoints(urls.into_iter().map(Into::into).collect())
+    }
+}
+
 pub struct ChainData {
     pub chain_hash: [u8; 32],
     pub chain_id: u64,
@@ -150,6 +200,14 @@ impl Client {
         max_fee: Option<Amount>,
         gas_limit: Option<Gas>,
         keypair: Option<Keypair>,
+        /// Sign transactions with any [`Signer`] (e.g. a remote service)
+        /// instead of `keypair`. Takes precedence over `keypair` if both are set.
+        signer: Option<Arc<dyn Signer>>,
+        /// Refuse to sign transactions this policy rejects, failing with
+        /// `SDKError::PolicyViolation`. Applies to everything the client signs
+        /// (`Transaction::builder`, `send_call_message` and the trading
+        /// helpers), whichever signer is used.
+        signing_policy: Option<SigningPolicy>,
         /// Restrict schema validation to specific `UserAction` variants.
         ///
         /// By default (`None`), the client validates every exchange `CallMessage`
@@ -163,39 +221,77 @@ impl Client {
         /// `UserAction` variants are rejected before signing because their schema branch
         /// was not validated at connect time.
         user_actions: Option<Vec<UserActionDiscriminants>>,
+        /// Directory for caching the validated schema result, chain data, and
+        /// exchange metadata across process starts. Off by default.
+        ///
+        /// Within `cache_ttl` the client connects without any network calls;
+        /// after it, a matching remote chain hash skips schema validation and
+        /// the entry is refreshed. Entries are keyed by network URLs,
+        /// `user_actions`, and the SDK's compiled schema. Cache I/O errors are
+        /// logged and never fail construction. Not supported on wasm.
+        #[builder(into)]
+        cache_dir: Option<std::path::PathBuf>,
+        /// How long a cache entry is reused without contacting the network.
+        ///
+        /// Default: 5 minutes. Ignored unless `cache_dir` is set.
+        cache_ttl: Option<Duration>,
+        /// Check the cached market status before placing or amending orders
+        /// and fail with `SDKError::MarketNotTrading` instead of submitting to a
+        /// halted market. Default: `true`.
+        ///
+        /// The status is only as fresh as the metadata; keep it current with
+        /// [`Client::metadata_refresher`].
+        market_status_guard: Option<bool>,
     ) -> SDKResult<Self> {
-        let url = network.url();
-        let parsed = Url::parse(url).map_err(|_| SDKError::InvalidNetworkUrl)?;
-
-        let (rest_url, ws_url) = match parsed.scheme() {
-            "https" => (url.to_string(), format!("wss://{}/ws", parsed.authority())),
-            "http" => (url.to_string(), format!("ws://{}/ws", parsed.authority())),
-            _ => return Err(SDKError::InvalidNetworkUrl),
-        };
+        let urls = network.urls();
+        if urls.is_empty() {
+            return Err(SDKError::InvalidNetworkUrl);
+        }
         let http_client = reqwest_client.unwrap_or_default();
-        let generated_client = GeneratedClient::new_with_client(&rest_url, http_client.clone());
-
-        // WebSocket requires HTTP/1.1 (HTTP/2 does not support the Upgrade mechanism).
-        // We always build a dedicated HTTP/1.1 client for WS, regardless of whether
-        // the caller supplied a custom reqwest client for REST.
-        #[cfg(not(target_arch = "wasm32"))]
-        let ws_client = reqwest::Client::builder().http1_only().build()?;
-        #[cfg(target_arch = "wasm32")]
-        let ws_client = reqwest::Client::new();
-
-        // fetch schema
-        let chain_data = Self::fetch_schema(&generated_client, &user_actions).await?;
+        let cache = cache_dir.as_deref().and_then(|dir| {
+            let ttl = cache_ttl.unwrap_or(DEFAULT_CACHE_TTL);
+            MetadataCache::new(dir, ttl, &urls, &user_actions)
+        });
+        let endpoints = urls
+            .into_iter()
+            .map(|url| Endpoint::new(url, &http_client))
+            .collect::<SDKResult<Vec<_>>>()?;
+
+        let ws_client = Self::ws_http_client()?;
+
+        let cached = cache.as_ref().and_then(MetadataCache::load);
+        let (endpoints, chain_data, metadata) = match (&cache, cached) {
+            (Some(cache), Some(entry)) if cache.is_fresh(&entry) => (
+                Endpoints::trusted(endpoints),
+                entry.chain_data(),
+                ExchangeMetadata::from_symbols(&entry.symbols),
+            ),
+            (_, entry) => {
+                // fetch schema from every endpoint, verify they agree, and rank by latency
+                let validated = entry.map(|e| e.chain_hash);
+                let (endpoints, chain_data) =
+                    Endpoints::connect(endpoints, &user_actions, validated).await?;
+
+                let symbols = endpoints
+                    .active()
+                    .client
+                    .exchange_info()
+                    .await?
+                    .into_inner()
+                    .symbols;
+                if let Some(cache) = &cache {
+                    cache.store(&chain_data, &symbols);
+                }
+                let metadata = ExchangeMetadata::from_symbols(&symbols);
+                (endpoints, chain_data, metadata)
+            }
+        };
 
         let max_priority_fee_bips = max_priority_fee_bips.unwrap_or(*MAX_PRIORITY_FEE_BIPS);
         let max_fee = max_fee.unwrap_or(*MAX_FEE);
 
-        let exchange_info = generated_client.exchange_info().await?;
-        let metadata = ExchangeMetadata::from_symbols(&exchange_info.into_inner().symbols);
-
         Ok(Self {
-            rest_url,
-            ws_url,
-            generated_client,
+            endpoints: Arc::new(endpoints),
             ws_client,
             chain_id: chain_data.chain_id,
             chain_hash: Mutex::new(chain_data.chain_hash),
@@ -205,11 +301,107 @@ impl Client {
             gas_limit,
             max_priority_fee_bips,
             max_fee,
+            signer: Self::default_signer(signer, &keypair),
+            signing_policy,
             keypair,
-            metadata,
+            metadata: Arc::new(Mutex::new(metadata)),
+            market_status_guard: market_status_guard.unwrap_or(true),
         })
     }
 
+    /// Create a Client from a [`ChainSnapshot`] without any network access.
+    ///
+    /// The snapshot's schema is validated against the compiled types exactly as
+    /// [`Client::builder`] validates the remote one, and must agree with the
+    /// snapshot's chain hash, chain ID, and chain name. The resulting client
+    /// can build, decode, and sign transactions offline; REST and WebSocket
+    /// calls target `snapshot.url` and fail if it is unreachable.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let snapshot = ChainSnapshot::read_from_file("chain.json")?;
+    /// let client = Client::offline().snapshot(snapshot).keypair(keypair).build()?;
+    /// let signed = Transaction::builder()
+    ///     .call_message(call_message)
+    ///     .client(&client)
+    ///     .build()?;
+    /// ```
+    #[builder(finish_fn = build)]
+    pub fn offline(
+        snapshot: ChainSnapshot,
+        max_priority_fee_bips: Option<PriorityFeeBips>,
+        max_fee: Option<Amount>,
+        gas_limit: Option<Gas>,
+        keypair: Option<Keypair>,
+        /// See [`Client::builder`]'s `signer`.
+        signer: Option<Arc<dyn Signer>>,
+        /// See [`Client::builder`]'s `signing_policy`.
+        signing_policy: Option<SigningPolicy>,
+        /// See [`Client::builder`]'s `user_actions`.
+        user_actions: Option<Vec<UserActionDiscriminants>>,
+        /// See [`Client::builder`]'s `market_status_guard`.
+        market_status_guard: Option<bool>,
+    ) -> SDKResult<Self> {
+        let chain_data = Self::chain_data_from_schema(&snapshot.schema, &user_actions, None)?;
+        if chain_data.chain_hash != snapshot.chain_hash {
+            return Err(SDKError::InvalidChainHash(
+                "snapshot chain hash does not match its schema".to_string(),
+            ));
+        }
+        if chain_data.chain_id != snapshot.chain_id {
+            return Err(SDKError::InvalidSchemaResponse("chain_id"));
+        }
+        if chain_data.chain_name != snapshot.chain_name {
+            return Err(SDKError::InvalidSchemaResponse("chain_name"));
+        }
+
+        let endpoint = Endpoint::new(&snapshot.url, &reqwest::Client::new())?;
+
+        Ok(Self {
+            endpoints: Arc::new(Endpoints::trusted(vec![endpoint])),
+            ws_client: Self::ws_http_client()?,
+            chain_id: chain_data.chain_id,
+            chain_hash: Mutex::new(chain_data.chain_hash),
+            chain_name: chain_data.chain_name,
+            user_actions,
+            window_nonce: AtomicU64::new(0),
+            gas_limit,
+            max_priority_fee_bips: max_priority_fee_bips.unwrap_or(*MAX_PRIORITY_FEE_BIPS),
+            max_fee: max_fee.unwrap_or(*MAX_FEE),
+            signer: Self::default_signer(signer, &keypair),
+            signing_policy,
+            keypair,
+            metadata: Arc::new(Mutex::new(ExchangeMetadata::from_symbols(
+                &snapshot.symbols,
+            ))),
+            market_status_guard: market_status_guard.unwrap_or(true),
+        })
+    }
+
+    /// The explicit signer if given, else one backed by `keypair`.
+    fn default_signer(
+        signer: Option<Arc<dyn Signer>>,
+        keypair: &Option<Keypair>,
+    ) -> Option<Arc<dyn Signer>> {
+        signer.or_else(|| {
+            keypair
+                .clone()
+                .map(|keypair| Arc::new(keypair) as Arc<dyn Signer>)
+        })
+    }
+
+    /// WebSocket requires HTTP/1.1 (HTTP/2 does not support the Upgrade mechanism).
+    /// We always build a dedicated HTTP/1.1 client for WS, regardless of whether
+    /// the caller supplied a custom reqwest client for REST.
+    fn ws_http_client() -> SDKResult<reqwest::Client> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let ws_client = reqwest::Client::builder().http1_only().build()?;
+        #[cfg(target_arch = "wasm32")]
+        let ws_client = reqwest::Client::new();
+        Ok(ws_client)
+    }
+
     /// Return the next value for the default `Window` uniqueness and advance the
     /// counter.
     ///
@@ -226,30 +418,36 @@ impl Client {
         self.window_nonce.fetch_add(1, Ordering::Relaxed)
     }
 
-    async fn fetch_schema(
+    pub(crate) async fn fetch_schema(
         generated_client: &GeneratedClient,
         user_actions: &Option<Vec<UserActionDiscriminants>>,
+        validated: Option<[u8; 32]>,
+    ) -> SDKResult<ChainData> {
+        let schema_obj = generated_client.schema().await?;
+        let obj = serde_json::to_value(schema_obj.into_inner())
+            .map_err(|_| SDKError::InvalidSchemaResponse("failed to serialize schema"))?;
+        Self::chain_data_from_schema(&obj, user_actions, validated)
+    }
+
+    /// Validate a `/rollup/schema` response body against the compiled types and
+    /// extract the chain data from it.
+    ///
+    /// `validated` is a chain hash whose schema is already known to pass (e.g.
+    /// from the metadata cache); a schema with that hash skips the comparison.
+    pub(crate) fn chain_data_from_schema(
+        schema: &serde_json::Value,
+        user_actions: &Option<Vec<UserActionDiscriminants>>,
+        validated: Option<[u8; 32]>,
     ) -> SDKResult<ChainData> {
         use bullet_exchange_interface::schema::{Schema, SchemaFile, trim_matching_variants};
         use bullet_exchange_interface::transaction::Transaction;
 
-        let schema_obj = generated_client.schema().await?;
-
-        // validate the remote schema
-        let obj = schema_obj.into_inner();
-        let sobj = serde_json::to_string(&obj)
+        // Round-trip through a string: `SchemaFile` doesn't deserialize from a
+        // `Value` directly.
+        let sobj = serde_json::to_string(schema)
             .map_err(|_| SDKError::InvalidSchemaResponse("failed to serialize schema"))?;
         let schema_file = serde_json::from_str::<SchemaFile>(&sobj)
             .map_err(|_| SDKError::InvalidSchemaResponse("failed to parse SchemaFile"))?;
-        let our_schema = Schema::of_single_type::<Transaction>()
-            .map_err(|_| SDKError::InvalidSchemaResponse("failed to derive local schema"))?;
-        let filter = |name: &str, variant: &str| {
-            Self::filter_variants(name, variant, user_actions.as_deref())
-        };
-        let (left, right) = trim_matching_variants(&our_schema, &schema_file.schema, &filter);
-        if left != right {
-            return Err(SDKError::SchemaOutdated);
-        }
 
         // get chain_hash
         let chain_hash_bytes = hex::decode(schema_file.chain_hash.replace("0x", ""))
@@ -258,6 +456,20 @@ impl Client {
         let chain_hash = chain_hash_bytes.try_into().map_err(|v: Vec<u8>| {
             SDKError::InvalidChainHash(format!("expected 32 bytes, got {}", v.len()))
         })?;
+
+        // validate the remote schema
+        if validated != Some(chain_hash) {
+            let our_schema = Schema::of_single_type::<Transaction>()
+                .map_err(|_| SDKError::InvalidSchemaResponse("failed to derive local schema"))?;
+            let filter = |name: &str, variant: &str| {
+                Self::filter_variants(name, variant, user_actions.as_deref())
+            };
+            let (left, right) = trim_matching_variants(&our_schema, &schema_file.schema, &filter);
+            if left != right {
+                return Err(SDKError::SchemaOutdated);
+            }
+        }
+
         let chain_id = schema_file.schema.chain_data().chain_id;
         let chain_name = schema_file.schema.chain_data().chain_name.clone();
         Ok(ChainData {
@@ -268,7 +480,10 @@ impl Client {
     }
 
     pub async fn update_schema(&self) -> SDKResult<()> {
-        let chain_data = Self::fetch_schema(self.client(), self.user_actions()).await?;
+        let user_actions = self.user_actions();
+        let chain_data = self
+            .with_failover(|api| async move { Self::fetch_schema(&api, user_actions, None).await })
+            .await?;
 
         // The expect is fine here as we just read and write the
         // object. We never hold a lock in code that can panic.
@@ -380,7 +595,7 @@ impl Client {
     /// Prefer using `Deref` (calling methods directly on `Client`)
     /// instead of this method.
     pub fn client(&self) -> &GeneratedClient {
-        &self.generated_client
+        &self.endpoints.active().client
     }
 
     /// Get the chain ID for this network.
@@ -407,25 +622,48 @@ impl Client {
         &self.user_actions
     }
 
-    /// The REST API URL.
+    /// The REST API URL of the active endpoint.
     pub fn url(&self) -> &str {
-        &self.rest_url
+        &self.endpoints.active().rest_url
     }
-    /// The websocket URL.
+    /// The websocket URL of the active endpoint.
     pub fn ws_url(&self) -> &str {
-        &self.ws_url
+        &self.endpoints.active().ws_url
+    }
+    /// All REST API URLs, in failover order.
+    pub fn endpoints(&self) -> Vec<&str> {
+        self.endpoints.rest_urls()
     }
 
     /// Get the default keypair for signing transactions.
+    ///
+    /// `None` if the client was built with only a [`signer`](Self::signer).
     pub fn keypair(&self) -> Option<&Keypair> {
         self.keypair.as_ref()
     }
 
+    /// Get the default signer for transactions: the one passed to the
+    /// builder's `signer`, else the keypair.
+    pub fn signer(&self) -> Option<&dyn Signer> {
+        self.signer.as_deref()
+    }
+
+    /// The policy checked before the client signs anything, if any.
+    pub fn signing_policy(&self) -> Option<&SigningPolicy> {
+        self.signing_policy.as_ref()
+    }
+
     /// Get the default max fee for transactions.
     pub fn max_fee(&self) -> Amount {
         self.max_fee
     }
 
+    /// Whether orders are checked against the cached market status before
+    /// submission. See [`Client::place_orders`].
+    pub fn market_status_guard(&self) -> bool {
+        self.market_status_guard
+    }
+
     /// Get the default max priority fee in basis points.
     pub fn max_priority_fee_bips(&self) -> PriorityFeeBips {
         self.max_priority_fee_bips
@@ -449,31 +687,30 @@ impl Client {
     /// client.place_orders(market_id, orders, false, None).await?;
     /// ```
     pub fn market_id(&self, symbol: &str) -> Option<MarketId> {
-        self.metadata.market_id(symbol)
+        self.metadata().market_id(symbol)
     }
 
     /// Get all available symbols and their metadata.
-    pub fn symbols(&self) -> &[SymbolInfo] {
-        self.metadata.symbols()
+    pub fn symbols(&self) -> Vec<SymbolInfo> {
+        self.metadata().symbols().to_vec()
     }
 
     /// Look up symbol info by [`MarketId`].
-    pub fn symbol_info(&self, market_id: MarketId) -> Option<&SymbolInfo> {
-        self.metadata.symbol_info_by_id(market_id)
+    pub fn symbol_info(&self, market_id: MarketId) -> Option<SymbolInfo> {
+        self.metadata().symbol_info_by_id(market_id).cloned()
     }
 
     /// Look up symbol info by name.
-    pub fn symbol_info_by_name(&self, symbol: &str) -> Option<&SymbolInfo> {
-        self.metadata.symbol_info_by_name(symbol)
+    pub fn symbol_info_by_name(&self, symbol: &str) -> Option<SymbolInfo> {
+        self.metadata().symbol_info_by_name(symbol).cloned()
     }
 
-    /// Re-fetch exchange metadata from the server.
-    ///
-    /// Call this in long-running bots to pick up newly listed markets.
-    pub async fn refresh_metadata(&mut self) -> SDKResult<()> {
-        let info = self.generated_client.exchange_info().await?;
-        self.metadata = ExchangeMetadata::from_symbols(&info.into_inner().symbols);
-        Ok(())
+    fn metadata(&self) -> std::sync::MutexGuard<'_, ExchangeMetadata> {
+        // The expect is fine here as we just read and write the
+        // object. We never hold a lock in code that can panic.
+        self.metadata
+            .lock()
+            .expect("Taking the metadata lock can never fail.")
     }
 }
 
@@ -510,6 +747,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn network_with_fallbacks_keeps_primary_first() {
+        let network = Network::Testnet.with_fallbacks(["https://a.example.com"]);
+
+        assert_eq!(
+            network.urls(),
+            vec![
+                "https://tradingapi.testnet.bullet.xyz",
+                "https://a.example.com"
+            ]
+        );
+        assert_eq!(network.url(), "https://tradingapi.testnet.bullet.xyz");
+    }
+
     #[test]
     fn default_schema_filter_keeps_all_call_message_groups() {
         for variant in ["User", "Vault", "Keeper", "Public", "Admin"] {
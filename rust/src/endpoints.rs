@@ -0,0 +1,551 @@
+//! Multi-endpoint failover for REST and WebSocket connections.
+//!
+//! A [`Network`](crate::Network) may resolve to several base URLs serving the
+//! same chain. On connect, every endpoint's schema is fetched concurrently:
+//! the endpoints that answer must agree on chain hash and chain ID, and are
+//! ranked by how quickly they answered. Endpoints that could not be reached
+//! are kept at the back of the list and verified the first time a failover
+//! lands on them.
+//!
+//! REST calls the SDK makes itself (schema and metadata refresh, account and
+//! order book queries) go through [`Client::with_failover`], which moves to
+//! the next endpoint when the active one fails at the transport level.
+//! Transaction submission only moves on when the connection could not be
+//! made, so a transaction is never sent twice.
+//! [`ManagedWebsocket`](crate::ManagedWebsocket) reconnects rotate through the
+//! verified endpoints the same way.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+use bullet_exchange_interface::message::UserActionDiscriminants;
+use futures::future::join_all;
+use tracing::{info, warn};
+use url::Url;
+use web_time::{Duration, Instant};
+
+use crate::client::ChainData;
+use crate::generated::Client as GeneratedClient;
+use crate::{Client, SDKError, SDKResult};
+
+/// Not yet confirmed to serve the client's chain.
+const UNVERIFIED: u8 = 0;
+/// Reported the same chain hash and chain ID as the client.
+const VERIFIED: u8 = 1;
+/// Reported a different chain or an incompatible schema; never used again.
+const REJECTED: u8 = 2;
+
+/// A single REST base URL with its derived WebSocket URL.
+pub(crate) struct Endpoint {
+    pub(crate) rest_url: String,
+    pub(crate) ws_url: String,
+    pub(crate) client: GeneratedClient,
+    state: AtomicU8,
+}
+
+impl Endpoint {
+    pub(crate) fn new(url: &str, http_client: &reqwest::Client) -> SDKResult<Self> {
+        let parsed = Url::parse(url).map_err(|_| SDKError::InvalidNetworkUrl)?;
+
+        let ws_url = match parsed.scheme() {
+            "https" => format!("wss://{}/ws", parsed.authority()),
+            "http" => format!("ws://{}/ws", parsed.authority()),
+            _ => return Err(SDKError::InvalidNetworkUrl),
+        };
+        Ok(Self {
+            rest_url: url.to_string(),
+            ws_url,
+            client: GeneratedClient::new_with_client(url, http_client.clone()),
+            state: AtomicU8::new(UNVERIFIED),
+        })
+    }
+
+    fn state(&self) -> u8 {
+        self.state.load(Ordering::Acquire)
+    }
+
+    fn set_state(&self, state: u8) {
+        self.state.store(state, Ordering::Release);
+    }
+}
+
+/// The client's endpoints in preference order plus the index of the active one.
+pub(crate) struct Endpoints {
+    list: Vec<Endpoint>,
+    active: AtomicUsize,
+}
+
+impl Endpoints {
+    /// Fetch the schema from every endpoint, check they serve the same chain,
+    /// and rank them by response latency.
+    ///
+    /// Endpoints that fail at the transport level are kept, unverified, behind
+    /// the reachable ones. Any other error (e.g. `SchemaOutdated`) or a chain
+    /// mismatch fails the whole connect. If no endpoint is reachable, the first
+    /// transport error is returned. `validated` is forwarded to
+    /// [`Client::chain_data_from_schema`].
+    pub(crate) async fn connect(
+        list: Vec<Endpoint>,
+        user_actions: &Option<Vec<UserActionDiscriminants>>,
+        validated: Option<[u8; 32]>,
+    ) -> SDKResult<(Endpoints, ChainData)> {
+        let probes = join_all(list.iter().map(|endpoint| async move {
+            let started = Instant::now();
+            let result = Client::fetch_schema(&endpoint.client, user_actions, validated).await;
+            (started.elapsed(), result)
+        }))
+        .await;
+
+        let mut chain: Option<(usize, ChainData)> = None;
+        let mut first_err = None;
+        let mut latencies = Vec::with_capacity(list.len());
+        for (index, (latency, result)) in probes.into_iter().enumerate() {
+            match result {
+                Ok(data) => {
+                    if let Some((reference, expected)) = &chain {
+                        check_same_chain(&list[index].rest_url, expected, &data).map_err(|e| {
+                            warn!(reference = %list[*reference].rest_url, "{e}");
+                            e
+                        })?;
+                    } else {
+                        chain = Some((index, data));
+                    }
+                    list[index].set_state(VERIFIED);
+                    latencies.push(latency);
+                }
+                Err(e) if fails_over(&e) => {
+                    warn!(url = %list[index].rest_url, error = %e, "endpoint unreachable");
+                    first_err.get_or_insert(e);
+                    latencies.push(Duration::MAX);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let Some((_, chain_data)) = chain else {
+            return Err(first_err.expect("an endpoint either answered or failed"));
+        };
+
+        // Stable sort: unreachable endpoints (Duration::MAX) keep their
+        // configured order behind the reachable ones.
+        let mut ranked: Vec<(Duration, Endpoint)> = latencies.into_iter().zip(list).collect();
+        ranked.sort_by_key(|(latency, _)| *latency);
+        let endpoints = Endpoints {
+            list: ranked.into_iter().map(|(_, endpoint)| endpoint).collect(),
+            active: AtomicUsize::new(0),
+        };
+        Ok((endpoints, chain_data))
+    }
+
+    /// Endpoints whose chain is already known (from a snapshot or the metadata
+    /// cache), without contacting them. The first is trusted and made active;
+    /// the rest are verified on first failover as usual.
+    pub(crate) fn trusted(list: Vec<Endpoint>) -> Endpoints {
+        list[0].set_state(VERIFIED);
+        Endpoints {
+            list,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn active(&self) -> &Endpoint {
+        &self.list[self.active_index()]
+    }
+
+    fn active_index(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    /// Move off `from` to the next endpoint that has not been rejected.
+    ///
+    /// Uses compare-and-swap so that concurrent calls failing on the same
+    /// endpoint only advance once; returns whichever endpoint is active
+    /// afterwards.
+    fn fail_over(&self, from: usize) -> usize {
+        let len = self.list.len();
+        let next = (1..len)
+            .map(|offset| (from + offset) % len)
+            .find(|&index| self.list[index].state() != REJECTED)
+            .unwrap_or(from);
+        match self
+            .active
+            .compare_exchange(from, next, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => {
+                if next != from {
+                    info!(from = %self.list[from].rest_url, to = %self.list[next].rest_url, "failing over");
+                }
+                next
+            }
+            Err(current) => current,
+        }
+    }
+
+    pub(crate) fn rest_urls(&self) -> Vec<&str> {
+        self.list.iter().map(|e| e.rest_url.as_str()).collect()
+    }
+
+    /// WebSocket URLs of the verified endpoints, starting from the active one.
+    ///
+    /// Never empty: connecting requires at least one verified endpoint and
+    /// verified endpoints are never demoted.
+    pub(crate) fn ws_urls(&self) -> Vec<String> {
+        let len = self.list.len();
+        let active = self.active_index();
+        (0..len)
+            .map(|offset| &self.list[(active + offset) % len])
+            .filter(|endpoint| endpoint.state() == VERIFIED)
+            .map(|endpoint| endpoint.ws_url.clone())
+            .collect()
+    }
+}
+
+/// Whether an error means the endpoint itself is unavailable, as opposed to
+/// the request being rejected, so the call should be retried elsewhere.
+fn fails_over(err: &SDKError) -> bool {
+    match err {
+        SDKError::HttpError(e) => e.is_request() || e.is_timeout(),
+        // Status 0 is a non-JSON error body, almost always an HTML page from a
+        // load balancer in front of a dead backend.
+        SDKError::ApiError(resp) => matches!(resp.status, 0 | 502..=504),
+        _ => false,
+    }
+}
+
+/// Whether the request never reached the endpoint.
+fn is_connect_error(err: &SDKError) -> bool {
+    match err {
+        #[cfg(not(target_arch = "wasm32"))]
+        SDKError::HttpError(e) => e.is_connect(),
+        _ => false,
+    }
+}
+
+fn check_same_chain(url: &str, expected: &ChainData, actual: &ChainData) -> SDKResult<()> {
+    let reason = if actual.chain_id != expected.chain_id {
+        format!("chain id {} != {}", actual.chain_id, expected.chain_id)
+    } else if actual.chain_hash != expected.chain_hash {
+        format!(
+            "chain hash 0x{} != 0x{}",
+            hex::encode(actual.chain_hash),
+            hex::encode(expected.chain_hash)
+        )
+    } else {
+        return Ok(());
+    };
+    Err(SDKError::EndpointMismatch {
+        url: url.to_string(),
+        reason,
+    })
+}
+
+impl Client {
+    /// Run a REST call against the active endpoint, failing over to the next
+    /// one if it is unreachable.
+    ///
+    /// The call is retried on each endpoint at most once, in preference order.
+    /// Only transport failures, `502`/`503`/`504` responses, and non-JSON error
+    /// bodies (see [`ApiErrorResponse::is_status_unknown`](crate::ApiErrorResponse::is_status_unknown))
+    /// fail over; any other error is returned as-is. An endpoint that was unreachable at
+    /// connect time has its chain hash and chain ID verified before the call
+    /// is sent to it, and is skipped permanently if they differ.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let book = client
+    ///     .with_failover(|api| async move { api.order_book(Some(10), "BTC-USD").await })
+    ///     .await?
+    ///     .into_inner();
+    /// ```
+    pub async fn with_failover<T, E, F, Fut>(&self, call: F) -> SDKResult<T>
+    where
+        F: FnMut(GeneratedClient) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Into<SDKError>,
+    {
+        self.failover_on(fails_over, call).await
+    }
+
+    /// [`with_failover`](Self::with_failover) for calls that must not run
+    /// twice, such as transaction submission: only fails over when the
+    /// connection could not be made. After a timeout or gateway error the
+    /// request may already have been processed, so the error is returned
+    /// rather than resent elsewhere, where it would come back as a duplicate.
+    pub(crate) async fn with_submit_failover<T, E, F, Fut>(&self, call: F) -> SDKResult<T>
+    where
+        F: FnMut(GeneratedClient) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Into<SDKError>,
+    {
+        self.failover_on(is_connect_error, call).await
+    }
+
+    async fn failover_on<T, E, F, Fut>(
+        &self,
+        call_fails_over: fn(&SDKError) -> bool,
+        mut call: F,
+    ) -> SDKResult<T>
+    where
+        F: FnMut(GeneratedClient) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Into<SDKError>,
+    {
+        let mut index = self.endpoints.active_index();
+        let mut last_err = None;
+        for _ in 0..self.endpoints.list.len() {
+            let endpoint = &self.endpoints.list[index];
+            // A failed verification means the call itself was never sent.
+            let (result, should_fail_over) = match self.verify_endpoint(endpoint).await {
+                Ok(()) => (
+                    call(endpoint.client.clone()).await.map_err(Into::into),
+                    call_fails_over,
+                ),
+                Err(e) => (Err(e), fails_over as fn(&SDKError) -> bool),
+            };
+            match result {
+                Err(e) if should_fail_over(&e) || endpoint.state() == REJECTED => {
+                    warn!(url = %endpoint.rest_url, error = %e, "endpoint failed");
+                    last_err = Some(e);
+                    index = self.endpoints.fail_over(index);
+                }
+                other => return other,
+            }
+        }
+        Err(last_err.expect("at least one endpoint was tried"))
+    }
+
+    /// Re-measure latency to every verified endpoint via `/health` and make
+    /// the fastest one active.
+    ///
+    /// Connect-time ranking goes stale in long-running processes; call this
+    /// periodically to move back to a preferred endpoint after it recovers.
+    /// Returns the first error if no endpoint responds.
+    pub async fn probe_endpoints(&self) -> SDKResult<()> {
+        let probes = join_all(
+            self.endpoints
+                .list
+                .iter()
+                .enumerate()
+                .filter(|(_, endpoint)| endpoint.state() == VERIFIED)
+                .map(|(index, endpoint)| async move {
+                    let started = Instant::now();
+                    let result = endpoint.client.health().await;
+                    (index, started.elapsed(), result)
+                }),
+        )
+        .await;
+
+        let mut fastest: Option<(usize, Duration)> = None;
+        let mut first_err = None;
+        for (index, latency, result) in probes {
+            match result {
+                Ok(_) if fastest.is_none_or(|(_, best)| latency < best) => {
+                    fastest = Some((index, latency));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    first_err.get_or_insert(e);
+                }
+            }
+        }
+        match (fastest, first_err) {
+            (Some((index, latency)), _) => {
+                let previous = self.endpoints.active.swap(index, Ordering::AcqRel);
+                if previous != index {
+                    info!(url = %self.endpoints.list[index].rest_url, ?latency, "switched to faster endpoint");
+                }
+                Ok(())
+            }
+            (None, Some(e)) => Err(e.into()),
+            (None, None) => Ok(()),
+        }
+    }
+
+    /// Check an endpoint serves this client's chain before first use.
+    async fn verify_endpoint(&self, endpoint: &Endpoint) -> SDKResult<()> {
+        match endpoint.state() {
+            VERIFIED => return Ok(()),
+            REJECTED => {
+                return Err(SDKError::EndpointMismatch {
+                    url: endpoint.rest_url.clone(),
+                    reason: "previously rejected".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let expected = ChainData {
+            chain_hash: self.chain_hash(),
+            chain_id: self.chain_id(),
+            chain_name: self.chain_name(),
+        };
+        let result = Client::fetch_schema(&endpoint.client, self.user_actions(), None)
+            .await
+            .and_then(|data| check_same_chain(&endpoint.rest_url, &expected, &data));
+        match result {
+            Ok(()) => endpoint.set_state(VERIFIED),
+            Err(ref e) if fails_over(e) => {}
+            Err(ref e) => {
+                warn!(url = %endpoint.rest_url, error = %e, "rejecting endpoint");
+                endpoint.set_state(REJECTED);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::UserAction;
+    use crate::testing::{
+        mount_chain_mocks, mount_connect_mocks, mount_submit_ok, submit_count, two_endpoint_client,
+    };
+    use crate::types::CallMessage;
+
+    /// A URL nothing listens on, so requests fail with a connect error.
+    const UNREACHABLE: &str = "http://127.0.0.1:1";
+
+    #[tokio::test]
+    async fn connect_ranks_unreachable_endpoints_last() {
+        let server = MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+
+        let client = Client::builder()
+            .network(vec![UNREACHABLE.to_string(), server.uri()])
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(client.url(), server.uri());
+        assert_eq!(client.endpoints(), vec![server.uri().as_str(), UNREACHABLE]);
+    }
+
+    #[tokio::test]
+    async fn connect_rejects_endpoints_on_different_chains() {
+        let a = MockServer::start().await;
+        let b = MockServer::start().await;
+        mount_chain_mocks(&a, 7, serde_json::json!([])).await;
+        mount_chain_mocks(&b, 8, serde_json::json!([])).await;
+
+        let result = Client::builder()
+            .network(vec![a.uri(), b.uri()])
+            .build()
+            .await;
+
+        assert!(matches!(result, Err(SDKError::EndpointMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn connect_fails_when_no_endpoint_is_reachable() {
+        let result = Client::builder().network(vec![UNREACHABLE]).build().await;
+
+        assert!(matches!(result, Err(SDKError::HttpError(_))));
+    }
+
+    #[tokio::test]
+    async fn with_failover_moves_to_next_endpoint_on_gateway_error() {
+        let a = MockServer::start().await;
+        let b = MockServer::start().await;
+        mount_connect_mocks(&a, serde_json::json!([])).await;
+        mount_connect_mocks(&b, serde_json::json!([])).await;
+        let client = Client::builder()
+            .network(vec![a.uri(), b.uri()])
+            .build()
+            .await
+            .unwrap();
+        // Ranking depends on measured latency; work out which one won.
+        let (primary, backup) = if client.url() == a.uri() {
+            (&a, &b)
+        } else {
+            (&b, &a)
+        };
+        primary.reset().await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(502))
+            .mount(primary)
+            .await;
+
+        client
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await
+            .unwrap();
+
+        assert_eq!(client.url(), backup.uri());
+    }
+
+    #[tokio::test]
+    async fn with_failover_returns_non_transport_errors() {
+        let a = MockServer::start().await;
+        let b = MockServer::start().await;
+        mount_connect_mocks(&a, serde_json::json!([])).await;
+        mount_connect_mocks(&b, serde_json::json!([])).await;
+        let client = Client::builder()
+            .network(vec![a.uri(), b.uri()])
+            .build()
+            .await
+            .unwrap();
+        let active = client.url().to_string();
+        for server in [&a, &b] {
+            Mock::given(method("GET"))
+                .and(path("/fapi/v1/exchangeInfo"))
+                .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                    "status": 400,
+                    "message": "bad request",
+                })))
+                .with_priority(1)
+                .mount(server)
+                .await;
+        }
+
+        let err = client
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.api_error().map(|e| e.status), Some(400));
+        assert_eq!(client.url(), active);
+    }
+
+    fn cancel_all() -> CallMessage {
+        CallMessage::User(UserAction::CancelAllOrders {
+            sub_account_index: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn submissions_do_not_fail_over_after_a_gateway_timeout() {
+        let (primary, backup, client) = two_endpoint_client().await;
+        mount_submit_ok(&backup).await;
+        Mock::given(method("POST"))
+            .and(path("/tx/submit"))
+            .respond_with(ResponseTemplate::new(504))
+            .mount(&primary)
+            .await;
+
+        let err = client.send_call_message(cancel_all()).await.unwrap_err();
+
+        assert_eq!(err.api_error().map(|e| e.status), Some(504));
+        assert_eq!(submit_count(&backup).await, 0);
+        assert_eq!(client.url(), primary.uri());
+    }
+
+    #[tokio::test]
+    async fn submissions_fail_over_when_the_endpoint_is_unreachable() {
+        let (primary, backup, client) = two_endpoint_client().await;
+        mount_submit_ok(&backup).await;
+        drop(primary);
+        // Let the client notice its pooled connection was closed.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        client.send_call_message(cancel_all()).await.unwrap();
+
+        assert_eq!(submit_count(&backup).await, 1);
+        assert_eq!(client.url(), backup.uri());
+    }
+}
@@ -0,0 +1,487 @@
+//! Portable envelopes for handing unsigned transactions to an offline signer.
+//!
+//! A [`TransactionEnvelope`] is a versioned JSON document carrying
+//! everything an air-gapped signer needs to review and sign a transaction
+//! without a network connection, and everything the online side needs to
+//! submit it afterwards:
+//!
+//! ```ignore
+//! // Online: build and export
+//! let unsigned = UnsignedTransaction::builder()
+//!     .call_message(call_msg)
+//!     .client(&client)
+//!     .build()?;
+//! unsigned.to_envelope(SigningFormat::Plain, &client)?.write_to_file("tx.json")?;
+//!
+//! // Offline: review the decoded transaction (not the sender's summary) and sign
+//! let mut envelope = TransactionEnvelope::read_from_file("tx.json")?;
+//! let symbols = offline_client.symbols();
+//! println!("{}", envelope.unsigned()?.summary(&symbols));
+//! envelope.sign(&keypair)?;
+//! envelope.write_to_file("tx.json")?;
+//!
+//! // Online: submit
+//! let envelope = TransactionEnvelope::read_from_file("tx.json")?;
+//! client.send_envelope(&envelope).await?;
+//! ```
+//!
+//! The envelope is checked whenever it is decoded: the transaction must be
+//! bound to the chain the envelope names and the signable bytes must be the
+//! ones the transaction produces in the chosen [`SigningFormat`], so an
+//! edited envelope is rejected rather than signed. The `summary` field is
+//! written by the sender and never checked, so reviewers should show the
+//! summary recomputed from [`TransactionEnvelope::unsigned`] instead.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bullet_exchange_interface::address::Address;
+use serde::{Deserialize, Serialize};
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use crate::generated::types::SubmitTxResponse;
+use crate::snapshot::chain_hash_hex;
+use crate::{
+    Client, MultisigConfig, SDKError, SDKResult, Signer, SolanaLedgerMultisigTransaction,
+    SolanaLedgerTransaction, SolanaOffchainTransaction, Transaction, UnsignedTransaction,
+};
+
+/// The envelope version this SDK writes and reads.
+pub const ENVELOPE_VERSION: u32 = 1;
+
+/// Which bytes the signer signs, and how the result is submitted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SigningFormat {
+    /// [`UnsignedTransaction::to_bytes`], submitted as a
+    /// [`SignedTransaction`](crate::SignedTransaction).
+    Plain,
+    /// [`UnsignedTransaction::to_message_bytes`], submitted as a
+    /// [`SolanaOffchainTransaction`].
+    SolanaOffchain,
+    /// [`UnsignedTransaction::to_ledger_signable_bytes`] for `pubkey`,
+    /// submitted as a [`SolanaLedgerTransaction`].
+    Ledger { pubkey: Address },
+    /// [`UnsignedTransaction::to_ledger_multisig_signable_bytes`], submitted
+    /// as a [`SolanaLedgerMultisigTransaction`] once `min_signers` have
+    /// signed.
+    Multisig {
+        min_signers: u8,
+        pubkeys: Vec<Address>,
+    },
+}
+
+impl SigningFormat {
+    fn multisig_config(&self) -> SDKResult<Option<MultisigConfig>> {
+        match self {
+            SigningFormat::Multisig {
+                min_signers,
+                pubkeys,
+            } => Ok(Some(MultisigConfig::new(
+                *min_signers,
+                pubkeys.iter().map(|pubkey| pubkey.0).collect(),
+            )?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn signable_bytes(&self, unsigned: &UnsignedTransaction) -> SDKResult<Vec<u8>> {
+        match self {
+            SigningFormat::Plain => unsigned.to_bytes(),
+            SigningFormat::SolanaOffchain => unsigned.to_message_bytes(),
+            SigningFormat::Ledger { pubkey } => unsigned.to_ledger_signable_bytes(&pubkey.0),
+            SigningFormat::Multisig { .. } => {
+                let config = self.multisig_config()?.expect("multisig format");
+                unsigned.to_ledger_multisig_signable_bytes(&config)
+            }
+        }
+    }
+}
+
+/// One signer's signature over [`TransactionEnvelope::signable`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvelopeSignature {
+    pub pubkey: Address,
+    #[serde(with = "base64_bytes")]
+    pub signature: [u8; 64],
+}
+
+/// A versioned, self-describing unsigned transaction.
+///
+/// Serializes to JSON with byte fields base64-encoded and the chain hash as
+/// a `0x`-prefixed hex string. Create with
+/// [`UnsignedTransaction::to_envelope`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionEnvelope {
+    /// Always [`ENVELOPE_VERSION`] for envelopes this SDK accepts.
+    pub version: u32,
+    pub chain_id: u64,
+    pub chain_name: String,
+    #[serde(with = "chain_hash_hex")]
+    pub chain_hash: [u8; 32],
+    pub format: SigningFormat,
+    /// The transaction as [`UnsignedTransaction::to_bytes`].
+    #[serde(with = "base64_bytes")]
+    pub transaction: Vec<u8>,
+    /// The exact bytes to sign in `format`.
+    #[serde(with = "base64_bytes")]
+    pub signable: Vec<u8>,
+    /// One-line description, as [`UnsignedTransaction::summary`], written by
+    /// the sender and not authenticated. Review
+    /// `envelope.unsigned()?.summary(&symbols)` before signing.
+    pub summary: String,
+    /// When the envelope was created, in unix seconds.
+    pub created_at: u64,
+    /// Signatures collected so far.
+    #[serde(default)]
+    pub signatures: Vec<EnvelopeSignature>,
+}
+
+impl UnsignedTransaction {
+    /// Wrap this transaction in an envelope for an offline signer.
+    ///
+    /// `client` supplies the market symbols for the summary; an offline
+    /// client works too.
+    pub fn to_envelope(
+        &self,
+        format: SigningFormat,
+        client: &Client,
+    ) -> SDKResult<TransactionEnvelope> {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| SDKError::SystemTimeError)?
+            .as_secs();
+        Ok(TransactionEnvelope {
+            version: ENVELOPE_VERSION,
+            chain_id: self.details().chain_id,
+            chain_name: self.chain_name().to_string(),
+            chain_hash: self.chain_hash(),
+            signable: format.signable_bytes(self)?,
+            format,
+            transaction: self.to_bytes()?,
+            summary: client.summarize(self),
+            created_at,
+            signatures: Vec::new(),
+        })
+    }
+
+    /// Rebuild the transaction from an envelope, without a client.
+    ///
+    /// Fails with [`SDKError::InvalidEnvelope`] if the envelope's version is
+    /// unsupported, the transaction is bound to a different chain than the
+    /// envelope names, or `signable` is not what the transaction produces in
+    /// the envelope's format.
+    pub fn from_envelope(envelope: &TransactionEnvelope) -> SDKResult<UnsignedTransaction> {
+        if envelope.version != ENVELOPE_VERSION {
+            return Err(SDKError::InvalidEnvelope(format!(
+                "unsupported version {} (expected {ENVELOPE_VERSION})",
+                envelope.version
+            )));
+        }
+        let unsigned = UnsignedTransaction::decode_bytes(&envelope.transaction)?;
+        if unsigned.chain_hash != envelope.chain_hash {
+            return Err(SDKError::InvalidEnvelope(
+                "transaction is bound to a different chain hash".to_string(),
+            ));
+        }
+        if unsigned.details().chain_id != envelope.chain_id {
+            return Err(SDKError::InvalidEnvelope(format!(
+                "transaction is for chain id {}, envelope says {}",
+                unsigned.details().chain_id,
+                envelope.chain_id
+            )));
+        }
+        let unsigned = UnsignedTransaction {
+            chain_name: envelope.chain_name.clone(),
+            ..unsigned
+        };
+        if envelope.format.signable_bytes(&unsigned)? != envelope.signable {
+            return Err(SDKError::InvalidEnvelope(
+                "signable bytes do not match the transaction".to_string(),
+            ));
+        }
+        Ok(unsigned)
+    }
+}
+
+impl TransactionEnvelope {
+    /// The transaction inside, after checking the envelope is consistent.
+    /// See [`UnsignedTransaction::from_envelope`].
+    pub fn unsigned(&self) -> SDKResult<UnsignedTransaction> {
+        UnsignedTransaction::from_envelope(self)
+    }
+
+    /// Sign [`signable`](Self::signable) with `signer` and record the
+    /// signature.
+    ///
+    /// Checks the envelope first, so a tampered envelope is never signed.
+    /// Fails with [`SDKError::AsyncSignerRequired`] for signers that only
+    /// sign asynchronously; use [`sign_async`](Self::sign_async) for those.
+    pub fn sign(&mut self, signer: &dyn Signer) -> SDKResult<()> {
+        self.unsigned()?;
+        let signature = signer.sign_message(&self.signable)?;
+        self.add_signature(signer.pubkey(), signature)
+    }
+
+    /// Like [`sign`](Self::sign), awaiting the signer's async flavour.
+    pub async fn sign_async(&mut self, signer: &dyn Signer) -> SDKResult<()> {
+        self.unsigned()?;
+        let signature = signer.sign_message_async(&self.signable).await?;
+        self.add_signature(signer.pubkey(), signature)
+    }
+
+    /// Record a signature produced elsewhere.
+    ///
+    /// The signature must verify against [`signable`](Self::signable), and
+    /// the signer must fit the format: the configured key for
+    /// [`SigningFormat::Ledger`], a member of the set for
+    /// [`SigningFormat::Multisig`], and the only signer otherwise.
+    pub fn add_signature(&mut self, pubkey: [u8; 32], signature: [u8; 64]) -> SDKResult<()> {
+        let signer = Address(pubkey);
+        let allowed = match &self.format {
+            SigningFormat::Plain | SigningFormat::SolanaOffchain => self.signatures.is_empty(),
+            SigningFormat::Ledger { pubkey } => *pubkey == signer && self.signatures.is_empty(),
+            SigningFormat::Multisig { pubkeys, .. } => {
+                pubkeys.contains(&signer) && self.signatures.iter().all(|s| s.pubkey != signer)
+            }
+        };
+        if !allowed {
+            return Err(SDKError::InvalidEnvelope(format!(
+                "{signer} cannot add a signature to this envelope"
+            )));
+        }
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&pubkey)
+            .map_err(|e| SDKError::InvalidSignature(e.to_string()))?;
+        verifying_key
+            .verify_strict(
+                &self.signable,
+                &ed25519_dalek::Signature::from_bytes(&signature),
+            )
+            .map_err(|_| {
+                SDKError::InvalidSignature(format!(
+                    "signature from {signer} does not match the signable bytes"
+                ))
+            })?;
+        self.signatures.push(EnvelopeSignature {
+            pubkey: signer,
+            signature,
+        });
+        Ok(())
+    }
+
+    /// Serialize the envelope to a JSON string.
+    pub fn to_json(&self) -> SDKResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Parse an envelope from a JSON string.
+    pub fn from_json(json: &str) -> SDKResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write the envelope to a JSON file.
+    pub fn write_to_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json)
+    }
+
+    /// Read an envelope from a JSON file written by
+    /// [`write_to_file`](Self::write_to_file).
+    pub fn read_from_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        serde_json::from_str(&data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// The single signature, for formats that take one.
+    fn only_signature(&self) -> SDKResult<&EnvelopeSignature> {
+        self.signatures
+            .first()
+            .ok_or_else(|| SDKError::InvalidEnvelope("envelope is not signed".to_string()))
+    }
+}
+
+impl Client {
+    /// Assemble a signed envelope in its format and submit it.
+    ///
+    /// The envelope must be for this client's chain and carry its
+    /// signature(s) (for multisig, at least `min_signers`).
+    pub async fn send_envelope(
+        &self,
+        envelope: &TransactionEnvelope,
+    ) -> SDKResult<SubmitTxResponse> {
+        let unsigned = envelope.unsigned()?;
+        if envelope.chain_hash != self.chain_hash() {
+            return Err(SDKError::InvalidChainHash(
+                "envelope does not match the connected client (built for a different network?)"
+                    .to_string(),
+            ));
+        }
+        match &envelope.format {
+            SigningFormat::Plain => {
+                let EnvelopeSignature { pubkey, signature } = envelope.only_signature()?;
+                let signed = Transaction::from_parts(unsigned, *signature, pubkey.0);
+                self.send_transaction(&signed).await
+            }
+            SigningFormat::SolanaOffchain => {
+                let EnvelopeSignature { pubkey, signature } = envelope.only_signature()?;
+                let signed = SolanaOffchainTransaction::from_parts(unsigned, *signature, pubkey.0)?;
+                self.send_offchain_transaction(&signed).await
+            }
+            SigningFormat::Ledger { pubkey } => {
+                let EnvelopeSignature { signature, .. } = envelope.only_signature()?;
+                let signed = SolanaLedgerTransaction::from_parts(unsigned, pubkey.0, *signature)?;
+                self.send_ledger_transaction(&signed).await
+            }
+            SigningFormat::Multisig { .. } => {
+                let config = envelope.format.multisig_config()?.expect("multisig format");
+                let mut signed = SolanaLedgerMultisigTransaction::new(unsigned, config)?;
+                for EnvelopeSignature { pubkey, signature } in &envelope.signatures {
+                    signed.add_signature(pubkey.0, *signature)?;
+                }
+                self.send_ledger_multisig_transaction(&signed).await
+            }
+        }
+    }
+}
+
+mod base64_bytes {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{BASE64, Engine};
+
+    pub(super) fn serialize<S: Serializer>(
+        bytes: impl AsRef<[u8]>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&BASE64.encode(bytes))
+    }
+
+    pub(super) fn deserialize<'de, D, T>(d: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<u8>>,
+    {
+        let bytes = BASE64
+            .decode(String::deserialize(d)?)
+            .map_err(D::Error::custom)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| D::Error::custom(format!("unexpected length {len}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_exchange_interface::message::UserAction;
+
+    use super::*;
+    use crate::testing::{decode_submitted, mock_signing_client, mount_submit_ok, unsigned};
+    use crate::types::CallMessage;
+    use crate::{Keypair, SignedTransaction};
+
+    fn cancel_all() -> CallMessage {
+        CallMessage::User(UserAction::CancelAllOrders {
+            sub_account_index: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn envelopes_round_trip_through_an_offline_signer() {
+        let (server, client) = mock_signing_client(vec![]).await;
+        mount_submit_ok(&server).await;
+        let keypair = Keypair::generate();
+
+        let envelope = unsigned(cancel_all(), &client)
+            .to_envelope(SigningFormat::Plain, &client)
+            .unwrap();
+        assert_eq!(envelope.summary, "Cancel all orders");
+        assert_eq!(envelope.chain_hash, client.chain_hash());
+        let json = envelope.to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["format"]["type"], "plain");
+
+        let mut offline = TransactionEnvelope::from_json(&json).unwrap();
+        offline.sign(&keypair).unwrap();
+        assert!(offline.sign(&keypair).is_err(), "only one signer");
+        let returned = TransactionEnvelope::from_json(&offline.to_json().unwrap()).unwrap();
+        assert_eq!(returned.signatures.len(), 1);
+
+        client.send_envelope(&returned).await.unwrap();
+        let requests = server.received_requests().await.unwrap();
+        let request = requests
+            .iter()
+            .find(|request| request.url.path() == "/tx/submit")
+            .unwrap();
+        let SignedTransaction::V0(v0) = decode_submitted(request) else {
+            unreachable!()
+        };
+        assert_eq!(v0.pub_key, keypair.pubkey());
+        assert!(client.send_envelope(&envelope).await.is_err(), "unsigned");
+    }
+
+    #[tokio::test]
+    async fn tampered_envelopes_are_rejected() {
+        let (_server, client) = mock_signing_client(vec![]).await;
+        let keypair = Keypair::generate();
+        let format = SigningFormat::Ledger {
+            pubkey: Address(keypair.pubkey()),
+        };
+        let unsigned = unsigned(cancel_all(), &client);
+        let envelope = unsigned.to_envelope(format, &client).unwrap();
+        assert_eq!(
+            envelope.signable,
+            unsigned
+                .to_ledger_signable_bytes(&keypair.pubkey())
+                .unwrap()
+        );
+
+        let mut wrong_chain = envelope.clone();
+        wrong_chain.chain_hash = [0; 32];
+        let mut wrong_signable = envelope.clone();
+        wrong_signable.signable[100] ^= 1;
+        let mut wrong_version = envelope.clone();
+        wrong_version.version = 2;
+        for mut tampered in [wrong_chain, wrong_signable, wrong_version] {
+            assert!(matches!(
+                tampered.sign(&keypair),
+                Err(SDKError::InvalidEnvelope(_))
+            ));
+        }
+
+        let mut envelope = envelope;
+        assert!(matches!(
+            envelope.sign(&Keypair::generate()),
+            Err(SDKError::InvalidEnvelope(_))
+        ));
+        assert!(matches!(
+            envelope.add_signature(keypair.pubkey(), [0; 64]),
+            Err(SDKError::InvalidSignature(_))
+        ));
+        envelope.sign(&keypair).unwrap();
+    }
+
+    #[tokio::test]
+    async fn multisig_envelopes_collect_signatures() {
+        let (_server, client) = mock_signing_client(vec![]).await;
+        let signers = [
+            Keypair::generate(),
+            Keypair::generate(),
+            Keypair::generate(),
+        ];
+        let format = SigningFormat::Multisig {
+            min_signers: 2,
+            pubkeys: signers.iter().map(|k| Address(k.pubkey())).collect(),
+        };
+        let mut envelope = unsigned(cancel_all(), &client)
+            .to_envelope(format, &client)
+            .unwrap();
+
+        envelope.sign(&signers[0]).unwrap();
+        assert!(envelope.sign(&signers[0]).is_err(), "duplicate signer");
+        assert!(envelope.sign(&Keypair::generate()).is_err(), "outsider");
+        envelope.sign(&signers[2]).unwrap();
+        assert_eq!(envelope.signatures.len(), 2);
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::string::FromUtf8Error;
 
+use bullet_exchange_interface::types::{AssetId, MarketId};
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 use crate::generated::types::{ApiErrorDetail, ApiErrorResponse};
@@ -92,9 +94,9 @@ pub enum SDKError {
     #[error("Request error: {0}")]
     RequestError(String),
 
-    /// No keypair available for signing.
+    /// No keypair or other [`Signer`](crate::Signer) available for signing.
     #[error(
-        "No keypair available. Provide a signer via Transaction::builder().signer() or Client::builder().keypair()"
+        "No keypair available. Provide a signer via Transaction::builder().signer(), Client::builder().keypair() or Client::builder().signer()"
     )]
     MissingKeypair,
 
@@ -125,6 +127,46 @@ pub enum SDKError {
     #[error("Invalid public key length: expected 32 bytes, got {0}")]
     InvalidPublicKeyLength(usize),
 
+    /// A signature does not verify against the transaction and public key.
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+
+    /// A [`TransactionEnvelope`](crate::TransactionEnvelope) is malformed,
+    /// inconsistent, or can't take the signature.
+    #[error("Invalid transaction envelope: {0}")]
+    InvalidEnvelope(String),
+
+    /// The [`Signer`](crate::Signer) only signs asynchronously but was used
+    /// from a synchronous path such as `Transaction::builder().build()`.
+    #[error("Signer only signs asynchronously; use an async path such as Transaction::sign_async")]
+    AsyncSignerRequired,
+
+    /// A [`Signer`](crate::Signer) failed to produce a signature.
+    #[error("Signing failed: {0}")]
+    SigningFailed(String),
+
+    /// A [`Keystore`](crate::Keystore) could not be read, written, or parsed.
+    #[error("Keystore error: {0}")]
+    KeystoreError(String),
+
+    /// The password did not decrypt the [`Keystore`](crate::Keystore), or
+    /// the keystore was modified.
+    #[error("Incorrect keystore password")]
+    IncorrectPassword,
+
+    /// A [`SigningPolicy`](crate::SigningPolicy) refused to sign the
+    /// transaction.
+    #[error("Signing policy violation: {0}")]
+    PolicyViolation(#[from] PolicyViolation),
+
+    /// A BIP-39 recovery phrase was malformed or failed its checksum.
+    #[error("Invalid mnemonic: {0}")]
+    InvalidMnemonic(String),
+
+    /// A derivation path was malformed or contained a non-hardened segment.
+    #[error("Invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
+
     #[error("Schema outdated - recompile the binary to update bullet-exchange-interface")]
     SchemaOutdated,
 
@@ -134,6 +176,39 @@ pub enum SDKError {
     #[error("Transaction is outdated - need to re-sign again.")]
     TransactionOutdated,
 
+    /// The API did not report ready on `/health/ready` within the given
+    /// timeout. See [`Client::wait_until_ready`](crate::Client::wait_until_ready).
+    #[error("API did not become ready within {0:?}")]
+    NotReady(std::time::Duration),
+
+    /// An endpoint in a multi-endpoint [`Network`](crate::Network) serves a
+    /// different chain than the others.
+    #[error("Endpoint {url} does not serve the same chain: {reason}")]
+    EndpointMismatch { url: String, reason: String },
+
+    /// The market's cached status is not `TRADING`, so the order was not
+    /// submitted. See [`Client::place_orders`](crate::Client::place_orders).
+    #[error("Market {symbol} is not trading (status: {status})")]
+    MarketNotTrading { symbol: String, status: String },
+
+    /// The order book had no resting orders on the side needed to derive a
+    /// protective price.
+    #[error("No liquidity on {0} to price against")]
+    NoLiquidity(String),
+
+    /// The market ID is not in the cached exchange metadata.
+    #[error("Unknown market {0}")]
+    UnknownMarket(u16),
+
+    /// An order size rounded to the lot size fell below the market's minimum
+    /// quantity.
+    #[error("Size {size} is below the minimum quantity {min_qty} for {symbol}")]
+    SizeBelowMinimum {
+        symbol: String,
+        size: rust_decimal::Decimal,
+        min_qty: rust_decimal::Decimal,
+    },
+
     #[error("Invalid multisig: {0}")]
     InvalidMultisig(String),
 
@@ -199,6 +274,62 @@ pub enum WSErrors {
     JsonError(#[from] serde_json::Error),
 }
 
+/// Why a [`SigningPolicy`](crate::SigningPolicy) refused a transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PolicyViolation {
+    /// The call is not in `allowed_calls`. Carries its name, e.g.
+    /// `"User/Transfer"`.
+    #[error("{0} is not allowed")]
+    CallNotAllowed(String),
+
+    /// The market is not in `allowed_markets`.
+    #[error("market {0} is not allowed")]
+    MarketNotAllowed(MarketId),
+
+    #[error("order size {size} exceeds the limit of {limit}")]
+    OrderTooLarge { size: Decimal, limit: Decimal },
+
+    #[error("order notional {notional} exceeds the limit of {limit}")]
+    NotionalTooLarge { notional: Decimal, limit: Decimal },
+
+    /// The order carries no price (e.g. a TWAP), so its notional cannot be
+    /// checked against `max_order_notional`.
+    #[error("order has no price to check against the notional limit")]
+    UnpricedOrder,
+
+    #[error("max_fee {max_fee} exceeds the limit of {limit}")]
+    FeeTooHigh { max_fee: u128, limit: u128 },
+
+    /// Withdrawing `amount` on top of the `withdrawn` so far would exceed
+    /// the limit for the period.
+    #[error(
+        "withdrawing {amount} of asset {asset_id} would exceed the limit of {limit} per {period_secs}s ({withdrawn} already withdrawn)"
+    )]
+    WithdrawalLimitExceeded {
+        asset_id: AssetId,
+        amount: Decimal,
+        withdrawn: Decimal,
+        limit: Decimal,
+        period_secs: u64,
+    },
+
+    /// The recipient is not in `allowed_destinations`.
+    #[error("destination {0} is not allowed")]
+    DestinationNotAllowed(String),
+
+    /// The call moves funds or hands over control in a way
+    /// `allowed_destinations` and `withdrawal_limits` cannot account for,
+    /// so it is refused while either is set. Carries its name.
+    #[error("{0} is not covered by the destination and withdrawal rules")]
+    UncheckedTransfer(String),
+
+    /// A [`PolicySigner`](crate::PolicySigner) was asked to sign bytes that
+    /// are not an unsigned transaction.
+    #[error("message is not an unsigned transaction")]
+    UnrecognizedMessage,
+}
+
 impl SDKError {
     /// Whether this error is potentially transient and the operation could
     /// be retried with backoff.
@@ -206,6 +337,7 @@ impl SDKError {
         match self {
             SDKError::HttpError(e) => e.is_timeout() || e.is_request(),
             SDKError::ApiError(resp) => resp.is_retryable(),
+            SDKError::NotReady(_) => true,
             SDKError::WebsocketError(e) => matches!(
                 e.as_ref(),
                 WSErrors::WsConnectionError(_)
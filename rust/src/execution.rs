@@ -0,0 +1,742 @@
+//! Slippage-bounded aggressive orders.
+//!
+//! The exchange has no market order type: a "market order" is an
+//! immediate-or-cancel limit order priced far enough through the book to
+//! fill. [`Client::market_order`] picks that price from the best opposing
+//! level and a slippage bound, so the worst execution price is always known
+//! up front:
+//!
+//! ```ignore
+//! use rust_decimal_macros::dec;
+//!
+//! // Buy 0.5 BTC, paying at most 0.5% over the best ask.
+//! let report = client.market_order(btc, Side::Bid, dec!(0.5), 50).call().await?;
+//! println!("filled {} @ {:?}", report.filled_size(), report.average_price());
+//!
+//! // Sell $5,000 worth, pricing off a locally maintained book.
+//! let report = client
+//!     .market_order(btc, Side::Ask, dec!(5000), 50)
+//!     .size_in_quote(true)
+//!     .reference_price(book.best_bid())
+//!     .call()
+//!     .await?;
+//! ```
+//!
+//! [`Client::close_position`] and [`Client::close_all_positions`] build on it
+//! to reduce positions with reduce-only IOCs, re-checking the position after
+//! each one until it reaches the target or the retry budget runs out.
+
+use std::time::Duration;
+
+use bon::bon;
+use bullet_exchange_interface::decimals::PositiveDecimal;
+use bullet_exchange_interface::message::NewOrderArgs;
+use bullet_exchange_interface::types::{ClientOrderId, MarketId, Side};
+use futures::future;
+use futures_timer::Delay;
+use rust_decimal::Decimal;
+use tracing::warn;
+
+use crate::generated::types::{AccountPosition, SubmitTxResponse};
+use crate::receipts::Fill;
+use crate::{Client, NewOrderExt, SDKError, SDKResult, SymbolInfo};
+
+/// Result of [`Client::market_order`].
+#[derive(Clone, Debug)]
+pub struct MarketOrderReport {
+    /// The IOC order as submitted, including its protective limit price.
+    pub order: NewOrderArgs,
+    /// The best opposing price the limit was derived from.
+    pub reference_price: Decimal,
+    /// Fills of the order, parsed from the transaction events.
+    pub fills: Vec<Fill>,
+    pub response: SubmitTxResponse,
+}
+
+impl MarketOrderReport {
+    /// Total base quantity filled.
+    pub fn filled_size(&self) -> Decimal {
+        self.fills.iter().map(|fill| fill.size).sum()
+    }
+
+    /// Size-weighted average fill price, or `None` if nothing filled.
+    pub fn average_price(&self) -> Option<Decimal> {
+        let size = self.filled_size();
+        if size.is_zero() {
+            return None;
+        }
+        let notional: Decimal = self.fills.iter().map(|fill| fill.price * fill.size).sum();
+        Some(notional / size)
+    }
+}
+
+#[bon]
+impl Client {
+    /// Submit an IOC order priced `max_slippage_bps` through the best
+    /// opposing level, snapped to the tick so the bound is never exceeded.
+    ///
+    /// The best level is read from the REST order book unless
+    /// `reference_price` supplies it (e.g. from a live websocket book). With
+    /// `size_in_quote`, `size` is a quote notional converted to base quantity
+    /// at the reference price and rounded down to the lot size; a result below
+    /// the minimum quantity fails with [`SDKError::SizeBelowMinimum`].
+    ///
+    /// Orders without a client order ID are given a random one. Subject to
+    /// the same market status check as [`place_orders`](Self::place_orders).
+    #[builder]
+    pub async fn market_order(
+        &self,
+        #[builder(start_fn)] market_id: MarketId,
+        #[builder(start_fn)] side: Side,
+        #[builder(start_fn)] size: Decimal,
+        #[builder(start_fn)] max_slippage_bps: u32,
+        /// Treat `size` as quote notional instead of base quantity. Default: false.
+        #[builder(default)]
+        size_in_quote: bool,
+        /// Best opposing price to use instead of fetching the order book.
+        reference_price: Option<Decimal>,
+        /// Only reduce an existing position. Default: false.
+        #[builder(default)]
+        reduce_only: bool,
+        client_order_id: Option<ClientOrderId>,
+        sub_account_index: Option<u8>,
+    ) -> SDKResult<MarketOrderReport> {
+        check_slippage(max_slippage_bps)?;
+        let info = self
+            .symbol_info(market_id)
+            .ok_or(SDKError::UnknownMarket(market_id.0))?;
+        let reference_price = match reference_price {
+            Some(price) => price,
+            None => self.best_opposing_price(&info.symbol, side).await?,
+        };
+        let price = protective_price(&info, side, reference_price, max_slippage_bps)?;
+        let size = if size_in_quote {
+            info.size_for_notional(size, reference_price)?
+        } else {
+            info.checked_size(size)?
+        };
+
+        let mut order = NewOrderArgs::ioc(price, size, side);
+        order.reduce_only = reduce_only;
+        order.client_order_id =
+            Some(client_order_id.unwrap_or_else(|| ClientOrderId(rand::random())));
+        let response = self
+            .place_orders(market_id, vec![order.clone()], false, sub_account_index)
+            .await?;
+
+        // The transaction holds only this order, so every taker fill on the
+        // market is one of its fills.
+        let fills = response
+            .fills()
+            .into_iter()
+            .filter(|fill| fill.market_id == market_id && fill.side == side && !fill.is_maker)
+            .collect();
+        Ok(MarketOrderReport {
+            order,
+            reference_price,
+            fills,
+            response,
+        })
+    }
+}
+
+impl Client {
+    /// Best price on the side of the REST order book that a `side` order
+    /// would trade against.
+    pub(crate) async fn best_opposing_price(&self, symbol: &str, side: Side) -> SDKResult<Decimal> {
+        let book = self
+            .with_failover(|api| async move { api.order_book(Some(5), symbol).await })
+            .await?
+            .into_inner();
+        let levels = match side {
+            Side::Bid => &book.asks,
+            Side::Ask => &book.bids,
+        };
+        levels
+            .first()
+            .and_then(|level| level.first())
+            .and_then(|price| price.parse::<Decimal>().ok())
+            .ok_or_else(|| SDKError::NoLiquidity(symbol.to_string()))
+    }
+}
+
+/// Reject slippage bounds of 100% or more, which would price sells at or
+/// below zero.
+pub(crate) fn check_slippage(max_slippage_bps: u32) -> SDKResult<()> {
+    if max_slippage_bps >= 10_000 {
+        return Err(SDKError::RequestError(format!(
+            "max_slippage_bps must be below 10000, got {max_slippage_bps}"
+        )));
+    }
+    Ok(())
+}
+
+/// An IOC limit price `max_slippage_bps` through `reference`, snapped to the
+/// tick on the passive side so it stays inside the bound.
+pub(crate) fn protective_price(
+    info: &SymbolInfo,
+    side: Side,
+    reference: Decimal,
+    max_slippage_bps: u32,
+) -> SDKResult<PositiveDecimal> {
+    check_slippage(max_slippage_bps)?;
+    let slippage = Decimal::from(max_slippage_bps) / Decimal::from(10_000);
+    let price = match side {
+        Side::Bid => reference * (Decimal::ONE + slippage),
+        Side::Ask => reference * (Decimal::ONE - slippage),
+    };
+    let price = info.snap_price(price, side);
+    match PositiveDecimal::try_from(price) {
+        Ok(positive) if !price.is_zero() => Ok(positive),
+        _ => Err(SDKError::NoLiquidity(info.symbol.clone())),
+    }
+}
+
+// ── Closing positions ───────────────────────────────────────────────────────
+
+/// Default close rounds before giving up on reaching the target size.
+const DEFAULT_CLOSE_ATTEMPTS: u32 = 3;
+
+/// Default pause between a closing IOC and re-reading the position.
+const DEFAULT_CLOSE_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Default worst price for [`Client::close_all_positions`], in basis points
+/// from the top of the book.
+const DEFAULT_CLOSE_SLIPPAGE_BPS: u32 = 100;
+
+/// Result of closing (part of) one position.
+///
+/// Sizes are signed like `positionAmt`: positive long, negative short.
+#[derive(Clone, Debug)]
+pub struct CloseReport {
+    pub market_id: MarketId,
+    /// `None` for the main account.
+    pub sub_account_index: Option<u8>,
+    /// Position size before closing.
+    pub initial_size: Decimal,
+    /// Position size the close aims to leave.
+    pub target_size: Decimal,
+    /// Position size at the last check.
+    pub remaining_size: Decimal,
+    /// Each reduce-only IOC sent, in order.
+    pub orders: Vec<MarketOrderReport>,
+    /// Why the last round failed, if it did.
+    pub last_error: Option<String>,
+}
+
+impl CloseReport {
+    /// Whether the position reached the target size.
+    pub fn is_complete(&self) -> bool {
+        self.last_error.is_none() && self.remaining_size.abs() <= self.target_size.abs()
+    }
+}
+
+/// Retry settings shared by the close helpers.
+struct CloseOptions {
+    max_slippage_bps: u32,
+    max_attempts: u32,
+    retry_delay: Duration,
+}
+
+#[bon]
+impl Client {
+    /// Close `fraction` (in `(0, 1]`) of the position on a market with
+    /// reduce-only IOC orders.
+    ///
+    /// Each round sends a [`market_order`](Self::market_order) for what is
+    /// left to close, rounded down to the lot size, then re-reads the
+    /// position. Rounds repeat until the target is reached or `max_attempts`
+    /// is used up; failed rounds are retried and the last error is kept in
+    /// the report. A flat position returns a complete report with no orders.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Halve the BTC position, accepting up to 1% slippage.
+    /// let report = client.close_position(btc, dec!(0.5), 100).call().await?;
+    /// assert!(report.is_complete());
+    /// ```
+    #[builder]
+    pub async fn close_position(
+        &self,
+        #[builder(start_fn)] market_id: MarketId,
+        #[builder(start_fn)] fraction: Decimal,
+        #[builder(start_fn)] max_slippage_bps: u32,
+        sub_account_index: Option<u8>,
+        /// Close rounds before giving up. Default: 3.
+        max_attempts: Option<u32>,
+        /// Pause between a closing order and re-reading the position. Default: 200ms.
+        retry_delay: Option<Duration>,
+    ) -> SDKResult<CloseReport> {
+        if fraction <= Decimal::ZERO || fraction > Decimal::ONE {
+            return Err(SDKError::RequestError(format!(
+                "close fraction must be in (0, 1], got {fraction}"
+            )));
+        }
+        check_slippage(max_slippage_bps)?;
+        let options = CloseOptions {
+            max_slippage_bps,
+            max_attempts: max_attempts.unwrap_or(DEFAULT_CLOSE_ATTEMPTS).max(1),
+            retry_delay: retry_delay.unwrap_or(DEFAULT_CLOSE_RETRY_DELAY),
+        };
+        let address = self.account_address(sub_account_index)?;
+        let initial = self.position_size(&address, market_id).await?;
+        Ok(self
+            .close_from(
+                market_id,
+                sub_account_index,
+                &address,
+                initial,
+                fraction,
+                &options,
+            )
+            .await)
+    }
+
+    /// Fully close every open position on an account with reduce-only IOC
+    /// orders, one [`CloseReport`] per position.
+    ///
+    /// Positions are closed concurrently, each as
+    /// [`close_position`](Self::close_position) with a fraction of 1.
+    #[builder]
+    pub async fn close_all_positions(
+        &self,
+        sub_account_index: Option<u8>,
+        /// Worst price, in bps from the top of the book. Default: 100.
+        max_slippage_bps: Option<u32>,
+        /// Close rounds per position before giving up. Default: 3.
+        max_attempts: Option<u32>,
+        /// Pause between a closing order and re-reading the position. Default: 200ms.
+        retry_delay: Option<Duration>,
+    ) -> SDKResult<Vec<CloseReport>> {
+        let options = CloseOptions {
+            max_slippage_bps: max_slippage_bps.unwrap_or(DEFAULT_CLOSE_SLIPPAGE_BPS),
+            max_attempts: max_attempts.unwrap_or(DEFAULT_CLOSE_ATTEMPTS).max(1),
+            retry_delay: retry_delay.unwrap_or(DEFAULT_CLOSE_RETRY_DELAY),
+        };
+        check_slippage(options.max_slippage_bps)?;
+        let address = self.account_address(sub_account_index)?;
+        let positions = self.open_positions_at(&address).await?;
+        let closes = positions.iter().map(|position| {
+            self.close_from(
+                MarketId(position.market_id),
+                sub_account_index,
+                &address,
+                position.position_amt,
+                Decimal::ONE,
+                &options,
+            )
+        });
+        Ok(future::join_all(closes).await)
+    }
+}
+
+impl Client {
+    async fn close_from(
+        &self,
+        market_id: MarketId,
+        sub_account_index: Option<u8>,
+        address: &str,
+        initial: Decimal,
+        fraction: Decimal,
+        options: &CloseOptions,
+    ) -> CloseReport {
+        let mut report = CloseReport {
+            market_id,
+            sub_account_index,
+            initial_size: initial,
+            target_size: Decimal::ZERO,
+            remaining_size: initial,
+            orders: Vec::new(),
+            last_error: None,
+        };
+        let Some(info) = self.symbol_info(market_id) else {
+            report.target_size = initial;
+            report.last_error = Some(SDKError::UnknownMarket(market_id.0).to_string());
+            return report;
+        };
+        let to_close = info.snap_size(initial.abs() * fraction);
+        report.target_size = if initial.is_sign_negative() {
+            initial + to_close
+        } else {
+            initial - to_close
+        };
+
+        for _ in 0..options.max_attempts {
+            let size = info.snap_size(report.remaining_size.abs() - report.target_size.abs());
+            if size.is_zero() || size < info.min_qty {
+                break;
+            }
+            let order = self
+                .market_order(
+                    market_id,
+                    closing_side(report.remaining_size),
+                    size,
+                    options.max_slippage_bps,
+                )
+                .reduce_only(true)
+                .maybe_sub_account_index(sub_account_index)
+                .call()
+                .await;
+            match order {
+                Ok(order) => {
+                    report.orders.push(order);
+                    report.last_error = None;
+                }
+                Err(e) => report.last_error = Some(e.to_string()),
+            }
+
+            Delay::new(options.retry_delay).await;
+            match self.position_size(address, market_id).await {
+                Ok(size) => report.remaining_size = size,
+                Err(e) => report.last_error = Some(e.to_string()),
+            }
+        }
+        if !report.is_complete() {
+            warn!(?market_id, remaining = %report.remaining_size, "position not closed to target");
+        }
+        report
+    }
+
+    /// Non-zero positions held by `address`.
+    pub(crate) async fn open_positions_at(&self, address: &str) -> SDKResult<Vec<AccountPosition>> {
+        let account = self
+            .with_failover(|api| async move { api.account_info(address).await })
+            .await?
+            .into_inner();
+        Ok(account
+            .positions
+            .into_iter()
+            .filter(|position| !position.position_amt.is_zero())
+            .collect())
+    }
+
+    /// Signed position size held by `address` on a market; zero if none.
+    async fn position_size(&self, address: &str, market_id: MarketId) -> SDKResult<Decimal> {
+        Ok(self
+            .open_positions_at(address)
+            .await?
+            .iter()
+            .filter(|position| position.market_id == market_id.0)
+            .map(|position| position.position_amt)
+            .sum())
+    }
+}
+
+/// The order side that reduces a position of signed size `position_amt`.
+pub(crate) fn closing_side(position_amt: Decimal) -> Side {
+    if position_amt.is_sign_negative() {
+        Side::Bid
+    } else {
+        Side::Ask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use std::sync::Mutex;
+
+    use bullet_exchange_interface::message::{CallMessage, UserAction};
+    use bullet_exchange_interface::types::OrderType;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+    use super::*;
+    use crate::testing::{
+        account_json, decode_submitted, mock_signing_client, mount_submit_ok, submit_count, symbol,
+        trade_event,
+    };
+    use crate::{RuntimeCall, SignedTransaction};
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn trade(side: Side, price: &str, size: &str, is_maker: bool) -> serde_json::Value {
+        serde_json::json!({
+            "key": "Exchange/TradeV1",
+            "module": { "name": "exchange" },
+            "number": 0,
+            "type": "event",
+            "value": trade_event(MarketId(0), side, dec(price), dec(size), is_maker, None),
+        })
+    }
+
+    async fn market_client() -> (MockServer, Client) {
+        let (server, client) = mock_signing_client(vec![symbol("BTC-USD", 0, "TRADING")]).await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/depth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "lastUpdateId": 1,
+                "E": 0,
+                "T": 0,
+                "bids": [["99.90", "3"]],
+                "asks": [["100.10", "3"]],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/tx/submit"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "0xtx",
+                "status": "processed",
+                "events": [
+                    trade(Side::Bid, "100.10", "0.2", false),
+                    trade(Side::Ask, "100.10", "0.2", true),
+                    trade(Side::Bid, "100.20", "0.3", false),
+                ],
+            })))
+            .mount(&server)
+            .await;
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn market_order_prices_through_the_book_and_reports_taker_fills() {
+        let (_server, client) = market_client().await;
+
+        let report = client
+            .market_order(MarketId(0), Side::Bid, dec("0.5"), 50)
+            .call()
+            .await
+            .unwrap();
+
+        // 100.10 plus 0.5% is 100.6005, snapped down to the 0.01 tick.
+        assert_eq!(Decimal::from(report.order.price), dec("100.6"));
+        assert_eq!(report.order.order_type, OrderType::ImmediateOrCancel);
+        assert!(report.order.client_order_id.is_some());
+        assert_eq!(report.reference_price, dec("100.10"));
+        assert_eq!(report.fills.len(), 2);
+        assert_eq!(report.filled_size(), dec("0.5"));
+        assert_eq!(report.average_price(), Some(dec("100.16")));
+    }
+
+    #[tokio::test]
+    async fn market_order_sizes_by_quote_notional() {
+        let (_server, client) = market_client().await;
+
+        let report = client
+            .market_order(MarketId(0), Side::Ask, dec("1000"), 100)
+            .size_in_quote(true)
+            .reference_price(dec("300"))
+            .call()
+            .await
+            .unwrap();
+
+        // 1000 / 300 = 3.333.. rounded down to the 0.001 lot.
+        assert_eq!(Decimal::from(report.order.size), dec("3.333"));
+        assert_eq!(Decimal::from(report.order.price), dec("297"));
+        assert_eq!(report.reference_price, dec("300"));
+    }
+
+    #[tokio::test]
+    async fn market_order_rejects_notional_below_one_lot() {
+        let (server, client) = market_client().await;
+
+        let err = client
+            .market_order(MarketId(0), Side::Bid, dec("0.05"), 50)
+            .size_in_quote(true)
+            .call()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SDKError::SizeBelowMinimum { .. }), "{err:?}");
+        assert_eq!(submit_count(&server).await, 0);
+    }
+
+    #[tokio::test]
+    async fn market_order_rejects_slippage_of_100_percent_or_more() {
+        let (server, client) = market_client().await;
+
+        let err = client
+            .market_order(MarketId(0), Side::Ask, dec("0.5"), 10_000)
+            .call()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SDKError::RequestError(_)), "{err:?}");
+        assert_eq!(submit_count(&server).await, 0);
+    }
+
+    /// Serves `/fapi/v3/account` bodies in turn, repeating the last one.
+    struct AccountSequence(Mutex<Vec<serde_json::Value>>);
+
+    impl Respond for AccountSequence {
+        fn respond(&self, _: &Request) -> ResponseTemplate {
+            let mut bodies = self.0.lock().unwrap();
+            let body = if bodies.len() > 1 {
+                bodies.remove(0)
+            } else {
+                bodies[0].clone()
+            };
+            ResponseTemplate::new(200).set_body_json(body)
+        }
+    }
+
+    async fn close_client(accounts: Vec<serde_json::Value>) -> (MockServer, Client) {
+        let (server, client) = mock_signing_client(vec![
+            symbol("BTC-USD", 0, "TRADING"),
+            symbol("ETH-USD", 1, "TRADING"),
+        ])
+        .await;
+        mount_submit_ok(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v3/account"))
+            .respond_with(AccountSequence(Mutex::new(accounts)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/depth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "lastUpdateId": 1,
+                "E": 0,
+                "T": 0,
+                "bids": [["99.90", "3"]],
+                "asks": [["100.10", "3"]],
+            })))
+            .mount(&server)
+            .await;
+        (server, client)
+    }
+
+    /// `(sub_account_index, order)` for every order placed so far.
+    async fn placed_orders(server: &MockServer) -> Vec<(Option<u8>, NewOrderArgs)> {
+        server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/tx/submit")
+            .filter_map(|request| match decode_submitted(request) {
+                SignedTransaction::V0(v0) => match v0.runtime_call {
+                    RuntimeCall::Exchange(CallMessage::User(UserAction::PlaceOrders {
+                        orders,
+                        sub_account_index,
+                        ..
+                    })) => Some(orders.into_iter().map(move |o| (sub_account_index, o))),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn close_position_reduces_by_the_requested_fraction() {
+        let (server, client) = close_client(vec![
+            account_json(&[("BTC-USD", 0, "1.001")]),
+            account_json(&[("BTC-USD", 0, "0.501")]),
+        ])
+        .await;
+
+        let report = client
+            .close_position(MarketId(0), dec("0.5"), 50)
+            .retry_delay(Duration::ZERO)
+            .call()
+            .await
+            .unwrap();
+
+        // Half of 1.001 is 0.5005, rounded down to the 0.001 lot.
+        assert_eq!(report.target_size, dec("0.501"));
+        assert_eq!(report.remaining_size, dec("0.501"));
+        assert!(report.is_complete());
+        let orders = placed_orders(&server).await;
+        assert_eq!(orders.len(), 1);
+        let (_, order) = &orders[0];
+        assert_eq!(order.side, Side::Ask);
+        assert!(order.reduce_only);
+        assert_eq!(order.order_type, OrderType::ImmediateOrCancel);
+        assert_eq!(Decimal::from(order.size), dec("0.5"));
+    }
+
+    #[tokio::test]
+    async fn close_position_retries_partial_fills_until_flat() {
+        let (server, client) = close_client(vec![
+            account_json(&[("BTC-USD", 0, "-0.4")]),
+            account_json(&[("BTC-USD", 0, "-0.1")]),
+            account_json(&[]),
+        ])
+        .await;
+
+        let report = client
+            .close_position(MarketId(0), Decimal::ONE, 50)
+            .retry_delay(Duration::ZERO)
+            .call()
+            .await
+            .unwrap();
+
+        assert!(report.is_complete(), "{report:?}");
+        assert_eq!(report.orders.len(), 2);
+        let sizes: Vec<_> = placed_orders(&server)
+            .await
+            .into_iter()
+            .map(|(_, order)| {
+                assert_eq!(order.side, Side::Bid);
+                Decimal::from(order.size)
+            })
+            .collect();
+        assert_eq!(sizes, vec![dec("0.4"), dec("0.1")]);
+    }
+
+    #[tokio::test]
+    async fn close_position_rejects_fractions_outside_unit_interval() {
+        let (server, client) = close_client(vec![account_json(&[])]).await;
+
+        for fraction in [Decimal::ZERO, dec("1.5")] {
+            let err = client
+                .close_position(MarketId(0), fraction, 50)
+                .call()
+                .await
+                .unwrap_err();
+            assert!(matches!(err, SDKError::RequestError(_)), "{err:?}");
+        }
+        assert_eq!(submit_count(&server).await, 0);
+    }
+
+    #[tokio::test]
+    async fn close_all_positions_flattens_each_market_on_a_sub_account() {
+        let (server, client) = close_client(vec![
+            account_json(&[("BTC-USD", 0, "0.2"), ("ETH-USD", 1, "-3")]),
+            account_json(&[]),
+        ])
+        .await;
+        let sub_account = client.account_address(Some(1)).unwrap();
+
+        let reports = client
+            .close_all_positions()
+            .sub_account_index(1)
+            .retry_delay(Duration::ZERO)
+            .call()
+            .await
+            .unwrap();
+
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(CloseReport::is_complete));
+        let orders = placed_orders(&server).await;
+        assert_eq!(orders.len(), 2);
+        assert!(
+            orders
+                .iter()
+                .all(|(index, order)| *index == Some(1) && order.reduce_only)
+        );
+        let queried: Vec<_> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|request| request.url.path() == "/fapi/v3/account")
+            .filter_map(|request| {
+                request
+                    .url
+                    .query_pairs()
+                    .find(|(key, _)| key == "address")
+                    .map(|(_, value)| value.into_owned())
+            })
+            .collect();
+        assert!(queried.len() >= 3);
+        assert!(queried.iter().all(|address| *address == sub_account));
+    }
+}
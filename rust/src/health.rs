@@ -0,0 +1,543 @@
+//! API readiness gating and a background health watchdog.
+//!
+//! The trading API exposes `/health/ready`, which returns a
+//! [`ReadinessStatus`] with `200` when every component is running and `503`
+//! while any component is starting or recovering. [`Client::wait_until_ready`]
+//! blocks startup until the API reports ready, and [`HealthWatchdog`] keeps
+//! probing in the background so a bot can pause quoting while the API is
+//! degraded.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use bullet_rust_sdk::{Client, HealthEvent};
+//!
+//! let client = Client::mainnet().await?;
+//! client.wait_until_ready(Duration::from_secs(30)).await?;
+//!
+//! let mut watchdog = client.health_watchdog().call();
+//! while let Some(event) = watchdog.recv().await {
+//!     match event {
+//!         HealthEvent::Ready(_) => { /* resume quoting */ }
+//!         HealthEvent::NotReady(_) | HealthEvent::Unreachable(_) => { /* pause quoting */ }
+//!     }
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bon::bon;
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, Either};
+use futures::{FutureExt, StreamExt};
+use futures_timer::Delay;
+use tracing::{debug, warn};
+use web_time::Instant;
+
+use crate::endpoints::Endpoints;
+use crate::generated::Client as GeneratedClient;
+use crate::generated::types::ReadinessStatus;
+use crate::runtime::spawn;
+use crate::{Client, SDKError, SDKResult};
+
+/// How often [`Client::wait_until_ready`] re-probes while waiting.
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Events delivered by a [`HealthWatchdog`] when the API's readiness changes.
+#[derive(Clone, Debug)]
+pub enum HealthEvent {
+    /// The API reported ready (`200` from `/health/ready`).
+    Ready(ReadinessStatus),
+    /// The API answered but reported that at least one component is not
+    /// running (`503` from `/health/ready`).
+    NotReady(ReadinessStatus),
+    /// The readiness probe failed
+    /// [`failure_threshold`](HealthWatchdogConfig::failure_threshold) times in
+    /// a row (network error, unexpected response). Carries the last error.
+    Unreachable(String),
+}
+
+impl HealthEvent {
+    /// Whether this event means the API is ready to accept trading traffic.
+    pub fn is_ready(&self) -> bool {
+        matches!(self, HealthEvent::Ready(_))
+    }
+}
+
+/// Configuration for a [`HealthWatchdog`].
+///
+/// # Example
+///
+/// ```ignore
+/// use bullet_rust_sdk::HealthWatchdogConfig;
+/// use std::time::Duration;
+///
+/// let config = HealthWatchdogConfig::builder()
+///     .interval(Duration::from_secs(2))
+///     .failure_threshold(3)
+///     .build();
+/// ```
+#[derive(bon::Builder, Clone, Debug)]
+pub struct HealthWatchdogConfig {
+    /// Delay between readiness probes.
+    ///
+    /// Default: 5 seconds
+    #[builder(default = Duration::from_secs(5))]
+    pub interval: Duration,
+
+    /// Number of consecutive failed probes (transport errors, unparseable
+    /// responses) before the watchdog reports [`HealthEvent::Unreachable`].
+    ///
+    /// A `503` response is authoritative and flips the state immediately;
+    /// this threshold only smooths over dropped probes. Values below `1` are
+    /// treated as `1`.
+    ///
+    /// Default: 2
+    #[builder(default = 2)]
+    pub failure_threshold: u32,
+
+    /// Event channel buffer size. Events are dropped (with a warning) when the
+    /// consumer isn't keeping up and re-sent on the next probe if the state
+    /// still differs from the last delivered event;
+    /// [`HealthWatchdog::is_ready`] always reflects the latest probe
+    /// regardless.
+    ///
+    /// Default: 64
+    #[builder(default = 64)]
+    pub channel_capacity: usize,
+}
+
+impl Default for HealthWatchdogConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Background task that periodically probes `/health/ready`.
+///
+/// The latest state is readable at any time via [`is_ready`](Self::is_ready)
+/// and [`status`](Self::status); transitions are delivered as
+/// [`HealthEvent`]s through [`recv`](Self::recv). The watchdog starts in the
+/// not-ready state and emits its first event once the initial probe resolves,
+/// so a bot gated on `is_ready()` never quotes before readiness is confirmed.
+/// Each probe goes to the client's active endpoint at the time, so the
+/// watchdog follows failovers.
+///
+/// Dropping the handle stops the background task.
+pub struct HealthWatchdog {
+    ready: Arc<AtomicBool>,
+    status: Arc<Mutex<Option<ReadinessStatus>>>,
+    event_rx: mpsc::Receiver<HealthEvent>,
+    /// Held, never sent on. Dropping signals shutdown to the background task.
+    _shutdown_tx: oneshot::Sender<()>,
+}
+
+impl HealthWatchdog {
+    /// Start a watchdog for the given [`Client`].
+    ///
+    /// Must be called from within an async runtime: the probe loop is spawned
+    /// via [`tokio::spawn`] on native targets and
+    /// [`wasm_bindgen_futures::spawn_local`] on wasm.
+    pub fn spawn(client: &Client, config: HealthWatchdogConfig) -> HealthWatchdog {
+        let ready = Arc::new(AtomicBool::new(false));
+        let status = Arc::new(Mutex::new(None));
+        let (event_tx, event_rx) = mpsc::channel(config.channel_capacity);
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        let endpoints = client.endpoints.clone();
+        let task_ready = ready.clone();
+        let task_status = status.clone();
+        spawn(async move {
+            run_watchdog(
+                endpoints,
+                config,
+                task_ready,
+                task_status,
+                event_tx,
+                shutdown_rx,
+            )
+            .await;
+        });
+
+        HealthWatchdog {
+            ready,
+            status,
+            event_rx,
+            _shutdown_tx: shutdown_tx,
+        }
+    }
+
+    /// Whether the most recent probe reported the API as ready.
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
+    /// The most recent [`ReadinessStatus`] returned by the API, if any probe
+    /// has been answered yet. Unreachable probes leave the last answer in
+    /// place.
+    pub fn status(&self) -> Option<ReadinessStatus> {
+        // The expect is fine here as we just read and write the
+        // object. We never hold a lock in code that can panic.
+        self.status
+            .lock()
+            .expect("Taking the readiness-status lock can never fail.")
+            .clone()
+    }
+
+    /// Receive the next readiness transition.
+    ///
+    /// Returns `None` once the background task has stopped.
+    pub async fn recv(&mut self) -> Option<HealthEvent> {
+        self.event_rx.next().await
+    }
+
+    /// Stop the watchdog and its background task.
+    pub fn stop(self) {
+        // Drop self — `_shutdown_tx` is dropped, closing the oneshot.
+    }
+}
+
+#[bon]
+impl Client {
+    /// Query `/health/ready` and return the [`ReadinessStatus`].
+    ///
+    /// Unlike the generated `ready()` method, a `503` response is not an
+    /// error: its body is returned with `ready == false` and the per-component
+    /// states.
+    pub async fn readiness(&self) -> SDKResult<ReadinessStatus> {
+        fetch_readiness(self.client()).await
+    }
+
+    /// Wait until the API reports ready, polling `/health/ready`.
+    ///
+    /// Transport errors while waiting (e.g. the API is still booting and
+    /// refuses connections) count as not-ready rather than failing the wait.
+    /// Returns the ready [`ReadinessStatus`], or [`SDKError::NotReady`] if the
+    /// API did not become ready within `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let client = Client::mainnet().await?;
+    /// client.wait_until_ready(Duration::from_secs(30)).await?;
+    /// ```
+    pub async fn wait_until_ready(&self, timeout: Duration) -> SDKResult<ReadinessStatus> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let probe = Box::pin(self.readiness());
+            match future::select(probe, Delay::new(remaining)).await {
+                Either::Left((Ok(status), _)) if status.ready => return Ok(status),
+                Either::Left((Ok(status), _)) => {
+                    debug!(components = ?status.components, "API not ready yet");
+                }
+                Either::Left((Err(e), _)) => debug!(?e, "readiness probe failed"),
+                Either::Right(_) => return Err(SDKError::NotReady(timeout)),
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(SDKError::NotReady(timeout));
+            }
+            Delay::new(READY_POLL_INTERVAL.min(remaining)).await;
+        }
+    }
+
+    /// Start a [`HealthWatchdog`] that probes readiness in the background.
+    ///
+    /// Thin wrapper around [`HealthWatchdog::spawn`] using the default
+    /// [`HealthWatchdogConfig`] unless one is given.
+    #[builder]
+    pub fn health_watchdog(&self, config: Option<HealthWatchdogConfig>) -> HealthWatchdog {
+        HealthWatchdog::spawn(self, config.unwrap_or_default())
+    }
+}
+
+/// Fetch `/health/ready`, recovering the [`ReadinessStatus`] body of a `503`.
+///
+/// The spec returns `ReadinessStatus` for both `200` and `503`, but progenitor
+/// types every 5xx body as `ApiErrorResponse`; the readiness body fails that
+/// parse and surfaces as `InvalidResponsePayload` with the raw bytes.
+async fn fetch_readiness(client: &GeneratedClient) -> SDKResult<ReadinessStatus> {
+    match client.ready().await {
+        Ok(resp) => Ok(resp.into_inner()),
+        Err(progenitor_client::Error::InvalidResponsePayload(bytes, e)) => {
+            serde_json::from_slice::<ReadinessStatus>(&bytes).map_err(|_| {
+                progenitor_client::Error::<crate::ApiErrorResponse>::InvalidResponsePayload(
+                    bytes, e,
+                )
+                .into()
+            })
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Background probe loop behind [`HealthWatchdog`].
+async fn run_watchdog(
+    endpoints: Arc<Endpoints>,
+    config: HealthWatchdogConfig,
+    ready: Arc<AtomicBool>,
+    status: Arc<Mutex<Option<ReadinessStatus>>>,
+    mut event_tx: mpsc::Sender<HealthEvent>,
+    mut shutdown_rx: oneshot::Receiver<()>,
+) {
+    let failure_threshold = config.failure_threshold.max(1);
+    let mut consecutive_failures = 0u32;
+    // Discriminant of the last emitted event, so we only report transitions.
+    let mut last_emitted: Option<std::mem::Discriminant<HealthEvent>> = None;
+
+    loop {
+        let client = endpoints.active().client.clone();
+        let probe = fetch_readiness(&client).fuse();
+        futures::pin_mut!(probe);
+        let result = futures::select! {
+            _ = (&mut shutdown_rx).fuse() => {
+                debug!("shutdown signaled, stopping health watchdog");
+                return;
+            }
+            r = probe => r,
+        };
+
+        let event = match result {
+            Ok(s) => {
+                consecutive_failures = 0;
+                // The expect is fine here as we just read and write the
+                // object. We never hold a lock in code that can panic.
+                *status
+                    .lock()
+                    .expect("Taking the readiness-status lock can never fail.") = Some(s.clone());
+                ready.store(s.ready, Ordering::Release);
+                Some(if s.ready {
+                    HealthEvent::Ready(s)
+                } else {
+                    HealthEvent::NotReady(s)
+                })
+            }
+            Err(e) => {
+                consecutive_failures = consecutive_failures.saturating_add(1);
+                debug!(?e, consecutive_failures, "readiness probe failed");
+                if consecutive_failures >= failure_threshold {
+                    ready.store(false, Ordering::Release);
+                    Some(HealthEvent::Unreachable(e.to_string()))
+                } else {
+                    None
+                }
+            }
+        };
+
+        if let Some(event) = event {
+            let discriminant = std::mem::discriminant(&event);
+            if last_emitted != Some(discriminant) {
+                match event_tx.try_send(event) {
+                    // Only a delivered event counts, so a dropped transition
+                    // is retried on the next probe.
+                    Ok(()) => last_emitted = Some(discriminant),
+                    Err(e) if e.is_full() => {
+                        warn!("health event channel full, dropping event — consumer too slow");
+                    }
+                    Err(_) => {
+                        debug!("health event receiver dropped, stopping watchdog");
+                        return;
+                    }
+                }
+            }
+        }
+
+        match future::select(Delay::new(config.interval), &mut shutdown_rx).await {
+            Either::Left(_) => {}
+            Either::Right(_) => {
+                debug!("shutdown signaled, stopping health watchdog");
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use super::*;
+    use crate::testing::{mock_client, two_endpoint_client};
+
+    fn readiness_body(ready: bool, state: &str) -> serde_json::Value {
+        serde_json::json!({ "ready": ready, "components": { "rollup": state } })
+    }
+
+    #[tokio::test]
+    async fn readiness_returns_503_body_as_not_ready() {
+        let (server, client) = mock_client().await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(
+                ResponseTemplate::new(503).set_body_json(readiness_body(false, "starting")),
+            )
+            .mount(&server)
+            .await;
+
+        let status = client.readiness().await.unwrap();
+
+        assert!(!status.ready);
+        assert_eq!(
+            status.components["rollup"],
+            crate::generated::types::HealthState::Starting
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_until_ready_times_out_while_not_ready() {
+        let (server, client) = mock_client().await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(
+                ResponseTemplate::new(503).set_body_json(readiness_body(false, "recovering")),
+            )
+            .mount(&server)
+            .await;
+
+        let err = client
+            .wait_until_ready(Duration::from_millis(50))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SDKError::NotReady(_)), "{err:?}");
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn wait_until_ready_returns_once_ready() {
+        let (server, client) = mock_client().await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(
+                ResponseTemplate::new(503).set_body_json(readiness_body(false, "starting")),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readiness_body(true, "running")))
+            .mount(&server)
+            .await;
+
+        let status = client
+            .wait_until_ready(Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert!(status.ready);
+    }
+
+    #[tokio::test]
+    async fn watchdog_reports_transitions_only() {
+        let (server, client) = mock_client().await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(
+                ResponseTemplate::new(503).set_body_json(readiness_body(false, "recovering")),
+            )
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readiness_body(true, "running")))
+            .mount(&server)
+            .await;
+
+        let config = HealthWatchdogConfig::builder()
+            .interval(Duration::from_millis(10))
+            .build();
+        let mut watchdog = client.health_watchdog().config(config).call();
+        assert!(!watchdog.is_ready());
+
+        // Two consecutive 503s collapse into a single NotReady event.
+        assert!(matches!(
+            watchdog.recv().await,
+            Some(HealthEvent::NotReady(_))
+        ));
+        assert!(matches!(watchdog.recv().await, Some(HealthEvent::Ready(_))));
+        assert!(watchdog.is_ready());
+        assert!(watchdog.status().unwrap().ready);
+    }
+
+    #[tokio::test]
+    async fn watchdog_follows_failover() {
+        let (primary, backup, client) = two_endpoint_client().await;
+        for (server, ready) in [(&primary, false), (&backup, true)] {
+            let state = if ready { "running" } else { "recovering" };
+            let status = if ready { 200 } else { 503 };
+            Mock::given(method("GET"))
+                .and(path("/health/ready"))
+                .respond_with(
+                    ResponseTemplate::new(status).set_body_json(readiness_body(ready, state)),
+                )
+                .mount(server)
+                .await;
+        }
+
+        let config = HealthWatchdogConfig::builder()
+            .interval(Duration::from_millis(10))
+            .failure_threshold(u32::MAX)
+            .build();
+        let mut watchdog = client.health_watchdog().config(config).call();
+        assert!(matches!(
+            watchdog.recv().await,
+            Some(HealthEvent::NotReady(_))
+        ));
+
+        drop(primary);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await
+            .unwrap();
+        assert_eq!(client.url(), backup.uri());
+
+        assert!(matches!(watchdog.recv().await, Some(HealthEvent::Ready(_))));
+    }
+
+    #[tokio::test]
+    async fn watchdog_resends_transitions_dropped_on_a_full_channel() {
+        let (server, client) = mock_client().await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(
+                ResponseTemplate::new(503).set_body_json(readiness_body(false, "recovering")),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/health/ready"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readiness_body(true, "running")))
+            .mount(&server)
+            .await;
+
+        let config = HealthWatchdogConfig::builder()
+            .interval(Duration::from_millis(10))
+            .channel_capacity(0)
+            .build();
+        let mut watchdog = client.health_watchdog().config(config).call();
+        // Let the Ready transition hit the full channel a few times.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(matches!(
+            watchdog.recv().await,
+            Some(HealthEvent::NotReady(_))
+        ));
+        let next = tokio::time::timeout(Duration::from_secs(2), watchdog.recv())
+            .await
+            .expect("dropped Ready transition was never re-sent");
+        assert!(matches!(next, Some(HealthEvent::Ready(_))));
+    }
+}
@@ -1,5 +1,8 @@
 //! Keypair functionality for the Trading SDK.
 
+use zeroize::Zeroizing;
+
+use crate::Signer;
 use crate::errors::{SDKError, SDKResult};
 
 /// An Ed25519 keypair for signing transactions.
@@ -51,6 +54,11 @@ impl Keypair {
         signature.to_bytes().to_vec()
     }
 
+    /// The 32-byte secret key.
+    pub(crate) fn secret_bytes(&self) -> [u8; 32] {
+        self.signing_key.to_bytes()
+    }
+
     /// Get the 32-byte public key.
     pub fn public_key(&self) -> Vec<u8> {
         self.signing_key.verifying_key().as_bytes().to_vec()
@@ -79,35 +87,100 @@ impl Keypair {
     ///
     /// Format: a JSON array of 64 integers — the 32-byte secret key followed
     /// by the 32-byte public key. Compatible with `solana-keygen` and Phantom.
+    /// The secret is stored unencrypted; see
+    /// [`write_encrypted`](Self::write_encrypted) for a password-protected file.
     pub fn write_to_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
-        let secret = self.signing_key.to_bytes();
-        let public = self.signing_key.verifying_key().to_bytes();
-        let mut bytes = [0u8; 64];
-        bytes[..32].copy_from_slice(&secret);
-        bytes[32..].copy_from_slice(&public);
-        let json = serde_json::to_string(&bytes.as_slice())
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-        std::fs::write(path, json)
+        let json = Zeroizing::new(self.to_solana_json());
+        std::fs::write(path, json.as_bytes())
     }
 
     /// Read a Solana-compatible JSON keystore file.
     ///
-    /// Accepts either a 64-byte array (secret + public) or a 32-byte array
-    /// (secret only). Returns an error if the file is missing or malformed.
+    /// Accepts the same formats as [`from_solana_json`](Self::from_solana_json).
+    /// Returns an error if the file is missing or malformed.
     pub fn read_from_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
-        let path = path.as_ref();
-        let data = std::fs::read_to_string(path)?;
-        let bytes: Vec<u8> = serde_json::from_str(&data)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-        if bytes.len() < 32 {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("keystore too short: {} bytes (need ≥32)", bytes.len()),
-            ));
+        let data = Zeroizing::new(std::fs::read_to_string(path)?);
+        Self::from_solana_json(&data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Parse a Solana CLI keypair (`id.json`): a JSON array of the 32-byte
+    /// secret key followed by the 32-byte public key.
+    ///
+    /// A bare 32-byte secret is also accepted. For 64-byte arrays the
+    /// embedded public key must match the one derived from the secret.
+    pub fn from_solana_json(json: &str) -> SDKResult<Self> {
+        let bytes: Zeroizing<Vec<u8>> = Zeroizing::new(
+            serde_json::from_str(json).map_err(|e| SDKError::InvalidPrivateKey(e.to_string()))?,
+        );
+        Self::from_keypair_bytes(&bytes)
+    }
+
+    /// Serialize as a Solana CLI keypair (`id.json`): a JSON array of 64
+    /// integers, the secret key followed by the public key.
+    pub fn to_solana_json(&self) -> String {
+        let bytes = self.keypair_bytes();
+        serde_json::to_string(bytes.as_slice()).expect("byte arrays always serialize")
+    }
+
+    /// Parse a base58-encoded secret key, as exported by Phantom and
+    /// Solflare.
+    ///
+    /// Accepts the 64-byte secret + public key form (the public key must
+    /// match) or a bare 32-byte secret.
+    pub fn from_base58(encoded: &str) -> SDKResult<Self> {
+        let bytes = Zeroizing::new(
+            bs58::decode(encoded.trim())
+                .into_vec()
+                .map_err(|e| SDKError::InvalidPrivateKey(e.to_string()))?,
+        );
+        Self::from_keypair_bytes(&bytes)
+    }
+
+    /// The 64-byte secret + public key, base58-encoded as wallets export it.
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.keypair_bytes().as_slice()).into_string()
+    }
+
+    /// The 32-byte secret key followed by the 32-byte public key.
+    fn keypair_bytes(&self) -> Zeroizing<[u8; 64]> {
+        Zeroizing::new(self.signing_key.to_keypair_bytes())
+    }
+
+    /// Build from a 64-byte secret + public key (checking they match) or a
+    /// 32-byte secret.
+    fn from_keypair_bytes(bytes: &[u8]) -> SDKResult<Self> {
+        match bytes.len() {
+            32 => {
+                let mut secret = Zeroizing::new([0u8; 32]);
+                secret.copy_from_slice(bytes);
+                Ok(Self::from_bytes(*secret))
+            }
+            64 => {
+                let bytes: &[u8; 64] = bytes.try_into().expect("length checked");
+                let signing_key =
+                    ed25519_dalek::SigningKey::from_keypair_bytes(bytes).map_err(|_| {
+                        SDKError::InvalidPrivateKey(
+                            "public key does not match the secret key".into(),
+                        )
+                    })?;
+                Ok(Self { signing_key })
+            }
+            len => Err(SDKError::InvalidPrivateKey(format!(
+                "expected 32 or 64 bytes, got {len}"
+            ))),
         }
-        let mut secret = [0u8; 32];
-        secret.copy_from_slice(&bytes[..32]);
-        Ok(Self::from_bytes(secret))
+    }
+}
+
+impl Signer for Keypair {
+    fn pubkey(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    fn sign_message(&self, message: &[u8]) -> SDKResult<[u8; 64]> {
+        use ed25519_dalek::Signer as _;
+        Ok(self.signing_key.sign(message).to_bytes())
     }
 }
 
@@ -144,4 +217,48 @@ mod tests {
         let keypair = Keypair::from_hex(hex).unwrap();
         assert_eq!(keypair.public_key().len(), 32);
     }
+
+    #[test]
+    fn solana_json_and_base58_round_trip() {
+        let keypair = Keypair::generate();
+
+        let json = keypair.to_solana_json();
+        let from_json = Keypair::from_solana_json(&json).unwrap();
+        let from_base58 = Keypair::from_base58(&keypair.to_base58()).unwrap();
+
+        assert_eq!(from_json.address(), keypair.address());
+        assert_eq!(from_base58.address(), keypair.address());
+        let bytes: Vec<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(&bytes[32..], keypair.public_key().as_slice());
+        assert_eq!(bs58::decode(keypair.to_base58()).into_vec().unwrap(), bytes);
+    }
+
+    #[test]
+    fn solana_keypairs_must_embed_the_matching_public_key() {
+        let keypair = Keypair::generate();
+        let mut bytes: Vec<u8> = serde_json::from_str(&keypair.to_solana_json()).unwrap();
+        bytes[63] ^= 1;
+
+        let json = serde_json::to_string(&bytes).unwrap();
+        let encoded = bs58::encode(&bytes).into_string();
+        assert!(matches!(
+            Keypair::from_solana_json(&json),
+            Err(SDKError::InvalidPrivateKey(_))
+        ));
+        assert!(matches!(
+            Keypair::from_base58(&encoded),
+            Err(SDKError::InvalidPrivateKey(_))
+        ));
+        assert!(matches!(
+            Keypair::from_solana_json("[1, 2, 3]"),
+            Err(SDKError::InvalidPrivateKey(_))
+        ));
+
+        let secret_only = serde_json::to_string(&bytes[..32]).unwrap();
+        assert_eq!(
+            Keypair::from_solana_json(&secret_only).unwrap().address(),
+            keypair.address()
+        );
+    }
 }
@@ -0,0 +1,427 @@
+//! Password-protected keystores for [`Keypair`]s.
+//!
+//! A [`Keystore`] is a versioned JSON document holding a secret key encrypted
+//! with XChaCha20-Poly1305 under a key derived from a password with
+//! Argon2id. The account address is stored in the clear (and authenticated),
+//! so a keystore can be identified without the password.
+//!
+//! ```ignore
+//! keypair.write_encrypted("trader.json", &password)?;
+//! let keypair = Keypair::read_encrypted("trader.json", &password)?;
+//!
+//! // Rotate the password in place
+//! Keypair::change_encrypted_password("trader.json", &old, &new)?;
+//! ```
+//!
+//! File layout (version 1):
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "address": "5Hq3...",
+//!   "crypto": {
+//!     "cipher": "xchacha20poly1305",
+//!     "nonce": "<24-byte hex>",
+//!     "ciphertext": "<hex>",
+//!     "kdf": "argon2id",
+//!     "kdf_params": { "memory_kib": 65536, "iterations": 3, "parallelism": 1, "salt": "<16-byte hex>" }
+//!   }
+//! }
+//! ```
+
+use std::path::Path;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::{Keypair, SDKError, SDKResult};
+
+/// The only keystore format version this SDK reads and writes.
+const KEYSTORE_VERSION: u32 = 1;
+const CIPHER: &str = "xchacha20poly1305";
+const KDF: &str = "argon2id";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+/// Largest accepted Argon2 memory cost: 4 GiB. Keystore files are untrusted
+/// input, and a huge cost would exhaust memory or stall decryption.
+const MAX_MEMORY_KIB: u32 = 4 * 1024 * 1024;
+/// Largest accepted number of Argon2 passes.
+const MAX_ITERATIONS: u32 = 10;
+
+/// Argon2id cost parameters for deriving the encryption key.
+///
+/// The default (64 MiB, 3 passes, 1 lane) takes well under a second on a
+/// server; raise `memory_kib` for keys that are decrypted rarely. Costs
+/// above 4 GiB or 10 passes are rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Memory cost in KiB.
+    pub memory_kib: u32,
+    /// Number of passes over the memory.
+    pub iterations: u32,
+    /// Degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+/// A password-encrypted secret key. See the [module docs](self) for the
+/// file format.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Keystore {
+    /// Format version. Only `1` is supported.
+    pub version: u32,
+    /// Base58 address of the encrypted key, checked on decryption.
+    pub address: String,
+    crypto: KeystoreCrypto,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+    kdf: String,
+    kdf_params: StoredKdfParams,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct StoredKdfParams {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    salt: String,
+}
+
+impl Keystore {
+    /// Encrypt `keypair` under `password` with the default [`KdfParams`].
+    pub fn encrypt(keypair: &Keypair, password: &str) -> SDKResult<Self> {
+        Self::encrypt_with(keypair, password, KdfParams::default())
+    }
+
+    /// Encrypt `keypair` under `password` with explicit KDF costs.
+    pub fn encrypt_with(keypair: &Keypair, password: &str, params: KdfParams) -> SDKResult<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let address = keypair.address();
+        let key = derive_key(password, &salt, params)?;
+        let secret = Zeroizing::new(keypair.secret_bytes());
+        let ciphertext = XChaCha20Poly1305::new(key.as_ref().into())
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: secret.as_ref(),
+                    aad: address.as_bytes(),
+                },
+            )
+            .map_err(|_| SDKError::KeystoreError("encryption failed".to_string()))?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address,
+            crypto: KeystoreCrypto {
+                cipher: CIPHER.to_string(),
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(ciphertext),
+                kdf: KDF.to_string(),
+                kdf_params: StoredKdfParams {
+                    memory_kib: params.memory_kib,
+                    iterations: params.iterations,
+                    parallelism: params.parallelism,
+                    salt: hex::encode(salt),
+                },
+            },
+        })
+    }
+
+    /// Decrypt the keypair.
+    ///
+    /// Fails with [`SDKError::IncorrectPassword`] if the password is wrong
+    /// or the keystore was tampered with, and [`SDKError::KeystoreError`] if
+    /// the document is malformed or uses an unsupported version.
+    pub fn decrypt(&self, password: &str) -> SDKResult<Keypair> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(SDKError::KeystoreError(format!(
+                "unsupported keystore version {}",
+                self.version
+            )));
+        }
+        let crypto = &self.crypto;
+        if crypto.cipher != CIPHER || crypto.kdf != KDF {
+            return Err(SDKError::KeystoreError(format!(
+                "unsupported cipher/kdf {}/{}",
+                crypto.cipher, crypto.kdf
+            )));
+        }
+        let salt = decode_hex("salt", &crypto.kdf_params.salt)?;
+        let nonce = decode_hex("nonce", &crypto.nonce)?;
+        let ciphertext = decode_hex("ciphertext", &crypto.ciphertext)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(SDKError::KeystoreError(format!(
+                "nonce must be {NONCE_LEN} bytes, got {}",
+                nonce.len()
+            )));
+        }
+
+        let key = derive_key(password, &salt, self.kdf_params())?;
+        let secret = Zeroizing::new(
+            XChaCha20Poly1305::new(key.as_ref().into())
+                .decrypt(
+                    XNonce::from_slice(&nonce),
+                    Payload {
+                        msg: &ciphertext,
+                        aad: self.address.as_bytes(),
+                    },
+                )
+                .map_err(|_| SDKError::IncorrectPassword)?,
+        );
+        let secret: [u8; 32] = secret.as_slice().try_into().map_err(|_| {
+            SDKError::KeystoreError(format!("secret must be 32 bytes, got {}", secret.len()))
+        })?;
+        let keypair = Keypair::from_bytes(secret);
+        if keypair.address() != self.address {
+            return Err(SDKError::KeystoreError(
+                "decrypted key does not match the keystore address".to_string(),
+            ));
+        }
+        Ok(keypair)
+    }
+
+    /// Re-encrypt under `new_password` with a fresh salt and nonce, keeping
+    /// the KDF costs.
+    pub fn change_password(&self, old_password: &str, new_password: &str) -> SDKResult<Self> {
+        let keypair = self.decrypt(old_password)?;
+        Self::encrypt_with(&keypair, new_password, self.kdf_params())
+    }
+
+    /// The KDF costs this keystore was encrypted with.
+    pub fn kdf_params(&self) -> KdfParams {
+        let stored = &self.crypto.kdf_params;
+        KdfParams {
+            memory_kib: stored.memory_kib,
+            iterations: stored.iterations,
+            parallelism: stored.parallelism,
+        }
+    }
+
+    /// Serialize the keystore to a JSON string.
+    pub fn to_json(&self) -> SDKResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parse a keystore from a JSON string.
+    pub fn from_json(json: &str) -> SDKResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write the keystore to a JSON file, replacing it atomically.
+    ///
+    /// On Unix the file is created with mode `0600`.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> SDKResult<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        write_private(Path::new(&tmp), self.to_json()?.as_bytes())
+            .and_then(|()| std::fs::rename(&tmp, path))
+            .map_err(|e| SDKError::KeystoreError(format!("{}: {e}", path.display())))
+    }
+
+    /// Read a keystore from a JSON file.
+    pub fn read_from_file(path: impl AsRef<Path>) -> SDKResult<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| SDKError::KeystoreError(format!("{}: {e}", path.display())))?;
+        Self::from_json(&json)
+    }
+}
+
+impl Keypair {
+    /// Write to a password-encrypted [`Keystore`] file.
+    pub fn write_encrypted(&self, path: impl AsRef<Path>, password: &str) -> SDKResult<()> {
+        Keystore::encrypt(self, password)?.write_to_file(path)
+    }
+
+    /// Read a password-encrypted [`Keystore`] file.
+    pub fn read_encrypted(path: impl AsRef<Path>, password: &str) -> SDKResult<Self> {
+        Keystore::read_from_file(path)?.decrypt(password)
+    }
+
+    /// Re-encrypt a [`Keystore`] file under a new password, in place.
+    pub fn change_encrypted_password(
+        path: impl AsRef<Path>,
+        old_password: &str,
+        new_password: &str,
+    ) -> SDKResult<()> {
+        let path = path.as_ref();
+        Keystore::read_from_file(path)?
+            .change_password(old_password, new_password)?
+            .write_to_file(path)
+    }
+}
+
+fn derive_key(password: &str, salt: &[u8], params: KdfParams) -> SDKResult<Zeroizing<[u8; 32]>> {
+    if params.memory_kib > MAX_MEMORY_KIB || params.iterations > MAX_ITERATIONS {
+        return Err(SDKError::KeystoreError(format!(
+            "kdf params exceed the limits ({} KiB, {} passes): {params:?}",
+            MAX_MEMORY_KIB, MAX_ITERATIONS
+        )));
+    }
+    let params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(32),
+    )
+    .map_err(|e| SDKError::KeystoreError(format!("invalid kdf params: {e}")))?;
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, key.as_mut())
+        .map_err(|e| SDKError::KeystoreError(format!("key derivation failed: {e}")))?;
+    Ok(key)
+}
+
+fn decode_hex(field: &str, value: &str) -> SDKResult<Vec<u8>> {
+    hex::decode(value).map_err(|e| SDKError::KeystoreError(format!("invalid {field}: {e}")))
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(contents)
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    std::fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Signer;
+
+    /// Cheap enough for debug-build tests.
+    const FAST: KdfParams = KdfParams {
+        memory_kib: 64,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    #[test]
+    fn keystore_roundtrips_and_rejects_wrong_password() {
+        let keypair = Keypair::generate();
+        let keystore = Keystore::encrypt_with(&keypair, "hunter2", FAST).unwrap();
+        let json = keystore.to_json().unwrap();
+
+        assert!(!json.contains(&hex::encode(keypair.secret_bytes())));
+        let restored = Keystore::from_json(&json).unwrap();
+        assert_eq!(restored.address, keypair.address());
+        assert_eq!(restored.kdf_params(), FAST);
+        assert_eq!(
+            restored.decrypt("hunter2").unwrap().pubkey(),
+            keypair.pubkey()
+        );
+        assert!(matches!(
+            restored.decrypt("hunter3"),
+            Err(SDKError::IncorrectPassword)
+        ));
+    }
+
+    #[test]
+    fn keystore_rejects_tampered_address_and_unknown_versions() {
+        let keypair = Keypair::generate();
+        let mut keystore = Keystore::encrypt_with(&keypair, "pw", FAST).unwrap();
+
+        keystore.address = Keypair::generate().address();
+        assert!(matches!(
+            keystore.decrypt("pw"),
+            Err(SDKError::IncorrectPassword)
+        ));
+
+        keystore.version = 2;
+        assert!(matches!(
+            keystore.decrypt("pw"),
+            Err(SDKError::KeystoreError(_))
+        ));
+    }
+
+    #[test]
+    fn keystore_rejects_excessive_kdf_costs() {
+        let keypair = Keypair::generate();
+        let keystore = Keystore::encrypt_with(&keypair, "pw", FAST).unwrap();
+
+        let mut huge_memory = keystore.clone();
+        huge_memory.crypto.kdf_params.memory_kib = u32::MAX;
+        let mut many_passes = keystore.clone();
+        many_passes.crypto.kdf_params.iterations = 1_000_000;
+        for keystore in [huge_memory, many_passes] {
+            assert!(matches!(
+                keystore.decrypt("pw"),
+                Err(SDKError::KeystoreError(_))
+            ));
+        }
+        assert!(matches!(
+            Keystore::encrypt_with(
+                &keypair,
+                "pw",
+                KdfParams {
+                    iterations: 11,
+                    ..FAST
+                }
+            ),
+            Err(SDKError::KeystoreError(_))
+        ));
+    }
+
+    #[test]
+    fn encrypted_files_can_change_password() {
+        let dir = std::env::temp_dir().join(format!("keystore-{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("trader.json");
+        let keypair = Keypair::generate();
+        Keystore::encrypt_with(&keypair, "old", FAST)
+            .unwrap()
+            .write_to_file(&path)
+            .unwrap();
+
+        Keypair::change_encrypted_password(&path, "old", "new").unwrap();
+
+        assert!(matches!(
+            Keypair::read_encrypted(&path, "old"),
+            Err(SDKError::IncorrectPassword)
+        ));
+        let restored = Keypair::read_encrypted(&path, "new").unwrap();
+        assert_eq!(restored.pubkey(), keypair.pubkey());
+        assert_eq!(Keystore::read_from_file(&path).unwrap().kdf_params(), FAST);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
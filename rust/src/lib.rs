@@ -1,9 +1,26 @@
+mod batch;
+mod cache;
 mod client;
+mod endpoints;
+mod envelope;
+mod execution;
+mod health;
 mod keypair;
+mod keystore;
 mod metadata;
+mod mnemonic;
 mod multisig;
+mod policy;
+mod quoting;
 mod receipts;
+mod runtime;
+mod safety;
+mod signer;
+mod snapshot;
 mod sub_account;
+mod summary;
+#[cfg(test)]
+mod testing;
 mod trading;
 mod transaction_builder;
 mod vault;
@@ -15,27 +32,49 @@ pub use vault::derive_vault_address;
 /// Error types for the SDK.
 pub mod errors;
 
+/// Wire types and client for the `bullet-signer` daemon.
+pub mod remote_signer;
+
 // Re-export main types at crate root for ergonomic imports
 pub use bullet_exchange_interface::transaction::{
     Amount as WarpAmount, RuntimeCall, Transaction as SignedTransaction, WarpBytes32 as HexBytes32,
     WarpCall,
 };
 pub use client::{Client, Network};
-pub use errors::{SDKError, SDKResult, WSErrors};
+pub use envelope::{ENVELOPE_VERSION, EnvelopeSignature, SigningFormat, TransactionEnvelope};
+pub use errors::{PolicyViolation, SDKError, SDKResult, WSErrors};
+pub use execution::{CloseReport, MarketOrderReport};
 pub use generated::types::ApiErrorResponse;
+pub use health::{HealthEvent, HealthWatchdog, HealthWatchdogConfig};
 pub use keypair::Keypair;
+pub use keystore::{KdfParams, Keystore};
 pub use multisig::{MAX_MULTISIG_SIGNERS, MultisigConfig, SolanaLedgerMultisigTransaction};
+pub use policy::{PolicySigner, SigningPolicy, WithdrawalLimit};
+pub use quoting::{
+    LadderSpacing, QuoteLadder, QuotePlan, QuoteReconciliation, RestingOrder, SizeCurve,
+};
+pub use receipts::Fill;
+pub use remote_signer::RemoteSigner;
+pub use safety::{
+    AccountKillReport, CancelOutcome, CancelStatus, DeadMansSwitch, DeadMansSwitchConfig,
+    DeadMansSwitchReport, FlattenOutcome, KillSwitchReport, OrderGuard,
+};
+pub use signer::{SignFuture, Signer};
+pub use snapshot::ChainSnapshot;
 // Re-export WebSocket close code for pattern matching
 pub use reqwest_websocket::CloseCode;
 pub use transaction_builder::{
-    SolanaLedgerTransaction, SolanaOffchainTransaction, Transaction, UnsignedTransaction,
+    DecodedTransaction, SolanaLedgerTransaction, SolanaOffchainTransaction, Transaction,
+    UnsignedTransaction,
 };
 pub use types::CallMessage;
 
 // Re-export WebSocket module and types
 pub mod ws;
 pub use ws::client::{WebsocketConfig, WebsocketHandle};
-pub use ws::managed::{ManagedWebsocket, ManagedWsConfig, ManagedWsError, WsEvent};
+pub use ws::managed::{
+    ManagedWebsocket, ManagedWsConfig, ManagedWsError, ManagedWsSender, WsEvent,
+};
 pub use ws::models::ServerMessage;
 pub use ws::topics::{KlineInterval, OrderbookDepth, Topic};
 
@@ -72,6 +111,8 @@ pub use bullet_exchange_interface::message::{
 /// Set on a transaction via `UnsignedTransaction::builder().uniqueness(...)` or
 /// `Transaction::builder().uniqueness(...)`.
 pub use bullet_exchange_interface::transaction::UniquenessData;
+/// Numeric asset identifier. Wraps a `u16`.
+pub use bullet_exchange_interface::types::AssetId;
 /// Client-assigned order identifier. Wraps a `u64`.
 pub use bullet_exchange_interface::types::ClientOrderId;
 /// Numeric market identifier. Wraps a `u16`.
@@ -100,9 +141,9 @@ pub use bullet_exchange_interface::types::OrderType;
 /// - `Side::Bid` corresponds to a **buy** order
 /// - `Side::Ask` corresponds to a **sell** order
 pub use bullet_exchange_interface::types::Side;
-pub use generated::types::SubmitTxResponse;
+pub use generated::types::{ReadinessStatus, SubmitTxResponse};
 // Re-export metadata types for symbol lookups.
-pub use metadata::SymbolInfo;
+pub use metadata::{MetadataEvent, MetadataRefresher, MetadataRefresherConfig, SymbolInfo};
 
 /// Re-export bullet_rollup types commonly used with the SDK.
 pub mod types {
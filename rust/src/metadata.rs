@@ -1,8 +1,10 @@
 //! Exchange metadata: symbol lookups and market info.
 //!
 //! Metadata is fetched from the exchange during [`Client`] construction and
-//! cached for the lifetime of the client. Use [`Client::refresh_metadata`] to
-//! update it for long-running processes.
+//! cached for the lifetime of the client. Long-running processes can update it
+//! through a shared `&Client` with [`Client::refresh_metadata`], or keep it
+//! current with a background [`MetadataRefresher`] that reports listings,
+//! delistings, and status changes as [`MetadataEvent`]s.
 //!
 //! # Example
 //!
@@ -18,13 +20,35 @@
 //! for sym in client.symbols() {
 //!     println!("{}: MarketId({})", sym.symbol, sym.market_id.0);
 //! }
+//!
+//! // Keep metadata current in the background
+//! let mut refresher = client.metadata_refresher().call();
+//! while let Some(event) = refresher.recv().await {
+//!     if let MetadataEvent::Added(info) = event {
+//!         println!("new market: {}", info.symbol);
+//!     }
+//! }
 //! ```
 
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use bullet_exchange_interface::types::MarketId;
+use bon::bon;
+use bullet_exchange_interface::decimals::PositiveDecimal;
+use bullet_exchange_interface::message::NewOrderArgs;
+use bullet_exchange_interface::types::{MarketId, OrderType, Side};
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, Either};
+use futures::{FutureExt, StreamExt};
+use futures_timer::Delay;
+use rust_decimal::Decimal;
+use tracing::{debug, warn};
 
-use crate::generated::types::Symbol;
+use crate::endpoints::Endpoints;
+use crate::generated::types::{Filter, Symbol};
+use crate::runtime::spawn;
+use crate::{Client, NewOrderExt, SDKError, SDKResult};
 
 /// Cached exchange metadata for fast symbol lookups.
 #[derive(Debug, Clone)]
@@ -53,22 +77,175 @@ pub struct SymbolInfo {
     pub price_precision: u8,
     /// Quantity decimal precision.
     pub quantity_precision: u8,
+    /// Price increment, from the `PRICE_FILTER` (or `price_precision` if
+    /// the exchange lists none).
+    pub tick_size: Decimal,
+    /// Quantity increment, from the `LOT_SIZE` filter (or
+    /// `quantity_precision` if the exchange lists none).
+    pub step_size: Decimal,
+    /// Minimum order quantity, from the `LOT_SIZE` filter (zero if none).
+    pub min_qty: Decimal,
+}
+
+/// `10^-precision`, with the precision clamped to the most `Decimal` can
+/// represent (28 places) so a malformed listing can't panic.
+fn increment(precision: u32) -> Decimal {
+    Decimal::new(1, precision.min(Decimal::MAX_SCALE))
+}
+
+impl SymbolInfo {
+    fn from_symbol(s: &Symbol) -> Self {
+        let mut tick_size = increment(s.price_precision.into());
+        let mut step_size = increment(s.quantity_precision.into());
+        let mut min_qty = Decimal::ZERO;
+        for filter in &s.filters {
+            match filter {
+                Filter::PriceFilter {
+                    tick_size: tick, ..
+                } if tick.is_sign_positive() && !tick.is_zero() => {
+                    tick_size = *tick;
+                }
+                Filter::LotSize {
+                    step_size: step,
+                    min_qty: min,
+                    ..
+                } => {
+                    if step.is_sign_positive() && !step.is_zero() {
+                        step_size = *step;
+                    }
+                    min_qty = *min;
+                }
+                _ => {}
+            }
+        }
+        SymbolInfo {
+            symbol: s.symbol.clone(),
+            market_id: MarketId(s.market_id),
+            status: s.status.clone(),
+            base_asset: s.base_asset.clone(),
+            quote_asset: s.quote_asset.clone(),
+            price_precision: s.price_precision,
+            quantity_precision: s.quantity_precision,
+            tick_size,
+            step_size,
+            min_qty,
+        }
+    }
+
+    /// Round `price` to a multiple of [`tick_size`](Self::tick_size) on the
+    /// passive side: down for bids, up for asks.
+    ///
+    /// # Panics
+    ///
+    /// If `price` is too large to count in ticks.
+    pub fn snap_price(&self, price: Decimal, side: Side) -> Decimal {
+        self.checked_snap_price(price, side)
+            .expect("price overflows when counted in ticks")
+    }
+
+    /// Round `size` down to a multiple of [`step_size`](Self::step_size).
+    ///
+    /// # Panics
+    ///
+    /// If `size` is too large to count in lot steps.
+    pub fn snap_size(&self, size: Decimal) -> Decimal {
+        self.checked_snap_size(size)
+            .expect("size overflows when counted in lot steps")
+    }
+
+    /// [`snap_price`](Self::snap_price), or `None` on overflow.
+    pub(crate) fn checked_snap_price(&self, price: Decimal, side: Side) -> Option<Decimal> {
+        let ticks = price.checked_div(self.tick_size)?;
+        let ticks = match side {
+            Side::Bid => ticks.floor(),
+            Side::Ask => ticks.ceil(),
+        };
+        Some(ticks.checked_mul(self.tick_size)?.normalize())
+    }
+
+    /// [`snap_size`](Self::snap_size), or `None` on overflow.
+    pub(crate) fn checked_snap_size(&self, size: Decimal) -> Option<Decimal> {
+        let steps = size.checked_div(self.step_size)?.floor();
+        Some(steps.checked_mul(self.step_size)?.normalize())
+    }
+
+    /// `size` as an order quantity, if it is positive and at least
+    /// [`min_qty`](Self::min_qty).
+    ///
+    /// Fails with [`SDKError::SizeBelowMinimum`] otherwise. The size is not
+    /// rounded; use [`snap_size`](Self::snap_size) first if needed.
+    pub fn checked_size(&self, size: Decimal) -> SDKResult<PositiveDecimal> {
+        match PositiveDecimal::try_from(size) {
+            Ok(positive) if !size.is_zero() && size >= self.min_qty => Ok(positive),
+            _ => Err(SDKError::SizeBelowMinimum {
+                symbol: self.symbol.clone(),
+                size,
+                min_qty: self.min_qty,
+            }),
+        }
+    }
+
+    /// Base quantity worth `notional` of the quote asset at `price`, rounded
+    /// down to the lot size.
+    ///
+    /// Fails with [`SDKError::SizeBelowMinimum`] if that rounds below
+    /// [`min_qty`](Self::min_qty), and [`SDKError::RequestError`] if `price`
+    /// is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // $5,000 of BTC at 64,000 with a 0.001 lot size is 0.078 BTC.
+    /// let size = info.size_for_notional(dec!(5000), dec!(64000))?;
+    /// ```
+    pub fn size_for_notional(
+        &self,
+        notional: Decimal,
+        price: Decimal,
+    ) -> SDKResult<PositiveDecimal> {
+        if price <= Decimal::ZERO {
+            return Err(SDKError::RequestError(format!(
+                "price must be positive to size {notional} of {}, got {price}",
+                self.symbol
+            )));
+        }
+        self.checked_size(self.snap_size(notional / price))
+    }
+
+    /// Build an order for `notional` of the quote asset at limit `price`.
+    ///
+    /// The price is snapped to the tick on the passive side (see
+    /// [`snap_price`](Self::snap_price)) and the size is computed at the
+    /// snapped price as in [`size_for_notional`](Self::size_for_notional),
+    /// so the order never costs more than `notional`. Other fields take the
+    /// [`NewOrderExt`] defaults.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let order = info.order_for_notional(dec!(5000), mid, Side::Bid, OrderType::PostOnly)?;
+    /// client.place_orders(info.market_id, vec![order], false, None).await?;
+    /// ```
+    pub fn order_for_notional(
+        &self,
+        notional: Decimal,
+        price: Decimal,
+        side: Side,
+        order_type: OrderType,
+    ) -> SDKResult<NewOrderArgs> {
+        let price = self.snap_price(price, side);
+        let size = self.size_for_notional(notional, price)?;
+        let price = PositiveDecimal::try_from(price)
+            .map_err(|e| SDKError::RequestError(format!("invalid price {price}: {e}")))?;
+        let mut order = NewOrderArgs::limit(price, size, side);
+        order.order_type = order_type;
+        Ok(order)
+    }
 }
 
 impl ExchangeMetadata {
     pub(crate) fn from_symbols(raw: &[Symbol]) -> Self {
-        let symbols: Vec<SymbolInfo> = raw
-            .iter()
-            .map(|s| SymbolInfo {
-                symbol: s.symbol.clone(),
-                market_id: MarketId(s.market_id),
-                status: s.status.clone(),
-                base_asset: s.base_asset.clone(),
-                quote_asset: s.quote_asset.clone(),
-                price_precision: s.price_precision,
-                quantity_precision: s.quantity_precision,
-            })
-            .collect();
+        let symbols: Vec<SymbolInfo> = raw.iter().map(SymbolInfo::from_symbol).collect();
 
         let by_name = symbols
             .iter()
@@ -104,6 +281,261 @@ impl ExchangeMetadata {
     pub(crate) fn symbols(&self) -> &[SymbolInfo] {
         &self.symbols
     }
+
+    /// Symbols added, removed, or whose status changed going from `self` to
+    /// `next`. Symbols are matched by name.
+    pub(crate) fn diff(&self, next: &ExchangeMetadata) -> Vec<MetadataEvent> {
+        let mut events = Vec::new();
+        for info in &next.symbols {
+            match self.symbol_info_by_name(&info.symbol) {
+                None => events.push(MetadataEvent::Added(info.clone())),
+                Some(prev) if prev.status != info.status => {
+                    events.push(MetadataEvent::StatusChanged {
+                        previous_status: prev.status.clone(),
+                        info: info.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for info in &self.symbols {
+            if next.symbol_info_by_name(&info.symbol).is_none() {
+                events.push(MetadataEvent::Removed(info.clone()));
+            }
+        }
+        events
+    }
+}
+
+/// Replace the shared metadata with `next`, returning what changed.
+pub(crate) fn swap_metadata(
+    shared: &Mutex<ExchangeMetadata>,
+    next: ExchangeMetadata,
+) -> Vec<MetadataEvent> {
+    // The expect is fine here as we just read and write the
+    // object. We never hold a lock in code that can panic.
+    let mut current = shared
+        .lock()
+        .expect("Taking the metadata lock can never fail.");
+    let events = current.diff(&next);
+    *current = next;
+    events
+}
+
+/// A change to the exchange's symbol list observed on refresh.
+#[derive(Clone, Debug)]
+pub enum MetadataEvent {
+    /// A symbol was listed.
+    Added(SymbolInfo),
+    /// A symbol is no longer listed. Carries its last known info.
+    Removed(SymbolInfo),
+    /// A symbol's trading status changed (e.g. `"TRADING"` → `"HALT"`).
+    StatusChanged {
+        /// The symbol's updated info.
+        info: SymbolInfo,
+        /// The status before the refresh.
+        previous_status: String,
+    },
+}
+
+/// Configuration for a [`MetadataRefresher`].
+#[derive(bon::Builder, Clone, Debug)]
+pub struct MetadataRefresherConfig {
+    /// Delay between `exchange_info` polls.
+    ///
+    /// Default: 60 seconds
+    #[builder(default = Duration::from_secs(60))]
+    pub interval: Duration,
+
+    /// Event channel buffer size. Events are dropped (with a warning) when the
+    /// consumer isn't keeping up; the client's metadata is updated regardless.
+    ///
+    /// Default: 64
+    #[builder(default = 64)]
+    pub channel_capacity: usize,
+}
+
+impl Default for MetadataRefresherConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Background task that periodically re-fetches `exchange_info` and updates
+/// the [`Client`]'s symbol metadata in place.
+///
+/// [`Client::symbols`], [`Client::market_id`] and friends reflect each refresh
+/// immediately; changes are also delivered as [`MetadataEvent`]s through
+/// [`recv`](Self::recv). Failed polls are logged and retried on the next
+/// interval. Each poll goes to the client's active endpoint at the time, so
+/// the refresher follows failovers.
+///
+/// Dropping the handle stops the background task.
+pub struct MetadataRefresher {
+    event_rx: mpsc::Receiver<MetadataEvent>,
+    /// Held, never sent on. Dropping signals shutdown to the background task.
+    _shutdown_tx: oneshot::Sender<()>,
+}
+
+impl MetadataRefresher {
+    /// Start a refresher for the given [`Client`].
+    ///
+    /// Must be called from within an async runtime: the poll loop is spawned
+    /// via [`tokio::spawn`] on native targets and
+    /// [`wasm_bindgen_futures::spawn_local`] on wasm.
+    pub fn spawn(client: &Client, config: MetadataRefresherConfig) -> MetadataRefresher {
+        let (event_tx, event_rx) = mpsc::channel(config.channel_capacity);
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        let endpoints = client.endpoints.clone();
+        let metadata = client.metadata.clone();
+        spawn(async move {
+            run_refresher(endpoints, config, metadata, event_tx, shutdown_rx).await;
+        });
+
+        MetadataRefresher {
+            event_rx,
+            _shutdown_tx: shutdown_tx,
+        }
+    }
+
+    /// Receive the next metadata change.
+    ///
+    /// Returns `None` once the background task has stopped.
+    pub async fn recv(&mut self) -> Option<MetadataEvent> {
+        self.event_rx.next().await
+    }
+
+    /// Stop the refresher and its background task.
+    pub fn stop(self) {
+        // Drop self — `_shutdown_tx` is dropped, closing the oneshot.
+    }
+}
+
+#[bon]
+impl Client {
+    /// Re-fetch exchange metadata from the server and return what changed.
+    ///
+    /// Call this in long-running bots to pick up newly listed markets, or use
+    /// [`metadata_refresher`](Client::metadata_refresher) to poll in the
+    /// background.
+    pub async fn refresh_metadata(&self) -> SDKResult<Vec<MetadataEvent>> {
+        let info = self
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await?;
+        let next = ExchangeMetadata::from_symbols(&info.into_inner().symbols);
+        Ok(swap_metadata(&self.metadata, next))
+    }
+
+    /// Start a [`MetadataRefresher`] with the given config (or defaults).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut refresher = client.metadata_refresher().call();
+    /// ```
+    #[builder]
+    pub fn metadata_refresher(&self, config: Option<MetadataRefresherConfig>) -> MetadataRefresher {
+        MetadataRefresher::spawn(self, config.unwrap_or_default())
+    }
+}
+
+impl Client {
+    /// Base quantity worth `notional` of the quote asset at `price` on a
+    /// market, rounded down to its lot size.
+    ///
+    /// See [`SymbolInfo::size_for_notional`]; also fails with
+    /// [`SDKError::UnknownMarket`] if the market is not in the metadata.
+    pub fn size_for_notional(
+        &self,
+        market_id: MarketId,
+        notional: Decimal,
+        price: Decimal,
+    ) -> SDKResult<PositiveDecimal> {
+        self.known_symbol(market_id)?
+            .size_for_notional(notional, price)
+    }
+
+    /// Build an order for `notional` of the quote asset at limit `price` on
+    /// a market.
+    ///
+    /// See [`SymbolInfo::order_for_notional`]; also fails with
+    /// [`SDKError::UnknownMarket`] if the market is not in the metadata.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Bid $5,000 of BTC at 64,000.
+    /// let order = client.order_for_notional(btc, dec!(5000), dec!(64000), Side::Bid, OrderType::Limit)?;
+    /// client.place_orders(btc, vec![order], false, None).await?;
+    /// ```
+    pub fn order_for_notional(
+        &self,
+        market_id: MarketId,
+        notional: Decimal,
+        price: Decimal,
+        side: Side,
+        order_type: OrderType,
+    ) -> SDKResult<NewOrderArgs> {
+        self.known_symbol(market_id)?
+            .order_for_notional(notional, price, side, order_type)
+    }
+
+    fn known_symbol(&self, market_id: MarketId) -> SDKResult<SymbolInfo> {
+        self.symbol_info(market_id)
+            .ok_or(SDKError::UnknownMarket(market_id.0))
+    }
+}
+
+/// Background poll loop behind [`MetadataRefresher`].
+async fn run_refresher(
+    endpoints: Arc<Endpoints>,
+    config: MetadataRefresherConfig,
+    metadata: Arc<Mutex<ExchangeMetadata>>,
+    mut event_tx: mpsc::Sender<MetadataEvent>,
+    mut shutdown_rx: oneshot::Receiver<()>,
+) {
+    loop {
+        match future::select(Delay::new(config.interval), &mut shutdown_rx).await {
+            Either::Left(_) => {}
+            Either::Right(_) => {
+                debug!("shutdown signaled, stopping metadata refresher");
+                return;
+            }
+        }
+
+        let client = endpoints.active().client.clone();
+        let fetch = client.exchange_info().fuse();
+        futures::pin_mut!(fetch);
+        let result = futures::select! {
+            _ = (&mut shutdown_rx).fuse() => {
+                debug!("shutdown signaled, stopping metadata refresher");
+                return;
+            }
+            r = fetch => r,
+        };
+
+        let info = match result {
+            Ok(info) => info.into_inner(),
+            Err(e) => {
+                warn!(error = %e, "metadata refresh failed");
+                continue;
+            }
+        };
+        let next = ExchangeMetadata::from_symbols(&info.symbols);
+        for event in swap_metadata(&metadata, next) {
+            match event_tx.try_send(event) {
+                Ok(()) => {}
+                Err(e) if e.is_full() => {
+                    warn!("metadata event channel full, dropping event — consumer too slow");
+                }
+                Err(_) => {
+                    debug!("metadata event receiver dropped, stopping refresher");
+                    return;
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -196,6 +628,82 @@ mod tests {
         assert!(meta.symbol_info_by_id(MarketId(99)).is_none());
     }
 
+    #[test]
+    fn tick_and_lot_sizes_come_from_filters_or_precision() {
+        let mut raw = mock_symbols();
+        raw[0].filters = vec![
+            Filter::PriceFilter {
+                max_price: Decimal::from(1_000_000),
+                min_price: Decimal::new(5, 1),
+                tick_size: Decimal::new(5, 1),
+            },
+            Filter::LotSize {
+                max_qty: Decimal::from(1000),
+                min_qty: Decimal::new(2, 3),
+                step_size: Decimal::new(1, 3),
+            },
+        ];
+        let meta = ExchangeMetadata::from_symbols(&raw);
+        let btc = meta.symbol_info_by_name("BTC-USD").unwrap();
+        let eth = meta.symbol_info_by_name("ETH-USD").unwrap();
+
+        assert_eq!(btc.tick_size, Decimal::new(5, 1));
+        assert_eq!(btc.min_qty, Decimal::new(2, 3));
+        assert_eq!(
+            btc.snap_price(Decimal::new(1002, 1), Side::Bid),
+            Decimal::from(100)
+        );
+        assert_eq!(
+            btc.snap_price(Decimal::new(1002, 1), Side::Ask),
+            Decimal::new(1005, 1)
+        );
+        assert_eq!(btc.snap_size(Decimal::new(12345, 4)), Decimal::new(1234, 3));
+        assert_eq!(eth.tick_size, Decimal::new(1, 2));
+        assert_eq!(eth.step_size, Decimal::new(1, 4));
+        assert_eq!(eth.min_qty, Decimal::ZERO);
+    }
+
+    #[test]
+    fn notional_sizing_rounds_down_to_the_lot_and_checks_minimum() {
+        let mut raw = mock_symbols();
+        raw[0].filters = vec![Filter::LotSize {
+            max_qty: Decimal::from(1000),
+            min_qty: Decimal::new(1, 2),
+            step_size: Decimal::new(1, 3),
+        }];
+        let meta = ExchangeMetadata::from_symbols(&raw);
+        let btc = meta.symbol_info_by_name("BTC-USD").unwrap();
+
+        // 5000 / 64000 = 0.078125, rounded down to the 0.001 lot.
+        let size = btc
+            .size_for_notional(Decimal::from(5000), Decimal::from(64000))
+            .unwrap();
+        assert_eq!(Decimal::from(size), Decimal::new(78, 3));
+        assert!(matches!(
+            btc.size_for_notional(Decimal::from(500), Decimal::from(64000)),
+            Err(SDKError::SizeBelowMinimum { size, .. }) if size == Decimal::new(7, 3)
+        ));
+        assert!(matches!(
+            btc.size_for_notional(Decimal::from(500), Decimal::ZERO),
+            Err(SDKError::RequestError(_))
+        ));
+
+        // The ask is snapped up to 100.01, so 1000 buys 9.999.
+        let order = btc
+            .order_for_notional(
+                Decimal::from(1000),
+                Decimal::new(100005, 3),
+                Side::Ask,
+                OrderType::PostOnly,
+            )
+            .unwrap();
+        assert_eq!(Decimal::from(order.price), Decimal::new(10001, 2));
+        assert_eq!(Decimal::from(order.size), Decimal::new(9999, 3));
+        assert_eq!(order.side, Side::Ask);
+        assert_eq!(order.order_type, OrderType::PostOnly);
+        assert!(!order.reduce_only);
+    }
+
     #[test]
     fn symbols_returns_all() {
         let meta = ExchangeMetadata::from_symbols(&mock_symbols());
@@ -208,4 +716,99 @@ mod tests {
         assert!(meta.symbols().is_empty());
         assert_eq!(meta.market_id("BTC-USD"), None);
     }
+
+    #[test]
+    fn diff_reports_added_removed_and_status_changes() {
+        let mut raw = mock_symbols();
+        let before = ExchangeMetadata::from_symbols(&raw);
+        raw[0].status = "HALT".into();
+        raw[1].symbol = "SOL-USD".into();
+        raw[1].market_id = 2;
+        let after = ExchangeMetadata::from_symbols(&raw);
+
+        let events = before.diff(&after);
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            &events[0],
+            MetadataEvent::StatusChanged { info, previous_status }
+                if info.symbol == "BTC-USD" && previous_status == "TRADING"
+        ));
+        assert!(matches!(&events[1], MetadataEvent::Added(info) if info.symbol == "SOL-USD"));
+        assert!(matches!(&events[2], MetadataEvent::Removed(info) if info.symbol == "ETH-USD"));
+    }
+
+    #[tokio::test]
+    async fn refresher_updates_shared_client_and_emits_events() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        use crate::testing::{exchange_info_response, mock_client};
+
+        let (server, client) = mock_client().await;
+        let symbols = serde_json::to_value(mock_symbols()).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(exchange_info_response(symbols)))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        let client = std::sync::Arc::new(client);
+
+        let config = MetadataRefresherConfig::builder()
+            .interval(Duration::from_millis(10))
+            .build();
+        let mut refresher = client.metadata_refresher().config(config).call();
+        let first = refresher.recv().await.unwrap();
+        let second = refresher.recv().await.unwrap();
+
+        assert!(matches!(first, MetadataEvent::Added(ref info) if info.symbol == "BTC-USD"));
+        assert!(matches!(second, MetadataEvent::Added(ref info) if info.symbol == "ETH-USD"));
+        assert_eq!(client.market_id("ETH-USD"), Some(MarketId(1)));
+    }
+
+    #[tokio::test]
+    async fn refresher_follows_failover() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        use crate::testing::{exchange_info_response, two_endpoint_client};
+
+        let (primary, backup, client) = two_endpoint_client().await;
+        let symbols = serde_json::to_value(mock_symbols()).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(exchange_info_response(symbols)))
+            .with_priority(1)
+            .mount(&backup)
+            .await;
+
+        let config = MetadataRefresherConfig::builder()
+            .interval(Duration::from_millis(10))
+            .build();
+        let mut refresher = client.metadata_refresher().config(config).call();
+
+        drop(primary);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await
+            .unwrap();
+        assert_eq!(client.url(), backup.uri());
+
+        let event = refresher.recv().await.unwrap();
+        assert!(matches!(event, MetadataEvent::Added(ref info) if info.symbol == "BTC-USD"));
+    }
+
+    #[test]
+    fn out_of_range_precision_is_clamped() {
+        let mut raw = mock_symbols().remove(0);
+        raw.price_precision = 40;
+        raw.quantity_precision = 255;
+
+        let info = SymbolInfo::from_symbol(&raw);
+
+        assert_eq!(info.tick_size, Decimal::new(1, 28));
+        assert_eq!(info.step_size, Decimal::new(1, 28));
+    }
 }
@@ -0,0 +1,176 @@
+//! BIP-39 mnemonics and SLIP-0010 Ed25519 key derivation for [`Keypair`]s.
+//!
+//! Keys derived here match the addresses shown by common Solana wallets for
+//! the same recovery phrase (Phantom, Solflare, Backpack and
+//! `solana-keygen` all use `m/44'/501'/{account}'/0'`):
+//!
+//! ```ignore
+//! let phrase = Keypair::generate_mnemonic(24)?;
+//! let keypair = Keypair::from_mnemonic(&phrase, "", &Keypair::solana_derivation_path(0))?;
+//! ```
+//!
+//! Ed25519 only supports hardened derivation, so every path segment must be
+//! hardened (`44'` or `44h`).
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+use crate::Keypair;
+use crate::errors::{SDKError, SDKResult};
+
+const HARDENED: u32 = 0x8000_0000;
+
+impl Keypair {
+    /// Generate a new random BIP-39 English recovery phrase.
+    ///
+    /// `word_count` must be 12, 15, 18, 21 or 24. Uses the OS random number
+    /// generator.
+    pub fn generate_mnemonic(word_count: usize) -> SDKResult<String> {
+        use rand::RngCore;
+
+        if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+            return Err(SDKError::InvalidMnemonic(format!(
+                "word count must be 12, 15, 18, 21 or 24, got {word_count}"
+            )));
+        }
+        let mut entropy = Zeroizing::new([0u8; 32]);
+        let len = word_count * 4 / 3;
+        rand::rngs::OsRng.fill_bytes(&mut entropy[..len]);
+        let mnemonic = Mnemonic::from_entropy(&entropy[..len])
+            .map_err(|e| SDKError::InvalidMnemonic(e.to_string()))?;
+        Ok(mnemonic.to_string())
+    }
+
+    /// Derive a keypair from a BIP-39 recovery phrase.
+    ///
+    /// `passphrase` is the optional BIP-39 passphrase (use `""` for none) and
+    /// `path` a fully hardened derivation path such as
+    /// [`Keypair::solana_derivation_path`].
+    pub fn from_mnemonic(phrase: &str, passphrase: &str, path: &str) -> SDKResult<Self> {
+        let mnemonic =
+            Mnemonic::parse(phrase).map_err(|e| SDKError::InvalidMnemonic(e.to_string()))?;
+        let seed = Zeroizing::new(mnemonic.to_seed(passphrase));
+        Self::from_seed(seed.as_slice(), path)
+    }
+
+    /// Derive a keypair from a BIP-39 seed along a SLIP-0010 Ed25519 path.
+    pub fn from_seed(seed: &[u8], path: &str) -> SDKResult<Self> {
+        let indices = parse_path(path)?;
+        let (mut key, mut chain_code) = hmac_sha512(b"ed25519 seed", &[seed]);
+        for index in indices {
+            (key, chain_code) = hmac_sha512(
+                chain_code.as_slice(),
+                &[&[0], key.as_slice(), &(index | HARDENED).to_be_bytes()],
+            );
+        }
+        Ok(Self::from_bytes(*key))
+    }
+
+    /// The derivation path Solana wallets use for `account`:
+    /// `m/44'/501'/{account}'/0'`.
+    pub fn solana_derivation_path(account: u32) -> String {
+        format!("m/44'/501'/{account}'/0'")
+    }
+}
+
+/// Parse `m/44'/501'/0'/0'` into unhardened indices, rejecting any segment
+/// that is not hardened.
+fn parse_path(path: &str) -> SDKResult<Vec<u32>> {
+    let invalid = |reason: String| SDKError::InvalidDerivationPath(format!("{path}: {reason}"));
+    let mut segments = path.trim().split('/');
+    if segments.next() != Some("m") {
+        return Err(invalid("must start with \"m\"".into()));
+    }
+    segments
+        .map(|segment| {
+            let index = segment
+                .strip_suffix('\'')
+                .or_else(|| segment.strip_suffix('h'))
+                .ok_or_else(|| invalid(format!("segment {segment:?} must be hardened")))?;
+            index
+                .parse::<u32>()
+                .ok()
+                .filter(|index| *index < HARDENED)
+                .ok_or_else(|| invalid(format!("invalid segment {segment:?}")))
+        })
+        .collect()
+}
+
+/// HMAC-SHA512 over the concatenated `parts`, split into (key, chain code).
+fn hmac_sha512(key: &[u8], parts: &[&[u8]]) -> (Zeroizing<[u8; 32]>, Zeroizing<[u8; 32]>) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in parts {
+        mac.update(part);
+    }
+    let output = Zeroizing::new(<[u8; 64]>::from(mac.finalize().into_bytes()));
+    let mut left = Zeroizing::new([0u8; 32]);
+    let mut right = Zeroizing::new([0u8; 32]);
+    left.copy_from_slice(&output[..32]);
+    right.copy_from_slice(&output[32..]);
+    (left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Signer;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn slip10_matches_the_published_test_vectors() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+
+        let master = Keypair::from_seed(&seed, "m").unwrap();
+        let child = Keypair::from_seed(&seed, "m/0h").unwrap();
+
+        assert_eq!(
+            hex::encode(master.secret_bytes()),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex::encode(child.secret_bytes()),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+    }
+
+    #[test]
+    fn mnemonics_derive_the_same_address_as_solana_wallets() {
+        let keypair =
+            Keypair::from_mnemonic(PHRASE, "", &Keypair::solana_derivation_path(0)).unwrap();
+
+        assert_eq!(
+            keypair.address(),
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"
+        );
+        let other =
+            Keypair::from_mnemonic(PHRASE, "", &Keypair::solana_derivation_path(1)).unwrap();
+        assert_ne!(other.pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn generated_mnemonics_round_trip() {
+        let phrase = Keypair::generate_mnemonic(24).unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 24);
+        Keypair::from_mnemonic(&phrase, "passphrase", "m/44'/501'/0'/0'").unwrap();
+
+        assert!(matches!(
+            Keypair::generate_mnemonic(13),
+            Err(SDKError::InvalidMnemonic(_))
+        ));
+        assert!(matches!(
+            Keypair::from_mnemonic("abandon abandon", "", "m/44'/501'/0'/0'"),
+            Err(SDKError::InvalidMnemonic(_))
+        ));
+        assert!(matches!(
+            Keypair::from_mnemonic(PHRASE, "", "m/44'/501'/0'/0"),
+            Err(SDKError::InvalidDerivationPath(_))
+        ));
+        assert!(matches!(
+            Keypair::from_mnemonic(PHRASE, "", "44'/501'"),
+            Err(SDKError::InvalidDerivationPath(_))
+        ));
+    }
+}
@@ -0,0 +1,793 @@
+//! Guardrails checked before a transaction is signed.
+//!
+//! A [`SigningPolicy`] decodes the call inside an [`UnsignedTransaction`] and
+//! rejects it with a [`PolicyViolation`] unless every configured rule holds.
+//! Unset rules allow everything.
+//!
+//! Give it to the client to cover every transaction the client signs —
+//! [`Transaction::builder`](crate::Transaction::builder), `send_call_message`
+//! and the trading helpers:
+//!
+//! ```ignore
+//! let policy = SigningPolicy::builder()
+//!     .allowed_calls(vec!["User/PlaceOrders".into(), "User/CancelOrders".into()])
+//!     .allowed_markets(vec![btc, eth])
+//!     .max_order_notional(dec!(50_000))
+//!     .build();
+//! let client = Client::builder()
+//!     .network(Network::Mainnet)
+//!     .keypair(keypair)
+//!     .signing_policy(policy)
+//!     .build()
+//!     .await?;
+//! ```
+//!
+//! or wrap any [`Signer`] in a [`PolicySigner`] to guard it wherever it is
+//! used, including for transactions built elsewhere.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bullet_exchange_interface::address::Address;
+use bullet_exchange_interface::message::{NewOrderArgs, UserAction};
+use bullet_exchange_interface::transaction::{BankCall, WarpCall};
+use bullet_exchange_interface::types::{AssetId, BalanceBucket, MarketId};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use web_time::Instant;
+
+use crate::errors::PolicyViolation;
+use crate::types::CallMessage;
+use crate::{RuntimeCall, SDKError, SDKResult, SignFuture, Signer, UnsignedTransaction};
+
+/// Rules a transaction must satisfy before it is signed.
+///
+/// Serializes to JSON (e.g. for the `bullet-signer` `--policy` file):
+///
+/// ```json
+/// {
+///   "allowed_calls": ["User/PlaceOrders", "User/CancelOrders", "User/Transfer"],
+///   "allowed_markets": [0, 1],
+///   "max_order_size": "2.5",
+///   "max_order_notional": "50000",
+///   "withdrawal_limits": [{ "asset_id": 0, "amount": "10000", "period_secs": 86400 }],
+///   "allowed_destinations": ["5Hq3..."]
+/// }
+/// ```
+///
+/// Clones share one withdrawal ledger, so a limit holds across every signer
+/// and client the policy was given to.
+#[derive(bon::Builder, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SigningPolicy {
+    /// Calls that may be signed, either exactly (`"User/PlaceOrders"`, as in
+    /// `CallMessage::msg_type`) or by category (`"User"`). Non-exchange runtime
+    /// calls are named by module (`"Bank"`, `"Warp"`).
+    ///
+    /// Default: `None` (any call)
+    pub allowed_calls: Option<Vec<String>>,
+
+    /// Markets new orders, trigger orders, TWAPs and leverage changes may
+    /// target. Cancels are always allowed so positions can be unwound.
+    ///
+    /// Default: `None` (any market)
+    pub allowed_markets: Option<Vec<MarketId>>,
+
+    /// Largest size of any single new order.
+    ///
+    /// Default: `None` (no limit)
+    pub max_order_size: Option<Decimal>,
+
+    /// Largest `price × size` of any single new order. TWAPs carry no
+    /// price, so they are refused while this is set.
+    ///
+    /// Default: `None` (no limit)
+    pub max_order_notional: Option<Decimal>,
+
+    /// Largest `max_fee` a transaction may carry.
+    ///
+    /// Default: `None` (no limit)
+    pub max_fee: Option<u128>,
+
+    /// Rolling caps on withdrawals per asset. Withdrawals are `Transfer`s to
+    /// another account or to the bank balance, and the legacy `Withdraw`.
+    /// Bank and Warp transfers move raw token units that cannot be counted
+    /// against an asset, so they are refused while any limit is set.
+    ///
+    /// Default: empty (no limit)
+    #[builder(default)]
+    pub withdrawal_limits: Vec<WithdrawalLimit>,
+
+    /// Addresses funds may be sent to: `Transfer` and bank transfer
+    /// recipients, and Warp recipients (as `0x`-prefixed hex). Moving funds
+    /// within the sender's own account is always allowed.
+    ///
+    /// While this or [`withdrawal_limits`](Self::withdrawal_limits) is set,
+    /// calls that move funds or hand over control in other ways (vault and
+    /// pool deposits, delegations, account groups, liquidations) are refused.
+    ///
+    /// Default: `None` (any destination)
+    pub allowed_destinations: Option<Vec<String>>,
+
+    #[builder(skip)]
+    #[serde(skip)]
+    withdrawals: Arc<Mutex<VecDeque<Withdrawal>>>,
+}
+
+/// At most `amount` of `asset_id` withdrawn within any `period_secs`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WithdrawalLimit {
+    pub asset_id: AssetId,
+    pub amount: Decimal,
+    pub period_secs: u64,
+}
+
+#[derive(Clone, Debug)]
+struct Withdrawal {
+    at: Instant,
+    asset_id: AssetId,
+    amount: Decimal,
+}
+
+impl SigningPolicy {
+    /// Check `unsigned` against every rule.
+    ///
+    /// Withdrawals that pass are counted against
+    /// [`withdrawal_limits`](Self::withdrawal_limits) immediately, so only
+    /// check transactions that are about to be signed.
+    pub fn check(&self, unsigned: &UnsignedTransaction) -> Result<(), PolicyViolation> {
+        let runtime_call = unsigned.runtime_call();
+        let call = call_name(runtime_call);
+        if let Some(allowed) = &self.allowed_calls {
+            let category = call.split('/').next().unwrap_or_default();
+            if !allowed
+                .iter()
+                .any(|allowed| *allowed == call || allowed == category)
+            {
+                return Err(PolicyViolation::CallNotAllowed(call.clone()));
+            }
+        }
+        if let Some(limit) = self.max_fee
+            && unsigned.max_fee() > limit
+        {
+            return Err(PolicyViolation::FeeTooHigh {
+                max_fee: unsigned.max_fee(),
+                limit,
+            });
+        }
+
+        match runtime_call {
+            RuntimeCall::Exchange(CallMessage::User(action)) => {
+                self.check_user_action(action, call)
+            }
+            RuntimeCall::Exchange(_) => self.check_unguarded(call),
+            RuntimeCall::Bank(BankCall::TransferWithMemo { to, .. }) => {
+                self.check_destination(to.to_string())?;
+                self.check_unmetered(call)
+            }
+            RuntimeCall::Warp(WarpCall::TransferRemote { recipient, .. }) => {
+                self.check_destination(format!("0x{}", hex::encode(recipient.0)))?;
+                self.check_unmetered(call)
+            }
+            _ => self.check_unguarded(call),
+        }
+    }
+
+    #[allow(deprecated)]
+    fn check_user_action(
+        &self,
+        action: &UserAction<Address>,
+        call: String,
+    ) -> Result<(), PolicyViolation> {
+        match action {
+            UserAction::PlaceOrders {
+                market_id, orders, ..
+            } => self.check_orders(*market_id, orders),
+            UserAction::CancelAndPlaceOrders {
+                market_id, places, ..
+            } => self.check_orders(*market_id, places),
+            UserAction::AmendOrders {
+                market_id, orders, ..
+            } => {
+                self.check_market(*market_id)?;
+                orders.iter().try_for_each(|amend| {
+                    self.check_order(amend.place.size.into(), Some(amend.place.price.into()))
+                })
+            }
+            UserAction::CreateTriggerOrders {
+                market_id,
+                trigger_orders,
+                ..
+            } => {
+                self.check_market(*market_id)?;
+                trigger_orders
+                    .iter()
+                    .try_for_each(|trigger| match trigger.size {
+                        Some(size) => {
+                            self.check_order(size.into(), Some(trigger.order_price.into()))
+                        }
+                        None => Ok(()),
+                    })
+            }
+            UserAction::CreatePositionTpsl {
+                market_id,
+                tpsl_pair,
+                size,
+                ..
+            } => {
+                self.check_market(*market_id)?;
+                let price = [&tpsl_pair.tp, &tpsl_pair.sl]
+                    .into_iter()
+                    .flatten()
+                    .map(|tpsl| Decimal::from(tpsl.order_price))
+                    .max();
+                match size {
+                    Some(size) => self.check_order((*size).into(), price),
+                    None => Ok(()),
+                }
+            }
+            UserAction::CreateTwapOrder {
+                market_id,
+                twap_order_args,
+                ..
+            } => {
+                self.check_market(*market_id)?;
+                self.check_order(twap_order_args.total_size.into(), None)
+            }
+            UserAction::UpdateMaxLeverage { market_id, .. } => self.check_market(*market_id),
+            UserAction::Withdraw { asset_id, amount } => {
+                self.record_withdrawal(*asset_id, (*amount).into())
+            }
+            UserAction::Transfer {
+                to,
+                to_address,
+                asset_id,
+                amount,
+                ..
+            } => {
+                if let Some(address) = to_address {
+                    self.check_destination(address.to_string())?;
+                } else if !matches!(to.balance, BalanceBucket::Bank) {
+                    return Ok(());
+                }
+                self.record_withdrawal(*asset_id, (*amount).into())
+            }
+            // Funds stay on the sender's account and no one else gains control.
+            UserAction::Deposit { .. }
+            | UserAction::DepositSpotCollateral { .. }
+            | UserAction::WithdrawSpotCollateral { .. }
+            | UserAction::TransferSpotCollateral { .. }
+            | UserAction::BorrowSpot { .. }
+            | UserAction::CreateSubAccount { .. }
+            | UserAction::TransferToSubAccount { .. }
+            | UserAction::RevokeDelegation { .. }
+            | UserAction::RevokeDelegationV1 { .. }
+            | UserAction::ClaimReferralRewards { .. }
+            | UserAction::DepositIso { .. }
+            | UserAction::WithdrawIso { .. }
+            | UserAction::SetPerpLedgerTradingMode { .. }
+            | UserAction::CancelOrders { .. }
+            | UserAction::CancelMarketOrders { .. }
+            | UserAction::CancelTriggerOrders { .. }
+            | UserAction::CancelTwapOrder { .. }
+            | UserAction::CancelAllOrders { .. }
+            | UserAction::SettleFromPnlPool { .. }
+            | UserAction::QueueWithdrawal { .. }
+            | UserAction::CancelQueuedWithdrawal { .. }
+            | UserAction::ForceWithdrawVault { .. } => Ok(()),
+            // Delegations, vault and pool deposits, account groups,
+            // liquidations and anything newer.
+            _ => self.check_unguarded(call),
+        }
+    }
+
+    fn check_orders(
+        &self,
+        market_id: MarketId,
+        orders: &[NewOrderArgs],
+    ) -> Result<(), PolicyViolation> {
+        self.check_market(market_id)?;
+        orders
+            .iter()
+            .try_for_each(|order| self.check_order(order.size.into(), Some(order.price.into())))
+    }
+
+    fn check_market(&self, market_id: MarketId) -> Result<(), PolicyViolation> {
+        match &self.allowed_markets {
+            Some(allowed) if !allowed.contains(&market_id) => {
+                Err(PolicyViolation::MarketNotAllowed(market_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// `price` is `None` for orders that execute at whatever the market
+    /// offers, which are refused while `max_order_notional` is set.
+    fn check_order(&self, size: Decimal, price: Option<Decimal>) -> Result<(), PolicyViolation> {
+        if let Some(limit) = self.max_order_size
+            && size > limit
+        {
+            return Err(PolicyViolation::OrderTooLarge { size, limit });
+        }
+        if let Some(limit) = self.max_order_notional {
+            let notional = price
+                .ok_or(PolicyViolation::UnpricedOrder)?
+                .saturating_mul(size);
+            if notional > limit {
+                return Err(PolicyViolation::NotionalTooLarge { notional, limit });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_destination(&self, destination: String) -> Result<(), PolicyViolation> {
+        match &self.allowed_destinations {
+            Some(allowed) if !allowed.contains(&destination) => {
+                Err(PolicyViolation::DestinationNotAllowed(destination))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Refuse a call the destination and withdrawal rules cannot evaluate
+    /// while either rule is set.
+    fn check_unguarded(&self, call: String) -> Result<(), PolicyViolation> {
+        if self.allowed_destinations.is_some() {
+            return Err(PolicyViolation::UncheckedTransfer(call));
+        }
+        self.check_unmetered(call)
+    }
+
+    /// Refuse an outflow whose amount cannot be attributed to an exchange
+    /// asset (bank and Warp transfers move raw token units) while
+    /// withdrawal limits are set.
+    fn check_unmetered(&self, call: String) -> Result<(), PolicyViolation> {
+        if self.withdrawal_limits.is_empty() {
+            Ok(())
+        } else {
+            Err(PolicyViolation::UncheckedTransfer(call))
+        }
+    }
+
+    /// Count a withdrawal against the limits for its asset, or reject it if
+    /// any would be exceeded.
+    fn record_withdrawal(&self, asset_id: AssetId, amount: Decimal) -> Result<(), PolicyViolation> {
+        let limits: Vec<_> = self
+            .withdrawal_limits
+            .iter()
+            .filter(|limit| limit.asset_id == asset_id)
+            .collect();
+        if limits.is_empty() {
+            return Ok(());
+        }
+        let now = Instant::now();
+        // The expect is fine here as we just read and write the
+        // object. We never hold a lock in code that can panic.
+        let mut withdrawals = self
+            .withdrawals
+            .lock()
+            .expect("Taking the withdrawals lock can never fail.");
+        let longest = limits
+            .iter()
+            .map(|limit| limit.period_secs)
+            .max()
+            .unwrap_or(0);
+        while withdrawals
+            .front()
+            .is_some_and(|w| now.duration_since(w.at) >= Duration::from_secs(longest))
+        {
+            withdrawals.pop_front();
+        }
+        for limit in limits {
+            let period = Duration::from_secs(limit.period_secs);
+            let withdrawn: Decimal = withdrawals
+                .iter()
+                .filter(|w| w.asset_id == asset_id && now.duration_since(w.at) < period)
+                .map(|w| w.amount)
+                .sum();
+            if withdrawn + amount > limit.amount {
+                return Err(PolicyViolation::WithdrawalLimitExceeded {
+                    asset_id,
+                    amount,
+                    withdrawn,
+                    limit: limit.amount,
+                    period_secs: limit.period_secs,
+                });
+            }
+        }
+        withdrawals.push_back(Withdrawal {
+            at: now,
+            asset_id,
+            amount,
+        });
+        Ok(())
+    }
+}
+
+/// `"User/PlaceOrders"` for exchange calls, the module name otherwise.
+pub(crate) fn call_name(runtime_call: &RuntimeCall) -> String {
+    match runtime_call {
+        RuntimeCall::Exchange(call_message) => call_message.msg_type(),
+        RuntimeCall::Bank(_) => "Bank".to_string(),
+        RuntimeCall::Warp(_) => "Warp".to_string(),
+        _ => "Unknown".to_string(),
+    }
+}
+
+/// A [`Signer`] that only signs transactions its [`SigningPolicy`] allows.
+///
+/// Messages are decoded from either signable form
+/// ([`to_bytes`](UnsignedTransaction::to_bytes) or
+/// [`to_message_bytes`](UnsignedTransaction::to_message_bytes)); anything
+/// else is refused with [`PolicyViolation::UnrecognizedMessage`].
+#[derive(Clone, Debug)]
+pub struct PolicySigner<S> {
+    signer: S,
+    policy: SigningPolicy,
+}
+
+impl<S: Signer> PolicySigner<S> {
+    pub fn new(signer: S, policy: SigningPolicy) -> Self {
+        Self { signer, policy }
+    }
+
+    pub fn policy(&self) -> &SigningPolicy {
+        &self.policy
+    }
+
+    fn check(&self, message: &[u8]) -> SDKResult<()> {
+        let unsigned = UnsignedTransaction::decode_signable(message)
+            .map_err(|_| PolicyViolation::UnrecognizedMessage)?;
+        Ok(self.policy.check(&unsigned)?)
+    }
+}
+
+impl<S: Signer> Signer for PolicySigner<S> {
+    fn pubkey(&self) -> [u8; 32] {
+        self.signer.pubkey()
+    }
+
+    fn sign_message(&self, message: &[u8]) -> SDKResult<[u8; 64]> {
+        self.check(message)?;
+        self.signer.sign_message(message)
+    }
+
+    fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+        if let Err(e) = self.check(message) {
+            return Box::pin(futures::future::ready(Err::<[u8; 64], SDKError>(e)));
+        }
+        self.signer.sign_message_async(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_exchange_interface::message::{NewTwapOrderArgs, Tpsl, TpslPair, TransferEndpoint};
+    use bullet_exchange_interface::transaction::bank::{Coins, TokenId};
+    use bullet_exchange_interface::transaction::{Amount, WarpBytes32};
+    use bullet_exchange_interface::types::{OrderType, Side, TriggerPriceCondition};
+
+    use super::*;
+    use crate::testing::{
+        mock_client, mount_connect_mocks, mount_submit_ok, positive, submit_count, unsigned,
+    };
+    use crate::{Client, Keypair, NewOrderExt, Transaction};
+
+    fn place_order(market_id: u16, price: i64, size: i64) -> CallMessage {
+        CallMessage::User(UserAction::PlaceOrders {
+            market_id: MarketId(market_id),
+            orders: vec![NewOrderArgs::limit(
+                positive(price, 0),
+                positive(size, 0),
+                Side::Bid,
+            )],
+            replace: false,
+            sub_account_index: None,
+        })
+    }
+
+    fn transfer(to_address: Option<Address>, amount: i64) -> CallMessage {
+        let endpoint = TransferEndpoint {
+            sub_account_index: None,
+            balance: BalanceBucket::Bank,
+        };
+        CallMessage::User(UserAction::Transfer {
+            from: endpoint.clone(),
+            to: endpoint,
+            to_address,
+            asset_id: AssetId(0),
+            amount: positive(amount, 0),
+            memo: "".into(),
+        })
+    }
+
+    #[tokio::test]
+    async fn restricts_calls_markets_and_order_sizes() {
+        let (_server, client) = mock_client().await;
+        let policy = SigningPolicy::builder()
+            .allowed_calls(vec![
+                "User/PlaceOrders".into(),
+                "User/CancelAllOrders".into(),
+            ])
+            .allowed_markets(vec![MarketId(0)])
+            .max_order_size(Decimal::from(2))
+            .max_order_notional(Decimal::from(100_000))
+            .build();
+        let check = |call_message| policy.check(&unsigned(call_message, &client));
+
+        check(place_order(0, 50_000, 2)).unwrap();
+        assert_eq!(
+            check(transfer(None, 1)),
+            Err(PolicyViolation::CallNotAllowed("User/Transfer".into()))
+        );
+        assert_eq!(
+            check(place_order(1, 50_000, 1)),
+            Err(PolicyViolation::MarketNotAllowed(MarketId(1)))
+        );
+        assert!(matches!(
+            check(place_order(0, 10, 3)),
+            Err(PolicyViolation::OrderTooLarge { .. })
+        ));
+        assert_eq!(
+            check(place_order(0, 60_000, 2)),
+            Err(PolicyViolation::NotionalTooLarge {
+                notional: Decimal::from(120_000),
+                limit: Decimal::from(100_000),
+            })
+        );
+
+        let policy = SigningPolicy::builder()
+            .allowed_calls(vec!["User".into()])
+            .max_fee(1_000)
+            .build();
+        assert!(matches!(
+            policy.check(&unsigned(place_order(0, 1, 1), &client)),
+            Err(PolicyViolation::FeeTooHigh { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn limits_withdrawals_and_destinations() {
+        let (_server, client) = mock_client().await;
+        let friend = Address(Keypair::generate().pubkey());
+        let policy = SigningPolicy::builder()
+            .withdrawal_limits(vec![WithdrawalLimit {
+                asset_id: AssetId(0),
+                amount: Decimal::from(100),
+                period_secs: 3600,
+            }])
+            .allowed_destinations(vec![friend.to_string()])
+            .build();
+        let shared = policy.clone();
+
+        policy
+            .check(&unsigned(transfer(None, 60), &client))
+            .unwrap();
+        assert_eq!(
+            shared.check(&unsigned(transfer(Some(friend), 50), &client)),
+            Err(PolicyViolation::WithdrawalLimitExceeded {
+                asset_id: AssetId(0),
+                amount: Decimal::from(50),
+                withdrawn: Decimal::from(60),
+                limit: Decimal::from(100),
+                period_secs: 3600,
+            })
+        );
+        shared
+            .check(&unsigned(transfer(Some(friend), 40), &client))
+            .unwrap();
+
+        let stranger = Address(Keypair::generate().pubkey());
+        assert_eq!(
+            policy.check(&unsigned(transfer(Some(stranger), 1), &client)),
+            Err(PolicyViolation::DestinationNotAllowed(stranger.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn refuses_transfers_the_fund_rules_cannot_check() {
+        let (_server, client) = mock_client().await;
+        let friend = Address(Keypair::generate().pubkey());
+        let destinations = SigningPolicy::builder()
+            .allowed_destinations(vec![friend.to_string()])
+            .build();
+        let limits = SigningPolicy::builder()
+            .withdrawal_limits(vec![WithdrawalLimit {
+                asset_id: AssetId(0),
+                amount: Decimal::from(100),
+                period_secs: 3600,
+            }])
+            .build();
+
+        let unchecked = [
+            CallMessage::User(UserAction::DepositToVault {
+                vault_address: friend,
+                asset_id: AssetId(0),
+                amount: positive(1, 0),
+            }),
+            CallMessage::User(UserAction::DelegateUser {
+                delegate: friend,
+                name: "".into(),
+            }),
+            CallMessage::User(UserAction::DelegateUserV1 {
+                delegate: friend,
+                name: "".into(),
+                sub_account_index: None,
+            }),
+            CallMessage::User(UserAction::DelegateUserV2 {
+                delegate: friend,
+                name: "".into(),
+                sub_account_index: None,
+                expires_at: None,
+                flags: 0,
+            }),
+            CallMessage::User(UserAction::SetAccountGroup {
+                address: None,
+                group: friend,
+                sub_account_index: None,
+            }),
+        ];
+        for call_message in unchecked {
+            let name = call_message.msg_type();
+            let unsigned = unsigned(call_message, &client);
+            for policy in [&destinations, &limits] {
+                assert_eq!(
+                    policy.check(&unsigned),
+                    Err(PolicyViolation::UncheckedTransfer(name.clone()))
+                );
+            }
+            SigningPolicy::default().check(&unsigned).unwrap();
+        }
+
+        let bridge = RuntimeCall::Warp(WarpCall::TransferRemote {
+            warp_route: WarpBytes32([1; 32]),
+            destination_domain: 1,
+            recipient: WarpBytes32([2; 32]),
+            amount: Amount(1),
+            relayer: None,
+            gas_payment_limit: Amount(0),
+        });
+        let bank = RuntimeCall::Bank(BankCall::TransferWithMemo {
+            to: friend,
+            coins: Coins {
+                amount: Amount(1),
+                token_id: TokenId([3; 32]),
+            },
+            memo: "".into(),
+        });
+        for (runtime_call, name) in [(bridge, "Warp"), (bank, "Bank")] {
+            let unsigned = UnsignedTransaction::from_runtime_call(
+                runtime_call,
+                10_000_000,
+                0,
+                None,
+                None,
+                &client,
+            )
+            .unwrap();
+            assert_eq!(
+                limits.check(&unsigned),
+                Err(PolicyViolation::UncheckedTransfer(name.into()))
+            );
+        }
+        // Cancels and other calls that keep funds in place still pass.
+        let cancel = CallMessage::User(UserAction::CancelAllOrders {
+            sub_account_index: None,
+        });
+        destinations.check(&unsigned(cancel, &client)).unwrap();
+    }
+
+    #[tokio::test]
+    async fn checks_notional_of_twaps_and_position_tpsl() {
+        let (_server, client) = mock_client().await;
+        let policy = SigningPolicy::builder()
+            .max_order_notional(Decimal::from(100_000))
+            .build();
+        let twap = CallMessage::User(UserAction::CreateTwapOrder {
+            market_id: MarketId(0),
+            twap_order_args: NewTwapOrderArgs {
+                side: Side::Bid,
+                total_size: positive(1, 0),
+                reduce_only: false,
+                total_duration_seconds: 600,
+            },
+            sub_account_index: None,
+        });
+        assert_eq!(
+            policy.check(&unsigned(twap.clone(), &client)),
+            Err(PolicyViolation::UnpricedOrder)
+        );
+        SigningPolicy::default()
+            .check(&unsigned(twap, &client))
+            .unwrap();
+
+        let tpsl = |size| {
+            let tpsl = |price| Tpsl {
+                order_price: positive(price, 0),
+                trigger_price: positive(price, 0),
+                price_condition: TriggerPriceCondition::Mark,
+                order_type: OrderType::ImmediateOrCancel,
+            };
+            CallMessage::User(UserAction::CreatePositionTpsl {
+                market_id: MarketId(0),
+                tpsl_pair: TpslPair {
+                    tp: Some(tpsl(60_000)),
+                    sl: Some(tpsl(40_000)),
+                },
+                size: Some(positive(size, 0)),
+                sub_account_index: None,
+            })
+        };
+        policy.check(&unsigned(tpsl(1), &client)).unwrap();
+        assert_eq!(
+            policy.check(&unsigned(tpsl(2), &client)),
+            Err(PolicyViolation::NotionalTooLarge {
+                notional: Decimal::from(120_000),
+                limit: Decimal::from(100_000),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn policy_signers_refuse_disallowed_messages() {
+        let (_server, client) = mock_client().await;
+        let policy = SigningPolicy::builder()
+            .allowed_markets(vec![MarketId(0)])
+            .build();
+        let signer = PolicySigner::new(Keypair::generate(), policy);
+
+        Transaction::sign(unsigned(place_order(0, 1, 1), &client), &signer).unwrap();
+        let message = unsigned(place_order(1, 1, 1), &client)
+            .to_message_bytes()
+            .unwrap();
+        assert!(matches!(
+            signer.sign_message_async(&message).await,
+            Err(SDKError::PolicyViolation(
+                PolicyViolation::MarketNotAllowed(_)
+            ))
+        ));
+        assert!(matches!(
+            signer.sign_message(b"hello"),
+            Err(SDKError::PolicyViolation(
+                PolicyViolation::UnrecognizedMessage
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn clients_check_their_policy_before_signing() {
+        let server = wiremock::MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        mount_submit_ok(&server).await;
+        let client = Client::builder()
+            .network(server.uri())
+            .keypair(Keypair::generate())
+            .signing_policy(
+                SigningPolicy::builder()
+                    .allowed_calls(vec!["User/PlaceOrders".into()])
+                    .build(),
+            )
+            .build()
+            .await
+            .unwrap();
+
+        client
+            .send_call_message(place_order(0, 1, 1))
+            .await
+            .unwrap();
+        let err = client
+            .send_call_message(transfer(None, 1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SDKError::PolicyViolation(_)), "{err:?}");
+        let err = Transaction::builder()
+            .call_message(transfer(None, 1))
+            .max_fee(10_000_000)
+            .client(&client)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, SDKError::PolicyViolation(_)), "{err:?}");
+        assert_eq!(submit_count(&server).await, 1);
+    }
+}
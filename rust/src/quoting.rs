@@ -0,0 +1,682 @@
+//! Desired-state quote management for market makers.
+//!
+//! Instead of issuing cancel/place calls by hand, describe the resting orders
+//! you *want* on a market and let the client work out the difference:
+//!
+//! ```ignore
+//! use bullet_rust_sdk::*;
+//!
+//! let desired = vec![
+//!     NewOrderArgs::post_only(bid_px, size, Side::Bid),
+//!     NewOrderArgs::post_only(ask_px, size, Side::Ask),
+//! ];
+//! let outcome = client.reconcile_quotes(market_id, desired, None).await?;
+//! println!("{} transaction(s), {} amended", outcome.responses.len(), outcome.plan.amend.len());
+//! ```
+//!
+//! [`QuotePlan::new`] does the diffing without touching the network, so it
+//! also works against a locally tracked order book.
+//!
+//! [`QuoteLadder`] generates the desired set: layered bids and asks around a
+//! reference price, snapped to the market's tick and lot sizes.
+//!
+//! ```ignore
+//! let info = client.symbol_info(market_id).unwrap();
+//! let desired = QuoteLadder::builder()
+//!     .reference_price(mid)
+//!     .levels(5)
+//!     .spacing(LadderSpacing::Bps(Decimal::from(10)))
+//!     .base_size(Decimal::new(1, 2))
+//!     .size_curve(SizeCurve::Geometric(Decimal::new(15, 1)))
+//!     .post_only(true)
+//!     .build()
+//!     .orders(&info);
+//! client.reconcile_quotes(market_id, desired, None).await?;
+//! ```
+
+use bullet_exchange_interface::decimals::PositiveDecimal;
+use bullet_exchange_interface::message::{AmendOrderArgs, CancelOrderArgs, NewOrderArgs};
+use bullet_exchange_interface::types::{ClientOrderId, MarketId, OrderId, OrderType, Side};
+use rust_decimal::Decimal;
+
+use crate::generated::types::{BinanceOrder, SubmitTxResponse};
+use crate::{Client, NewOrderExt, SDKResult, SymbolInfo};
+
+/// An order currently resting on the book, as seen by the reconciler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestingOrder {
+    pub order_id: OrderId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub side: Side,
+    pub price: PositiveDecimal,
+    /// Unfilled size.
+    pub size: PositiveDecimal,
+    pub order_type: OrderType,
+    pub reduce_only: bool,
+}
+
+impl RestingOrder {
+    /// Convert a row from `query_open_orders` / [`Client::my_open_orders`].
+    ///
+    /// Returns `None` for trigger orders (they don't rest on the book) and for
+    /// rows whose side or quantities can't be interpreted.
+    pub fn from_open_order(order: &BinanceOrder) -> Option<Self> {
+        if order.is_trigger {
+            return None;
+        }
+        let side = match order.side.as_str() {
+            "BUY" => Side::Bid,
+            "SELL" => Side::Ask,
+            _ => return None,
+        };
+        Some(RestingOrder {
+            order_id: OrderId(order.order_id),
+            client_order_id: order
+                .client_order_id
+                .as_deref()
+                .and_then(|id| id.parse().ok()),
+            side,
+            price: PositiveDecimal::new(order.price)?,
+            size: PositiveDecimal::new(order.orig_qty - order.executed_qty)?,
+            order_type: match order.time_in_force.as_str() {
+                "GTX" => OrderType::PostOnly,
+                "IOC" => OrderType::ImmediateOrCancel,
+                "FOK" => OrderType::FillOrKill,
+                _ => OrderType::Limit,
+            },
+            reduce_only: order.reduce_only,
+        })
+    }
+
+    /// Whether this order already satisfies `desired` (same side, price,
+    /// remaining size, order type and reduce-only flag).
+    fn satisfies(&self, desired: &NewOrderArgs) -> bool {
+        self.side == desired.side
+            && self.price == desired.price
+            && self.size == desired.size
+            && self.order_type == desired.order_type
+            && self.reduce_only == desired.reduce_only
+    }
+
+    fn cancel_args(&self) -> CancelOrderArgs {
+        CancelOrderArgs {
+            order_id: Some(self.order_id),
+            client_order_id: None,
+        }
+    }
+}
+
+/// The changes needed to turn one set of resting orders into another.
+///
+/// Built by [`QuotePlan::new`] and submitted by
+/// [`Client::submit_quote_plan`] in at most two transactions.
+#[derive(Debug, Clone, Default)]
+pub struct QuotePlan {
+    /// Orders to cancel outright.
+    pub cancel: Vec<CancelOrderArgs>,
+    /// Stale orders atomically replaced by a desired one.
+    pub amend: Vec<AmendOrderArgs>,
+    /// Desired orders to place.
+    pub place: Vec<NewOrderArgs>,
+    /// Resting orders that already match a desired order and are left alone.
+    pub keep: Vec<OrderId>,
+}
+
+impl QuotePlan {
+    /// Diff `current` resting orders on one market against the `desired` set.
+    ///
+    /// Orders matching a desired order on side, price, remaining size, order
+    /// type and reduce-only flag are kept. The remaining stale and missing orders are paired into amends —
+    /// same side first, closest to the top of book first, then across sides —
+    /// and whatever is left over becomes plain cancels or places.
+    ///
+    /// Stale orders are always cancelled by ID, so orders missing from
+    /// `current` (such as trigger orders) are never touched. A kept order
+    /// retains its original `client_order_id`; a desired order's
+    /// `client_order_id` only applies if it ends up being placed.
+    pub fn new(current: &[RestingOrder], desired: &[NewOrderArgs]) -> Self {
+        let mut stale: Vec<&RestingOrder> = current.iter().collect();
+        let mut missing: Vec<&NewOrderArgs> = Vec::new();
+        let mut keep = Vec::new();
+        for order in desired {
+            match stale.iter().position(|resting| resting.satisfies(order)) {
+                Some(pos) => keep.push(stale.remove(pos).order_id),
+                None => missing.push(order),
+            }
+        }
+
+        let mut plan = QuotePlan {
+            keep,
+            ..Default::default()
+        };
+        let mut stale_left = Vec::new();
+        let mut missing_left = Vec::new();
+        for side in [Side::Bid, Side::Ask] {
+            let mut side_stale: Vec<_> = stale.iter().filter(|o| o.side == side).copied().collect();
+            let mut side_missing: Vec<_> =
+                missing.iter().filter(|o| o.side == side).copied().collect();
+            side_stale.sort_by(|a, b| best_first(side, a.price, b.price));
+            side_missing.sort_by(|a, b| best_first(side, a.price, b.price));
+
+            let pairs = side_stale.len().min(side_missing.len());
+            plan.pair(side_stale.drain(..pairs), side_missing.drain(..pairs));
+            stale_left.extend(side_stale);
+            missing_left.extend(side_missing);
+        }
+
+        let pairs = stale_left.len().min(missing_left.len());
+        plan.pair(stale_left.drain(..pairs), missing_left.drain(..pairs));
+        plan.cancel = stale_left.iter().map(|o| o.cancel_args()).collect();
+        plan.place = missing_left.into_iter().cloned().collect();
+        plan
+    }
+
+    /// Whether the plan changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.cancel.is_empty() && self.amend.is_empty() && self.place.is_empty()
+    }
+
+    fn pair<'a>(
+        &mut self,
+        stale: impl Iterator<Item = &'a RestingOrder>,
+        missing: impl Iterator<Item = &'a NewOrderArgs>,
+    ) {
+        self.amend
+            .extend(stale.zip(missing).map(|(resting, desired)| AmendOrderArgs {
+                cancel: resting.cancel_args(),
+                place: desired.clone(),
+            }));
+    }
+}
+
+/// Order prices so the level nearest the top of book comes first.
+fn best_first(side: Side, a: PositiveDecimal, b: PositiveDecimal) -> std::cmp::Ordering {
+    match side {
+        Side::Bid => b.cmp(&a),
+        Side::Ask => a.cmp(&b),
+    }
+}
+
+/// A submitted [`QuotePlan`] and the transaction responses, in submission
+/// order.
+#[derive(Debug)]
+pub struct QuoteReconciliation {
+    pub plan: QuotePlan,
+    pub responses: Vec<SubmitTxResponse>,
+}
+
+impl Client {
+    /// Move the client's resting orders on a market to `desired`.
+    ///
+    /// Fetches the account's open orders on `market_id`, builds a
+    /// [`QuotePlan`] and submits it with
+    /// [`submit_quote_plan`](Self::submit_quote_plan).
+    pub async fn reconcile_quotes(
+        &self,
+        market_id: MarketId,
+        desired: Vec<NewOrderArgs>,
+        sub_account_index: Option<u8>,
+    ) -> SDKResult<QuoteReconciliation> {
+        let address = self.account_address(sub_account_index)?;
+        let address = address.as_str();
+        let current: Vec<RestingOrder> = self
+            .with_failover(|api| async move { api.query_open_orders(address, None).await })
+            .await?
+            .into_inner()
+            .iter()
+            .filter(|order| order.market_id == market_id.0)
+            .filter_map(RestingOrder::from_open_order)
+            .collect();
+        self.reconcile_quotes_against(market_id, &current, desired, sub_account_index)
+            .await
+    }
+
+    /// Like [`reconcile_quotes`](Self::reconcile_quotes), but diffs against
+    /// caller-supplied resting orders (e.g. from a live order tracker) instead
+    /// of fetching them.
+    pub async fn reconcile_quotes_against(
+        &self,
+        market_id: MarketId,
+        current: &[RestingOrder],
+        desired: Vec<NewOrderArgs>,
+        sub_account_index: Option<u8>,
+    ) -> SDKResult<QuoteReconciliation> {
+        let plan = QuotePlan::new(current, &desired);
+        let responses = self
+            .submit_quote_plan(market_id, &plan, sub_account_index)
+            .await?;
+        Ok(QuoteReconciliation { plan, responses })
+    }
+
+    /// Submit a [`QuotePlan`]: cancels, then amends, then places, skipping
+    /// empty steps.
+    ///
+    /// A plan from [`QuotePlan::new`] needs at most two transactions. If a
+    /// step fails, the error is returned and later steps are not sent; earlier
+    /// ones have already been applied.
+    pub async fn submit_quote_plan(
+        &self,
+        market_id: MarketId,
+        plan: &QuotePlan,
+        sub_account_index: Option<u8>,
+    ) -> SDKResult<Vec<SubmitTxResponse>> {
+        let mut responses = Vec::new();
+        if !plan.cancel.is_empty() {
+            responses.push(
+                self.cancel_orders(market_id, plan.cancel.clone(), sub_account_index)
+                    .await?,
+            );
+        }
+        if !plan.amend.is_empty() {
+            responses.push(
+                self.amend_orders(market_id, plan.amend.clone(), sub_account_index)
+                    .await?,
+            );
+        }
+        if !plan.place.is_empty() {
+            responses.push(
+                self.place_orders(market_id, plan.place.clone(), false, sub_account_index)
+                    .await?,
+            );
+        }
+        Ok(responses)
+    }
+}
+
+// ── Quote ladder ────────────────────────────────────────────────────────────
+
+/// Distance between consecutive ladder levels.
+#[derive(Debug, Clone, Copy)]
+pub enum LadderSpacing {
+    /// Basis points of the reference price.
+    Bps(Decimal),
+    /// Multiples of the market's tick size.
+    Ticks(u32),
+}
+
+/// How order size changes from the innermost level outwards.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum SizeCurve {
+    /// Every level gets `base_size`.
+    #[default]
+    Flat,
+    /// Level `i` gets `base_size + i * increment`.
+    Linear(Decimal),
+    /// Level `i` gets `base_size * ratio^i`.
+    Geometric(Decimal),
+}
+
+/// Layered quotes on both sides of a reference price.
+///
+/// Level `i` (from 0) sits `i + 1` spacings away from the reference. Prices
+/// are snapped to the tick on the passive side (bids down, asks up) and sizes
+/// down to the lot step; levels that collapse onto the previous level's price,
+/// fall below the minimum quantity, or reach a non-positive price are dropped.
+/// The ladder stops at the first level whose price or size overflows
+/// `Decimal`.
+#[derive(bon::Builder, Debug, Clone)]
+pub struct QuoteLadder {
+    reference_price: Decimal,
+    /// Levels per side.
+    levels: usize,
+    spacing: LadderSpacing,
+    /// Size of the innermost level.
+    base_size: Decimal,
+    #[builder(default)]
+    size_curve: SizeCurve,
+    /// Place post-only orders instead of plain limits.
+    #[builder(default)]
+    post_only: bool,
+}
+
+impl QuoteLadder {
+    /// Bids then asks, each innermost first, ready for
+    /// [`Client::place_orders`] or [`Client::reconcile_quotes`].
+    pub fn orders(&self, info: &SymbolInfo) -> Vec<NewOrderArgs> {
+        let mut orders = self.side(info, Side::Bid);
+        orders.extend(self.side(info, Side::Ask));
+        orders
+    }
+
+    /// One side of the ladder, innermost first.
+    pub fn side(&self, info: &SymbolInfo, side: Side) -> Vec<NewOrderArgs> {
+        let mut orders: Vec<NewOrderArgs> = Vec::with_capacity(self.levels);
+        for level in 0..self.levels {
+            let Some((price, size)) = self.level(info, side, level) else {
+                break;
+            };
+            if orders
+                .last()
+                .is_some_and(|prev| prev.price.as_dec() == price)
+            {
+                continue;
+            }
+            let (Some(price), Some(size)) = (positive(price), positive(size)) else {
+                continue;
+            };
+            if size.as_dec() < info.min_qty {
+                continue;
+            }
+            orders.push(if self.post_only {
+                NewOrderArgs::post_only(price, size, side)
+            } else {
+                NewOrderArgs::limit(price, size, side)
+            });
+        }
+        orders
+    }
+
+    /// Snapped price and size of a level, or `None` if either overflows.
+    fn level(&self, info: &SymbolInfo, side: Side, level: usize) -> Option<(Decimal, Decimal)> {
+        let distance = Decimal::from(level + 1);
+        let offset = match self.spacing {
+            LadderSpacing::Bps(bps) => self
+                .reference_price
+                .checked_mul(bps)?
+                .checked_mul(distance)?
+                .checked_div(BPS)?,
+            LadderSpacing::Ticks(ticks) => info
+                .tick_size
+                .checked_mul(Decimal::from(ticks))?
+                .checked_mul(distance)?,
+        };
+        let price = match side {
+            Side::Bid => self.reference_price.checked_sub(offset)?,
+            Side::Ask => self.reference_price.checked_add(offset)?,
+        };
+        Some((
+            info.checked_snap_price(price, side)?,
+            info.checked_snap_size(self.size_at(level)?)?,
+        ))
+    }
+
+    fn size_at(&self, level: usize) -> Option<Decimal> {
+        match self.size_curve {
+            SizeCurve::Flat => Some(self.base_size),
+            SizeCurve::Linear(increment) => self
+                .base_size
+                .checked_add(increment.checked_mul(Decimal::from(level))?),
+            SizeCurve::Geometric(ratio) => {
+                (0..level).try_fold(self.base_size, |size, _| size.checked_mul(ratio))
+            }
+        }
+    }
+}
+
+const BPS: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// `value` as a strictly positive decimal.
+fn positive(value: Decimal) -> Option<PositiveDecimal> {
+    if value > Decimal::ZERO {
+        PositiveDecimal::new(value)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::metadata::ExchangeMetadata;
+    use crate::testing::{mock_client, symbol};
+
+    fn dec(s: &str) -> PositiveDecimal {
+        PositiveDecimal::try_from(Decimal::from_str(s).unwrap()).unwrap()
+    }
+
+    fn resting(id: u64, side: Side, price: &str, size: &str) -> RestingOrder {
+        RestingOrder {
+            order_id: OrderId(id),
+            client_order_id: None,
+            side,
+            price: dec(price),
+            size: dec(size),
+            order_type: OrderType::Limit,
+            reduce_only: false,
+        }
+    }
+
+    fn cancelled(args: &CancelOrderArgs) -> u64 {
+        args.order_id.unwrap().0
+    }
+
+    #[test]
+    fn matching_orders_are_kept_and_the_rest_amended_on_the_same_side() {
+        let current = [
+            resting(1, Side::Bid, "100", "1"),
+            resting(2, Side::Bid, "99", "1"),
+            resting(3, Side::Ask, "101", "1"),
+        ];
+        let desired = [
+            NewOrderArgs::limit(dec("100"), dec("1"), Side::Bid),
+            NewOrderArgs::limit(dec("98"), dec("1"), Side::Bid),
+            NewOrderArgs::limit(dec("102"), dec("1"), Side::Ask),
+        ];
+
+        let plan = QuotePlan::new(&current, &desired);
+
+        assert_eq!(plan.keep, vec![OrderId(1)]);
+        assert!(plan.cancel.is_empty() && plan.place.is_empty());
+        let amends: Vec<_> = plan
+            .amend
+            .iter()
+            .map(|a| (cancelled(&a.cancel), a.place.price))
+            .collect();
+        assert_eq!(amends, vec![(2, dec("98")), (3, dec("102"))]);
+    }
+
+    #[test]
+    fn leftovers_pair_across_sides_then_cancel() {
+        let current = [
+            resting(1, Side::Bid, "100", "1"),
+            resting(2, Side::Bid, "99", "1"),
+            resting(3, Side::Bid, "98", "1"),
+        ];
+        let desired = [
+            NewOrderArgs::limit(dec("100"), dec("1"), Side::Bid),
+            NewOrderArgs::limit(dec("101"), dec("1"), Side::Ask),
+        ];
+
+        let plan = QuotePlan::new(&current, &desired);
+
+        assert_eq!(plan.amend.len(), 1);
+        assert_eq!(cancelled(&plan.amend[0].cancel), 2);
+        assert_eq!(plan.amend[0].place.side, Side::Ask);
+        assert_eq!(plan.cancel.iter().map(cancelled).collect::<Vec<_>>(), [3]);
+        assert!(plan.place.is_empty());
+    }
+
+    #[test]
+    fn nothing_kept_still_amends_by_id() {
+        let current = [resting(1, Side::Bid, "100", "1")];
+        let desired = [
+            NewOrderArgs::limit(dec("99"), dec("1"), Side::Bid),
+            NewOrderArgs::limit(dec("101"), dec("1"), Side::Ask),
+        ];
+
+        let plan = QuotePlan::new(&current, &desired);
+
+        assert_eq!(plan.amend.len(), 1);
+        assert_eq!(cancelled(&plan.amend[0].cancel), 1);
+        assert_eq!(plan.place.len(), 1);
+        assert!(plan.cancel.is_empty());
+    }
+
+    #[test]
+    fn partially_filled_size_does_not_match() {
+        let current = [resting(1, Side::Bid, "100", "0.5")];
+        let desired = [NewOrderArgs::limit(dec("100"), dec("1"), Side::Bid)];
+
+        let plan = QuotePlan::new(&current, &desired);
+
+        assert!(plan.keep.is_empty());
+        assert_eq!(plan.amend.len(), 1);
+    }
+
+    #[test]
+    fn different_flags_do_not_match() {
+        let current = [
+            resting(1, Side::Bid, "100", "1"),
+            resting(2, Side::Ask, "101", "1"),
+        ];
+        let mut reduce_only = NewOrderArgs::limit(dec("101"), dec("1"), Side::Ask);
+        reduce_only.reduce_only = true;
+        let desired = [
+            NewOrderArgs::post_only(dec("100"), dec("1"), Side::Bid),
+            reduce_only,
+        ];
+
+        let plan = QuotePlan::new(&current, &desired);
+
+        assert!(plan.keep.is_empty());
+        assert_eq!(plan.amend.len(), 2);
+        let post_only = RestingOrder {
+            order_type: OrderType::PostOnly,
+            ..resting(3, Side::Bid, "100", "1")
+        };
+        assert_eq!(
+            QuotePlan::new(&[post_only], &desired[..1]).keep,
+            vec![OrderId(3)]
+        );
+    }
+
+    #[test]
+    fn empty_desired_cancels_everything() {
+        let current = [resting(1, Side::Bid, "100", "1")];
+
+        let plan = QuotePlan::new(&current, &[]);
+
+        assert_eq!(plan.cancel.iter().map(cancelled).collect::<Vec<_>>(), [1]);
+        assert!(QuotePlan::new(&[], &[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn unchanged_quotes_submit_nothing() {
+        let (_server, client) = mock_client().await;
+        let current = [resting(1, Side::Bid, "100", "1")];
+        let desired = vec![NewOrderArgs::limit(dec("100"), dec("1"), Side::Bid)];
+
+        let outcome = client
+            .reconcile_quotes_against(MarketId(0), &current, desired, None)
+            .await
+            .unwrap();
+
+        assert!(outcome.plan.is_empty());
+        assert!(outcome.responses.is_empty());
+    }
+
+    fn btc_info() -> SymbolInfo {
+        // Two price decimals and three quantity decimals: tick 0.01, step 0.001.
+        ExchangeMetadata::from_symbols(&[symbol("BTC-USD", 0, "TRADING")])
+            .symbol_info_by_id(MarketId(0))
+            .unwrap()
+            .clone()
+    }
+
+    fn ladder(spacing: LadderSpacing, size_curve: SizeCurve) -> QuoteLadder {
+        QuoteLadder::builder()
+            .reference_price(Decimal::from(100))
+            .levels(3)
+            .spacing(spacing)
+            .base_size(Decimal::ONE)
+            .size_curve(size_curve)
+            .build()
+    }
+
+    fn prices(orders: &[NewOrderArgs]) -> Vec<PositiveDecimal> {
+        orders.iter().map(|o| o.price).collect()
+    }
+
+    #[test]
+    fn ladder_spaces_levels_in_bps_and_snaps_passively() {
+        let info = btc_info();
+        // 3.333 bps of 100 is 0.03333: bids snap down, asks snap up.
+        let ladder = ladder(LadderSpacing::Bps(Decimal::new(3333, 3)), SizeCurve::Flat);
+
+        let bids = ladder.side(&info, Side::Bid);
+        let asks = ladder.side(&info, Side::Ask);
+
+        assert_eq!(prices(&bids), [dec("99.96"), dec("99.93"), dec("99.90")]);
+        assert_eq!(prices(&asks), [dec("100.04"), dec("100.07"), dec("100.10")]);
+        assert!(
+            bids.iter()
+                .all(|o| o.side == Side::Bid && o.size == dec("1"))
+        );
+    }
+
+    #[test]
+    fn ladder_spaces_levels_in_ticks_and_shapes_sizes() {
+        let info = btc_info();
+        let linear = ladder(
+            LadderSpacing::Ticks(5),
+            SizeCurve::Linear(Decimal::new(5, 1)),
+        );
+        let geometric = ladder(LadderSpacing::Ticks(5), SizeCurve::Geometric(Decimal::TWO));
+
+        let asks = linear.side(&info, Side::Ask);
+        let geometric_sizes: Vec<_> = geometric
+            .side(&info, Side::Bid)
+            .iter()
+            .map(|o| o.size.as_dec())
+            .collect();
+
+        assert_eq!(prices(&asks), [dec("100.05"), dec("100.10"), dec("100.15")]);
+        assert_eq!(asks[2].size, dec("2"));
+        assert_eq!(geometric_sizes, [1, 2, 4].map(Decimal::from));
+    }
+
+    #[test]
+    fn ladder_drops_collapsed_and_undersized_levels() {
+        let info = btc_info();
+        // 0.1 bps of 100 is 0.001, so every bid snaps to 99.99.
+        let collapsed = ladder(LadderSpacing::Bps(Decimal::new(1, 1)), SizeCurve::Flat);
+        let shrinking = ladder(
+            LadderSpacing::Ticks(1),
+            SizeCurve::Geometric(Decimal::new(1, 3)),
+        );
+
+        assert_eq!(collapsed.side(&info, Side::Bid).len(), 1);
+        // 1, 0.001, 0.000001 → the last rounds down to zero.
+        assert_eq!(shrinking.side(&info, Side::Ask).len(), 2);
+    }
+
+    #[test]
+    fn ladder_stops_at_the_first_overflowing_level() {
+        let info = btc_info();
+        let huge_ratio = QuoteLadder::builder()
+            .reference_price(Decimal::from(100))
+            .levels(5)
+            .spacing(LadderSpacing::Ticks(1))
+            .base_size(Decimal::ONE)
+            .size_curve(SizeCurve::Geometric(Decimal::from(10u128.pow(20))))
+            .build();
+        let huge_spacing = ladder(LadderSpacing::Bps(Decimal::MAX), SizeCurve::Flat);
+
+        // 1, 1e20, then 1e40 overflows.
+        assert_eq!(huge_ratio.side(&info, Side::Bid).len(), 2);
+        assert!(huge_spacing.orders(&info).is_empty());
+    }
+
+    #[test]
+    fn ladder_orders_are_post_only_when_requested() {
+        let info = btc_info();
+        let ladder = QuoteLadder::builder()
+            .reference_price(Decimal::from(100))
+            .levels(2)
+            .spacing(LadderSpacing::Ticks(1))
+            .base_size(Decimal::ONE)
+            .post_only(true)
+            .build();
+
+        let orders = ladder.orders(&info);
+
+        assert_eq!(orders.len(), 4);
+        assert!(orders.iter().all(|o| o.order_type == OrderType::PostOnly));
+        assert_eq!(orders[0].side, Side::Bid);
+        assert_eq!(orders[3].side, Side::Ask);
+    }
+}
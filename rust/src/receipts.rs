@@ -1,6 +1,23 @@
+use bullet_exchange_interface::event::Event;
+use bullet_exchange_interface::types::{ClientOrderId, MarketId, OrderId, Side};
+use rust_decimal::Decimal;
 use serde_json::Value;
 
-use crate::generated::types::SubmitTxResponse;
+use crate::generated::types::{LedgerEvent, SubmitTxResponse};
+
+/// A trade reported in a transaction's events. See [`SubmitTxResponse::fills`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    pub market_id: MarketId,
+    pub order_id: OrderId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+    pub is_maker: bool,
+    /// Fee charged on this fill; negative for a maker rebate.
+    pub fee: Decimal,
+}
 
 impl SubmitTxResponse {
     /// Return the Hyperlane message id emitted by a bridge withdrawal, when the
@@ -15,6 +32,71 @@ impl SubmitTxResponse {
             find_message_id_in_map(&event.value, MessageIdSearchContext::default())
         })
     }
+
+    /// Return the trades emitted by the transaction, for both sides of each
+    /// match, in event order.
+    ///
+    /// Event values that don't decode as an exchange trade event are
+    /// skipped. Filter on [`Fill::client_order_id`] or [`Fill::order_id`] to
+    /// pick out one order's fills.
+    pub fn fills(&self) -> Vec<Fill> {
+        self.events.iter().filter_map(fill_from_event).collect()
+    }
+}
+
+fn fill_from_event(event: &LedgerEvent) -> Option<Fill> {
+    let value = Value::Object(event.value.clone());
+    let decoded = serde_json::from_value::<Event<Value>>(value.clone()).or_else(|_| {
+        // The payload may be unwrapped, with the variant named by the event
+        // key instead (e.g. `Exchange/TradeV1`).
+        let variant = snake_case(event.key.rsplit('/').next().unwrap_or_default());
+        serde_json::from_value(serde_json::json!({ variant: value }))
+    });
+    match decoded.ok()? {
+        Event::TradeV1 {
+            market_id,
+            price,
+            size,
+            side,
+            order_id,
+            is_maker,
+            fee,
+            client_order_id,
+            ..
+        }
+        | Event::Trade {
+            market_id,
+            price,
+            size,
+            side,
+            order_id,
+            is_maker,
+            fee,
+            client_order_id,
+            ..
+        } => Some(Fill {
+            market_id,
+            order_id,
+            client_order_id,
+            side,
+            price: price.into(),
+            size: size.into(),
+            is_maker,
+            fee,
+        }),
+        _ => None,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
 }
 
 #[derive(Clone, Copy, Default)]
@@ -127,7 +209,9 @@ fn normalize_key(key: &str) -> String {
 mod tests {
     use serde_json::json;
 
+    use super::*;
     use crate::generated::types::{LedgerEvent, ModuleRef, SubmitTxResponse, TxStatus};
+    use crate::testing::trade_event;
 
     fn response_with_value(value: serde_json::Value) -> SubmitTxResponse {
         let value = value
@@ -213,4 +297,34 @@ mod tests {
 
         assert_eq!(response.message_id(), None);
     }
+
+    #[test]
+    fn fills_decode_trade_events_and_skip_others() {
+        let trade = |client_order_id, is_maker| {
+            trade_event(
+                MarketId(3),
+                Side::Bid,
+                Decimal::new(10125, 2),
+                Decimal::new(5, 1),
+                is_maker,
+                client_order_id,
+            )
+        };
+        let mut response = response_with_value(trade(Some(ClientOrderId(9)), false));
+        let mut unwrapped = response.events[0].clone();
+        unwrapped.key = "Exchange/TradeV1".to_string();
+        unwrapped.value = trade(None, true)["trade_v1"].as_object().unwrap().clone();
+        response.events.push(unwrapped);
+        response
+            .events
+            .extend(response_with_value(json!({ "message_id": "0x00" })).events);
+
+        let fills = response.fills();
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].market_id, MarketId(3));
+        assert_eq!(fills[0].price, Decimal::new(10125, 2));
+        assert_eq!(fills[0].client_order_id, Some(ClientOrderId(9)));
+        assert!(!fills[0].is_maker && fills[1].is_maker);
+    }
 }
@@ -0,0 +1,284 @@
+//! Client side of the `bullet-signer` daemon.
+//!
+//! `bullet-signer` holds the key on a separate host (or process) and signs
+//! over HTTP after checking each request against its policy. [`RemoteSigner`]
+//! implements [`Signer`] on top of it, so a [`Client`](crate::Client) uses it
+//! like a local keypair:
+//!
+//! ```ignore
+//! let signer = RemoteSigner::builder()
+//!     .url("http://127.0.0.1:7419")
+//!     .token(std::env::var("BULLET_SIGNER_TOKEN")?)
+//!     .build()
+//!     .await?;
+//! let client = Client::builder()
+//!     .network(Network::Mainnet)
+//!     .signer(Arc::new(signer))
+//!     .build()
+//!     .await?;
+//! ```
+//!
+//! Protocol (JSON over HTTP, bytes base64-encoded):
+//!
+//! - `GET /v1/pubkey` → [`PubkeyResponse`]
+//! - `POST /v1/sign` with [`SignRequest`] → [`SignResponse`], or a non-2xx
+//!   status with [`ErrorResponse`]
+//!
+//! A `token`, when configured, is sent as `Authorization: Bearer <token>`.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bon::bon;
+use bullet_exchange_interface::address::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::{SDKError, SDKResult, SignFuture, Signer};
+
+/// Body of `GET /v1/pubkey`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PubkeyResponse {
+    /// Base58 address of the signing key.
+    pub address: String,
+}
+
+/// Body of `POST /v1/sign`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignRequest {
+    /// The bytes to sign, base64-encoded: either
+    /// [`UnsignedTransaction::to_bytes`](crate::UnsignedTransaction::to_bytes)
+    /// or [`UnsignedTransaction::to_message_bytes`](crate::UnsignedTransaction::to_message_bytes).
+    pub message: String,
+}
+
+/// Successful response to `POST /v1/sign`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignResponse {
+    /// The 64-byte Ed25519 signature, base64-encoded.
+    pub signature: String,
+}
+
+/// Body of any non-2xx response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+/// A [`Signer`] backed by a `bullet-signer` daemon.
+///
+/// Only signs asynchronously; see [`Signer`] for which paths support that.
+/// Every signature is verified against the daemon's public key before it is
+/// returned.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    url: String,
+    pubkey: [u8; 32],
+    token: Option<String>,
+    http: reqwest::Client,
+}
+
+#[bon]
+impl RemoteSigner {
+    /// Connect to a signer daemon and fetch its public key.
+    #[builder]
+    pub async fn new(
+        /// Base URL of the daemon, e.g. `http://127.0.0.1:7419`.
+        #[builder(into)]
+        url: String,
+        /// Bearer token the daemon was started with.
+        #[builder(into)]
+        token: Option<String>,
+        /// Fail unless the daemon signs with this address, guarding against
+        /// pointing at the wrong daemon.
+        #[builder(into)]
+        expected_address: Option<String>,
+        /// Custom reqwest client.
+        reqwest_client: Option<reqwest::Client>,
+    ) -> SDKResult<Self> {
+        let url = url.trim_end_matches('/').to_string();
+        let http = reqwest_client.unwrap_or_default();
+        let mut request = http.get(format!("{url}/v1/pubkey"));
+        if let Some(token) = &token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(SDKError::SigningFailed(error_message(response).await));
+        }
+        let PubkeyResponse { address } = response.json().await?;
+        if let Some(expected) = expected_address
+            && expected != address
+        {
+            return Err(SDKError::SigningFailed(format!(
+                "remote signer uses {address}, expected {expected}"
+            )));
+        }
+        let Address(pubkey) = address.parse().map_err(|_| {
+            SDKError::SigningFailed(format!("remote signer returned invalid address {address}"))
+        })?;
+        Ok(Self {
+            url,
+            pubkey,
+            token,
+            http,
+        })
+    }
+}
+
+impl RemoteSigner {
+    async fn request_signature(&self, message: &[u8]) -> SDKResult<[u8; 64]> {
+        let mut request = self
+            .http
+            .post(format!("{}/v1/sign", self.url))
+            .json(&SignRequest {
+                message: BASE64.encode(message),
+            });
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SDKError::SigningFailed(format!("remote signer unreachable: {e}")))?;
+        if !response.status().is_success() {
+            return Err(SDKError::SigningFailed(error_message(response).await));
+        }
+        let SignResponse { signature } = response
+            .json()
+            .await
+            .map_err(|e| SDKError::SigningFailed(format!("invalid remote signer response: {e}")))?;
+        let signature: [u8; 64] = BASE64
+            .decode(signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                SDKError::SigningFailed("remote signer returned a malformed signature".into())
+            })?;
+
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&self.pubkey)
+            .map_err(|e| SDKError::SigningFailed(e.to_string()))?;
+        verifying_key
+            .verify_strict(message, &ed25519_dalek::Signature::from_bytes(&signature))
+            .map_err(|_| {
+                SDKError::SigningFailed("remote signer returned an invalid signature".into())
+            })?;
+        Ok(signature)
+    }
+}
+
+/// Describe a non-2xx daemon response, including its `error` if present.
+async fn error_message(response: reqwest::Response) -> String {
+    let status = response.status();
+    match response.json::<ErrorResponse>().await {
+        Ok(ErrorResponse { error }) => {
+            format!("remote signer rejected the request ({status}): {error}")
+        }
+        Err(_) => format!("remote signer rejected the request ({status})"),
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn pubkey(&self) -> [u8; 32] {
+        self.pubkey
+    }
+
+    fn sign_message(&self, _: &[u8]) -> SDKResult<[u8; 64]> {
+        Err(SDKError::AsyncSignerRequired)
+    }
+
+    fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+        Box::pin(self.request_signature(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use super::*;
+    use crate::Keypair;
+
+    /// A daemon stand-in that signs with `keypair`.
+    async fn mock_daemon(keypair: Keypair) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/pubkey"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(PubkeyResponse {
+                address: keypair.address(),
+            }))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sign"))
+            .respond_with(move |request: &Request| {
+                let SignRequest { message } = request.body_json().unwrap();
+                let message = BASE64.decode(message).unwrap();
+                if message == b"forbidden" {
+                    return ResponseTemplate::new(403).set_body_json(ErrorResponse {
+                        error: "action not allowed".into(),
+                    });
+                }
+                // Tampered signatures for "tamper", valid ones otherwise.
+                let mut signature = keypair.sign_message(&message).unwrap();
+                if message == b"tamper" {
+                    signature[0] ^= 1;
+                }
+                ResponseTemplate::new(200).set_body_json(SignResponse {
+                    signature: BASE64.encode(signature),
+                })
+            })
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn signs_through_the_daemon_and_verifies_signatures() {
+        let keypair = Keypair::generate();
+        let server = mock_daemon(keypair.clone()).await;
+
+        let signer = RemoteSigner::builder()
+            .url(server.uri())
+            .token("secret")
+            .expected_address(keypair.address())
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+        assert_eq!(
+            signer.sign_message_async(b"hello").await.unwrap(),
+            keypair.sign_message(b"hello").unwrap()
+        );
+        assert!(matches!(
+            signer.sign_message(b"hello"),
+            Err(SDKError::AsyncSignerRequired)
+        ));
+        let err = signer.sign_message_async(b"forbidden").await.unwrap_err();
+        assert!(err.to_string().contains("action not allowed"), "{err}");
+        let err = signer.sign_message_async(b"tamper").await.unwrap_err();
+        assert!(err.to_string().contains("invalid signature"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn rejects_unexpected_daemons() {
+        let server = mock_daemon(Keypair::generate()).await;
+
+        let err = RemoteSigner::builder()
+            .url(server.uri())
+            .token("secret")
+            .expected_address(Keypair::generate().address())
+            .build()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SDKError::SigningFailed(_)), "{err:?}");
+
+        let err = RemoteSigner::builder()
+            .url(server.uri())
+            .build()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SDKError::SigningFailed(_)), "{err:?}");
+    }
+}
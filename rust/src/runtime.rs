@@ -0,0 +1,48 @@
+//! Portable async runtime helpers shared by the SDK's background tasks.
+
+/// Spawn a background future on the target's executor.
+///
+/// Native uses [`tokio::spawn`] (requires `Send`); wasm uses
+/// [`wasm_bindgen_futures::spawn_local`] (no `Send` required since JS is
+/// single-threaded).
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn<F>(fut: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(fut);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn spawn<F>(fut: F)
+where
+    F: std::future::Future<Output = ()> + 'static,
+{
+    wasm_bindgen_futures::spawn_local(fut);
+}
+
+/// Like [`spawn`], but returns `false` instead of panicking when there is no
+/// runtime to spawn on. For use from `Drop`, which may run during unwinding
+/// where a second panic would abort.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn try_spawn<F>(fut: F) -> bool
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(fut);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn try_spawn<F>(fut: F) -> bool
+where
+    F: std::future::Future<Output = ()> + 'static,
+{
+    wasm_bindgen_futures::spawn_local(fut);
+    true
+}
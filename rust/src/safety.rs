@@ -0,0 +1,1328 @@
+//! Safety controls that pull resting orders when a bot stops behaving.
+//!
+//! A [`DeadMansSwitch`] expects the application to call
+//! [`heartbeat`](DeadMansSwitch::heartbeat) regularly — typically once per
+//! quoting loop iteration. If no heartbeat arrives within the configured
+//! window (the loop hung, the process is wedged on a lock, the strategy
+//! stopped making decisions), the switch cancels all orders on the configured
+//! markets and sub-accounts and reports what it sent.
+//!
+//! [`Client::kill_switch`] is the manual counterpart for incident response:
+//! it cancels everything on the main account and every sub-account,
+//! optionally flattens positions, and keeps going until the API confirms no
+//! orders are left.
+//!
+//! [`OrderGuard`] scopes orders to a block of code: orders placed with
+//! [`Client::place_orders_guarded`] are cancelled when the guard is dropped,
+//! unless it is disarmed first.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use bullet_rust_sdk::{Client, DeadMansSwitch, DeadMansSwitchConfig};
+//!
+//! let client = Arc::new(Client::builder().network(network).keypair(keypair).build().await?);
+//! let config = DeadMansSwitchConfig::builder()
+//!     .window(Duration::from_secs(10))
+//!     .markets(vec![btc])
+//!     .build();
+//! let mut switch = DeadMansSwitch::spawn(client.clone(), config);
+//!
+//! loop {
+//!     requote(&client).await?;
+//!     switch.heartbeat();
+//! }
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::task::Poll;
+use std::time::Duration;
+
+use bon::bon;
+use bullet_exchange_interface::message::{CancelOrderArgs, NewOrderArgs};
+use bullet_exchange_interface::types::ClientOrderId;
+use futures::StreamExt;
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, Either};
+use futures::task::AtomicWaker;
+use futures_timer::Delay;
+use tracing::{debug, warn};
+use web_time::Instant;
+
+use rust_decimal::Decimal;
+
+use crate::execution::{MarketOrderReport, check_slippage, closing_side};
+use crate::generated::types::{AccountPosition, BinanceOrder, SubmitTxResponse};
+use crate::runtime::{spawn, try_spawn};
+use crate::sub_account::MAX_SUB_ACCOUNT_INDEX;
+use crate::types::{CallMessage, UserAction};
+use crate::ws::managed::ManagedWsSender;
+use crate::{Client, MarketId, RuntimeCall, SDKError, SDKResult, Side, derive_sub_account_address};
+
+/// Configuration for a [`DeadMansSwitch`].
+///
+/// # Example
+///
+/// ```ignore
+/// use bullet_rust_sdk::DeadMansSwitchConfig;
+/// use std::time::Duration;
+///
+/// let config = DeadMansSwitchConfig::builder()
+///     .window(Duration::from_secs(5))
+///     .sub_accounts(vec![None, Some(1)])
+///     .websocket(ws.sender())
+///     .build();
+/// ```
+#[derive(bon::Builder, Clone, Debug)]
+pub struct DeadMansSwitchConfig {
+    /// How long the switch waits for a heartbeat before cancelling.
+    ///
+    /// Default: 30 seconds
+    #[builder(default = Duration::from_secs(30))]
+    pub window: Duration,
+
+    /// Markets to cancel on. Empty cancels across all markets with a single
+    /// `CancelAllOrders` per sub-account.
+    ///
+    /// Default: empty (all markets)
+    #[builder(default)]
+    pub markets: Vec<MarketId>,
+
+    /// Accounts to cancel on: `None` is the main account, `Some(i)` the
+    /// sub-account at index `i`.
+    ///
+    /// Default: `[None]` (main account only)
+    #[builder(default = vec![None])]
+    pub sub_accounts: Vec<Option<u8>>,
+
+    /// Send the cancels over this [`ManagedWebsocket`](crate::ManagedWebsocket)
+    /// instead of REST, with the same per-market scoping: market cancels go
+    /// out as `order.cancel`, whole-account ones as `order.cancelAll`.
+    /// WebSocket sends are fire-and-forget, so they are reported as
+    /// [`CancelStatus::Sent`]; the server's acknowledgements arrive on the
+    /// websocket's own event stream.
+    ///
+    /// Default: `None` (submit via REST)
+    pub websocket: Option<ManagedWsSender>,
+
+    /// Report channel buffer size. Reports are dropped (with a warning) when
+    /// the consumer isn't keeping up.
+    ///
+    /// Default: 16
+    #[builder(default = 16)]
+    pub channel_capacity: usize,
+}
+
+impl Default for DeadMansSwitchConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// What happened to one cancel sent by a safety control.
+#[derive(Clone, Debug)]
+pub enum CancelStatus {
+    /// Submitted via REST and accepted by the rollup.
+    Confirmed(SubmitTxResponse),
+    /// Queued on the websocket. Fire-and-forget; no acknowledgement awaited.
+    Sent,
+    /// Signing or submission failed. Carries the error message.
+    Failed(String),
+}
+
+/// One cancel sent by a safety control.
+#[derive(Clone, Debug)]
+pub struct CancelOutcome {
+    /// The market cancelled on, or `None` for all markets.
+    pub market_id: Option<MarketId>,
+    /// The sub-account cancelled on, or `None` for the main account.
+    pub sub_account_index: Option<u8>,
+    pub status: CancelStatus,
+}
+
+/// Delivered by a [`DeadMansSwitch`] each time it fires.
+#[derive(Clone, Debug)]
+pub struct DeadMansSwitchReport {
+    /// Time since the last heartbeat (or since the switch was started) when
+    /// it fired.
+    pub silent_for: Duration,
+    /// One entry per cancel, in the order they were sent.
+    pub cancels: Vec<CancelOutcome>,
+}
+
+impl DeadMansSwitchReport {
+    /// Whether every cancel was confirmed or sent.
+    pub fn all_succeeded(&self) -> bool {
+        self.cancels
+            .iter()
+            .all(|c| !matches!(c.status, CancelStatus::Failed(_)))
+    }
+}
+
+/// Background task that cancels orders when heartbeats stop.
+///
+/// The window starts when the switch is spawned. Once it fires, the switch
+/// stays tripped — sending nothing further — until the next
+/// [`heartbeat`](Self::heartbeat) re-arms it with a fresh window.
+///
+/// Dropping the handle stops the background task without cancelling
+/// anything.
+pub struct DeadMansSwitch {
+    tripped: Arc<AtomicBool>,
+    heartbeats: Arc<Heartbeats>,
+    report_rx: mpsc::Receiver<DeadMansSwitchReport>,
+    /// Held, never sent on. Dropping signals shutdown to the background task.
+    _shutdown_tx: oneshot::Sender<()>,
+}
+
+impl DeadMansSwitch {
+    /// Arm a dead man's switch for the given [`Client`].
+    ///
+    /// The client must have a keypair to sign the cancels. It is taken as an
+    /// `Arc` because the background task outlives this call.
+    ///
+    /// Must be called from within an async runtime: the timer loop is spawned
+    /// via [`tokio::spawn`] on native targets and
+    /// [`wasm_bindgen_futures::spawn_local`] on wasm.
+    pub fn spawn(client: Arc<Client>, config: DeadMansSwitchConfig) -> DeadMansSwitch {
+        let tripped = Arc::new(AtomicBool::new(false));
+        let heartbeats = Arc::new(Heartbeats::new());
+        let (report_tx, report_rx) = mpsc::channel(config.channel_capacity);
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        let task_tripped = tripped.clone();
+        let task_heartbeats = heartbeats.clone();
+        spawn(async move {
+            run_switch(
+                client,
+                config,
+                task_tripped,
+                task_heartbeats,
+                report_tx,
+                shutdown_rx,
+            )
+            .await;
+        });
+
+        DeadMansSwitch {
+            tripped,
+            heartbeats,
+            report_rx,
+            _shutdown_tx: shutdown_tx,
+        }
+    }
+
+    /// Signal that the application is alive, restarting the window.
+    ///
+    /// Re-arms the switch if it has fired.
+    pub fn heartbeat(&self) {
+        self.heartbeats.beat();
+    }
+
+    /// Whether the switch has fired and not been re-armed since.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.load(Ordering::Acquire)
+    }
+
+    /// Receive the next report of cancels sent after a missed heartbeat.
+    ///
+    /// Returns `None` once the background task has stopped.
+    pub async fn recv(&mut self) -> Option<DeadMansSwitchReport> {
+        self.report_rx.next().await
+    }
+
+    /// Disarm the switch and stop its background task.
+    pub fn stop(self) {
+        // Drop self — `_shutdown_tx` is dropped, closing the oneshot.
+    }
+}
+
+/// The latest heartbeat, shared by a [`DeadMansSwitch`] and its task.
+///
+/// A timestamp rather than a channel, so heartbeats never queue up while
+/// the task is busy cancelling.
+struct Heartbeats {
+    epoch: Instant,
+    /// Microseconds after `epoch` of the latest heartbeat.
+    last: AtomicU64,
+    /// Woken on each heartbeat so a tripped switch can re-arm.
+    waker: AtomicWaker,
+}
+
+impl Heartbeats {
+    fn new() -> Self {
+        Heartbeats {
+            epoch: Instant::now(),
+            last: AtomicU64::new(0),
+            waker: AtomicWaker::new(),
+        }
+    }
+
+    fn beat(&self) {
+        let micros = self.epoch.elapsed().as_micros() as u64;
+        self.last.store(micros, Ordering::Release);
+        self.waker.wake();
+    }
+
+    fn last(&self) -> Instant {
+        self.epoch + Duration::from_micros(self.last.load(Ordering::Acquire))
+    }
+
+    /// Resolve once a heartbeat later than `since` arrives.
+    async fn after(&self, since: Instant) {
+        future::poll_fn(|cx| {
+            self.waker.register(cx.waker());
+            if self.last() > since {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+/// Background timer loop behind [`DeadMansSwitch`].
+async fn run_switch(
+    client: Arc<Client>,
+    config: DeadMansSwitchConfig,
+    tripped: Arc<AtomicBool>,
+    heartbeats: Arc<Heartbeats>,
+    mut report_tx: mpsc::Sender<DeadMansSwitchReport>,
+    mut shutdown_rx: oneshot::Receiver<()>,
+) {
+    // The latest heartbeat when the switch last fired, while it is tripped.
+    let mut fired_after: Option<Instant> = None;
+    loop {
+        let wait = match fired_after {
+            // Nothing to time until the next heartbeat re-arms the switch.
+            Some(since) => Either::Left(heartbeats.after(since)),
+            None => {
+                let deadline = heartbeats.last() + config.window;
+                Either::Right(Delay::new(
+                    deadline.saturating_duration_since(Instant::now()),
+                ))
+            }
+        };
+        match future::select(std::pin::pin!(wait), &mut shutdown_rx).await {
+            Either::Left(_) if fired_after.is_some() => {
+                fired_after = None;
+                tripped.store(false, Ordering::Release);
+            }
+            Either::Left(_) => {
+                let last_heartbeat = heartbeats.last();
+                if last_heartbeat + config.window > Instant::now() {
+                    // A heartbeat arrived while waiting; time from it.
+                    continue;
+                }
+                let silent_for = last_heartbeat.elapsed();
+                warn!(?silent_for, "heartbeat missed, cancelling orders");
+                fired_after = Some(last_heartbeat);
+                tripped.store(true, Ordering::Release);
+                let report = DeadMansSwitchReport {
+                    silent_for,
+                    cancels: cancel_scopes(&client, &config).await,
+                };
+                match report_tx.try_send(report) {
+                    Ok(()) => {}
+                    Err(e) if e.is_full() => {
+                        warn!("dead man's switch report channel full, dropping report");
+                    }
+                    Err(_) => {
+                        debug!("dead man's switch receiver dropped, stopping");
+                        return;
+                    }
+                }
+            }
+            Either::Right(_) => {
+                debug!("shutdown signaled, stopping dead man's switch");
+                return;
+            }
+        }
+    }
+}
+
+/// Send a cancel for every configured (sub-account, market) pair.
+async fn cancel_scopes(client: &Client, config: &DeadMansSwitchConfig) -> Vec<CancelOutcome> {
+    let markets: Vec<Option<MarketId>> = if config.markets.is_empty() {
+        vec![None]
+    } else {
+        config.markets.iter().copied().map(Some).collect()
+    };
+
+    let mut cancels = Vec::with_capacity(config.sub_accounts.len() * markets.len());
+    for &sub_account_index in &config.sub_accounts {
+        for &market_id in &markets {
+            let call_message = CallMessage::User(match market_id {
+                Some(market_id) => UserAction::CancelMarketOrders {
+                    market_id,
+                    sub_account_index,
+                },
+                None => UserAction::CancelAllOrders { sub_account_index },
+            });
+            let status = match &config.websocket {
+                Some(ws) => send_over_ws(client, ws, call_message, market_id.is_some()).await,
+                None => match client.send_call_message(call_message).await {
+                    Ok(response) => CancelStatus::Confirmed(response),
+                    Err(e) => CancelStatus::Failed(e.to_string()),
+                },
+            };
+            cancels.push(CancelOutcome {
+                market_id,
+                sub_account_index,
+                status,
+            });
+        }
+    }
+    cancels
+}
+
+/// Sign `call_message` and queue it on the websocket: as a plain cancel when
+/// it is scoped to one market, so it can't be taken for a cancel-all.
+async fn send_over_ws(
+    client: &Client,
+    ws: &ManagedWsSender,
+    call_message: CallMessage,
+    market_scoped: bool,
+) -> CancelStatus {
+    let signed = client
+        .sign_runtime_call(RuntimeCall::Exchange(call_message))
+        .await;
+    let result = signed.and_then(|signed| {
+        let sent = if market_scoped {
+            ws.cancel_order(&signed, None)
+        } else {
+            ws.cancel_all_orders(&signed, None)
+        };
+        sent.map_err(SDKError::from)
+    });
+    match result {
+        Ok(()) => CancelStatus::Sent,
+        Err(e) => CancelStatus::Failed(e.to_string()),
+    }
+}
+
+// ── Kill switch ─────────────────────────────────────────────────────────────
+
+/// Default number of cancel-and-verify rounds per account.
+const DEFAULT_KILL_ATTEMPTS: u32 = 5;
+
+/// Default pause between sending cancels and checking open orders.
+const DEFAULT_KILL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Default worst price for flattening IOCs, in basis points from the top of
+/// the book.
+const DEFAULT_FLATTEN_SLIPPAGE_BPS: u32 = 500;
+
+/// Sub-account probes in flight at once during discovery.
+const DISCOVERY_CONCURRENCY: usize = 8;
+
+/// Tries per discovery probe while the API answers `408` or `429`.
+const DISCOVERY_ATTEMPTS: u32 = 4;
+
+/// Pause before the first discovery retry; doubles on each further one.
+const DISCOVERY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// One reduce-only IOC sent by [`Client::kill_switch`] to close a position.
+#[derive(Clone, Debug)]
+pub struct FlattenOutcome {
+    pub market_id: MarketId,
+    pub side: Side,
+    /// Size of the order: the absolute position size when it was sent.
+    pub size: Decimal,
+    pub result: Result<MarketOrderReport, String>,
+}
+
+/// What [`Client::kill_switch`] did on one account.
+#[derive(Clone, Debug)]
+pub struct AccountKillReport {
+    /// `None` for the main account.
+    pub sub_account_index: Option<u8>,
+    pub address: String,
+    /// Cancel-and-verify rounds run on this account.
+    pub attempts: u32,
+    /// The `CancelAllOrders` sent in each round.
+    pub cancels: Vec<CancelStatus>,
+    /// Reduce-only IOCs sent, across all rounds.
+    pub flattens: Vec<FlattenOutcome>,
+    /// Orders still open at the last check.
+    pub open_orders: Vec<BinanceOrder>,
+    /// Non-zero positions at the last check. Only checked when flattening.
+    pub open_positions: Vec<AccountPosition>,
+    /// Why the last check failed, if it did.
+    pub check_error: Option<String>,
+}
+
+impl AccountKillReport {
+    fn new(sub_account_index: Option<u8>, address: String) -> Self {
+        Self {
+            sub_account_index,
+            address,
+            attempts: 0,
+            cancels: Vec::new(),
+            flattens: Vec::new(),
+            open_orders: Vec::new(),
+            open_positions: Vec::new(),
+            check_error: None,
+        }
+    }
+
+    /// Whether the last check confirmed no open orders (and, when
+    /// flattening, no open positions).
+    pub fn is_clean(&self) -> bool {
+        self.check_error.is_none() && self.open_orders.is_empty() && self.open_positions.is_empty()
+    }
+}
+
+/// Returned by [`Client::kill_switch`].
+#[derive(Clone, Debug)]
+pub struct KillSwitchReport {
+    /// The main account first, then each sub-account acted on.
+    pub accounts: Vec<AccountKillReport>,
+}
+
+impl KillSwitchReport {
+    /// Whether every account was confirmed clean.
+    pub fn is_clean(&self) -> bool {
+        self.accounts.iter().all(AccountKillReport::is_clean)
+    }
+}
+
+/// Options shared by every account's kill loop.
+struct KillOptions {
+    flatten: bool,
+    max_attempts: u32,
+    retry_delay: Duration,
+    max_slippage_bps: u32,
+}
+
+#[bon]
+impl Client {
+    /// Cancel every open order on the main account and its sub-accounts,
+    /// optionally flattening positions, and verify the result.
+    ///
+    /// Each account gets a `CancelAllOrders`, then its open orders are
+    /// re-queried after `retry_delay`; rounds repeat until none are left or
+    /// `max_attempts` is reached. With `flatten`, every non-zero position is
+    /// closed each round with a reduce-only IOC priced `max_slippage_bps`
+    /// through the top of the book.
+    ///
+    /// The API has no sub-account listing, so unless `sub_accounts` is given
+    /// every index is probed and those with nothing to cancel (or flatten)
+    /// are skipped. Only a `400` or `404` marks an index as unused: throttled
+    /// probes are retried with backoff, and any other failure keeps the
+    /// account in the kill. Accounts are handled concurrently. Failures are recorded
+    /// in the report rather than aborting; the only error is
+    /// [`SDKError::MissingKeypair`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let report = client.kill_switch().flatten(true).call().await?;
+    /// if !report.is_clean() {
+    ///     eprintln!("kill switch incomplete: {report:#?}");
+    /// }
+    /// ```
+    #[builder]
+    pub async fn kill_switch(
+        &self,
+        /// Also close positions with reduce-only IOC orders. Default: false.
+        #[builder(default)]
+        flatten: bool,
+        /// Sub-account indices to act on, skipping discovery.
+        sub_accounts: Option<Vec<u8>>,
+        /// Cancel-and-verify rounds per account. Default: 5.
+        max_attempts: Option<u32>,
+        /// Pause between cancelling and re-checking. Default: 500ms.
+        retry_delay: Option<Duration>,
+        /// Worst flattening price, in bps from the top of the book. Default: 500.
+        max_slippage_bps: Option<u32>,
+    ) -> SDKResult<KillSwitchReport> {
+        let master = self.address()?;
+        let options = KillOptions {
+            flatten,
+            max_attempts: max_attempts.unwrap_or(DEFAULT_KILL_ATTEMPTS).max(1),
+            retry_delay: retry_delay.unwrap_or(DEFAULT_KILL_RETRY_DELAY),
+            max_slippage_bps: max_slippage_bps.unwrap_or(DEFAULT_FLATTEN_SLIPPAGE_BPS),
+        };
+        if flatten {
+            check_slippage(options.max_slippage_bps)?;
+        }
+
+        let sub_accounts = match sub_accounts {
+            Some(indices) => indices
+                .into_iter()
+                .map(|index| {
+                    derive_sub_account_address(&master, index.into())
+                        .map(|address| (Some(index), address))
+                })
+                .collect::<SDKResult<Vec<_>>>()?,
+            None => self.discover_sub_accounts(&master, flatten).await,
+        };
+        let targets = std::iter::once((None, master)).chain(sub_accounts);
+
+        let accounts = future::join_all(
+            targets.map(|(index, address)| self.kill_account(index, address, &options)),
+        )
+        .await;
+        Ok(KillSwitchReport { accounts })
+    }
+}
+
+impl Client {
+    /// Probe every sub-account index, keeping those with open orders (or,
+    /// when flattening, positions) and those whose probe failed for any
+    /// reason other than the account not existing.
+    ///
+    /// At most [`DISCOVERY_CONCURRENCY`] probes run at once, and probes that
+    /// are throttled or time out are retried with backoff.
+    async fn discover_sub_accounts(
+        &self,
+        master: &str,
+        flatten: bool,
+    ) -> Vec<(Option<u8>, String)> {
+        let probes = (0..=MAX_SUB_ACCOUNT_INDEX as u8).map(|index| async move {
+            let address = derive_sub_account_address(master, index.into()).ok()?;
+            let address = address.as_str();
+            let busy = match with_backoff(|| self.open_orders_at(address)).await {
+                Ok(orders) if !orders.is_empty() => true,
+                Ok(_) if flatten => match with_backoff(|| self.open_positions_at(address)).await {
+                    Ok(positions) => !positions.is_empty(),
+                    Err(e) => !is_missing_account(&e),
+                },
+                Ok(_) => false,
+                Err(e) => !is_missing_account(&e),
+            };
+            busy.then(|| (Some(index), address.to_string()))
+        });
+        let mut found: Vec<_> = futures::stream::iter(probes)
+            .buffer_unordered(DISCOVERY_CONCURRENCY)
+            .filter_map(future::ready)
+            .collect()
+            .await;
+        found.sort_by_key(|(index, _)| *index);
+        found
+    }
+
+    async fn kill_account(
+        &self,
+        sub_account_index: Option<u8>,
+        address: String,
+        options: &KillOptions,
+    ) -> AccountKillReport {
+        let mut report = AccountKillReport::new(sub_account_index, address);
+        if options.flatten {
+            match self.open_positions_at(&report.address).await {
+                Ok(positions) => report.open_positions = positions,
+                Err(e) => report.check_error = Some(e.to_string()),
+            }
+        }
+
+        while report.attempts < options.max_attempts {
+            report.attempts += 1;
+            report
+                .cancels
+                .push(match self.cancel_all_orders(sub_account_index).await {
+                    Ok(response) => CancelStatus::Confirmed(response),
+                    Err(e) => CancelStatus::Failed(e.to_string()),
+                });
+            for position in std::mem::take(&mut report.open_positions) {
+                let outcome = self
+                    .flatten_position(&position, sub_account_index, options.max_slippage_bps)
+                    .await;
+                report.flattens.push(outcome);
+            }
+
+            Delay::new(options.retry_delay).await;
+            report.check_error = None;
+            match self.open_orders_at(&report.address).await {
+                Ok(orders) => report.open_orders = orders,
+                Err(e) => report.check_error = Some(e.to_string()),
+            }
+            if options.flatten {
+                match self.open_positions_at(&report.address).await {
+                    Ok(positions) => report.open_positions = positions,
+                    Err(e) => report.check_error = Some(e.to_string()),
+                }
+            }
+            if report.is_clean() {
+                break;
+            }
+            warn!(
+                address = %report.address,
+                attempt = report.attempts,
+                open_orders = report.open_orders.len(),
+                open_positions = report.open_positions.len(),
+                "account not clean after kill switch round"
+            );
+        }
+        report
+    }
+
+    /// Close `position` with a reduce-only IOC priced off the top of the book.
+    async fn flatten_position(
+        &self,
+        position: &AccountPosition,
+        sub_account_index: Option<u8>,
+        max_slippage_bps: u32,
+    ) -> FlattenOutcome {
+        let market_id = MarketId(position.market_id);
+        let side = closing_side(position.position_amt);
+        let size = position.position_amt.abs();
+        let result = self
+            .market_order(market_id, side, size, max_slippage_bps)
+            .reduce_only(true)
+            .maybe_sub_account_index(sub_account_index)
+            .call()
+            .await
+            .map_err(|e| e.to_string());
+        FlattenOutcome {
+            market_id,
+            side,
+            size,
+            result,
+        }
+    }
+
+    async fn open_orders_at(&self, address: &str) -> SDKResult<Vec<BinanceOrder>> {
+        Ok(self
+            .with_failover(|api| async move { api.query_open_orders(address, None).await })
+            .await?
+            .into_inner())
+    }
+}
+
+/// A `400` or `404` from the API: for sub-account discovery, the account
+/// doesn't exist. Other client errors, such as throttling, say nothing about
+/// the account.
+fn is_missing_account(error: &SDKError) -> bool {
+    matches!(error, SDKError::ApiError(e) if matches!(e.status, 400 | 404))
+}
+
+/// Run `probe`, retrying with exponential backoff while the API answers
+/// `408 Request Timeout` or `429 Too Many Requests`.
+async fn with_backoff<T, F, Fut>(mut probe: F) -> SDKResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = SDKResult<T>>,
+{
+    let mut delay = DISCOVERY_BACKOFF;
+    for _ in 1..DISCOVERY_ATTEMPTS {
+        match probe().await {
+            Err(SDKError::ApiError(e)) if matches!(e.status, 408 | 429) => {
+                debug!(
+                    status = e.status,
+                    ?delay,
+                    "sub-account probe throttled, backing off"
+                );
+                Delay::new(delay).await;
+                delay *= 2;
+            }
+            other => return other,
+        }
+    }
+    probe().await
+}
+
+// ── Order guard ─────────────────────────────────────────────────────────────
+
+/// Orders that are cancelled when the guard goes out of scope.
+///
+/// Returned by [`Client::place_orders_guarded`]. Dropping an armed guard —
+/// including during unwinding when the owning task panics — spawns a
+/// background task that cancels the guarded orders by client order ID, since
+/// `Drop` can't await. Orders that have already filled or been cancelled are
+/// unaffected. Use [`cancel`](Self::cancel) to cancel and await the result,
+/// or [`disarm`](Self::disarm) to leave the orders resting.
+///
+/// The drop-time cancel is best-effort: its outcome is only logged, and
+/// nothing is sent if the process aborts or no async runtime is running.
+#[must_use = "dropping the guard immediately cancels the orders"]
+pub struct OrderGuard {
+    client: Arc<Client>,
+    market_id: MarketId,
+    sub_account_index: Option<u8>,
+    client_order_ids: Vec<ClientOrderId>,
+    response: SubmitTxResponse,
+    armed: bool,
+}
+
+impl OrderGuard {
+    /// The market the guarded orders were placed on.
+    pub fn market_id(&self) -> MarketId {
+        self.market_id
+    }
+
+    /// Client order IDs of the guarded orders, in placement order.
+    pub fn client_order_ids(&self) -> &[ClientOrderId] {
+        &self.client_order_ids
+    }
+
+    /// The response to the placing transaction.
+    pub fn response(&self) -> &SubmitTxResponse {
+        &self.response
+    }
+
+    /// Keep the orders resting and release the guard, returning their client
+    /// order IDs.
+    pub fn disarm(mut self) -> Vec<ClientOrderId> {
+        self.armed = false;
+        std::mem::take(&mut self.client_order_ids)
+    }
+
+    /// Cancel the guarded orders now and wait for the result.
+    pub async fn cancel(mut self) -> SDKResult<SubmitTxResponse> {
+        self.armed = false;
+        let orders = cancel_args(&self.client_order_ids);
+        self.client
+            .cancel_orders(self.market_id, orders, self.sub_account_index)
+            .await
+    }
+}
+
+impl Drop for OrderGuard {
+    fn drop(&mut self) {
+        if !self.armed || self.client_order_ids.is_empty() {
+            return;
+        }
+        let client = self.client.clone();
+        let market_id = self.market_id;
+        let sub_account_index = self.sub_account_index;
+        let orders = cancel_args(&self.client_order_ids);
+        let spawned = try_spawn(async move {
+            match client
+                .cancel_orders(market_id, orders, sub_account_index)
+                .await
+            {
+                Ok(_) => debug!(?market_id, "order guard cancelled its orders"),
+                Err(e) => warn!(?market_id, error = %e, "order guard failed to cancel its orders"),
+            }
+        });
+        if !spawned {
+            warn!(
+                ?market_id,
+                "order guard dropped outside an async runtime, orders left resting"
+            );
+        }
+    }
+}
+
+fn cancel_args(client_order_ids: &[ClientOrderId]) -> Vec<CancelOrderArgs> {
+    client_order_ids
+        .iter()
+        .map(|&client_order_id| CancelOrderArgs {
+            order_id: None,
+            client_order_id: Some(client_order_id),
+        })
+        .collect()
+}
+
+impl Client {
+    /// Place orders and return an [`OrderGuard`] that cancels them when
+    /// dropped unless disarmed.
+    ///
+    /// Orders without a client order ID are given a random one so the guard
+    /// can cancel them without parsing the response. Subject to the same
+    /// market status check as [`place_orders`](Self::place_orders). Takes
+    /// `&Arc<Client>` because the drop-time cancel runs on a background task.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let client = Arc::new(client);
+    /// let guard = client
+    ///     .place_orders_guarded(market_id, vec![bid, ask], None)
+    ///     .await?;
+    /// hedge(&client).await?; // on error or panic, the quotes are pulled
+    /// guard.disarm();
+    /// ```
+    pub async fn place_orders_guarded(
+        self: &Arc<Self>,
+        market_id: MarketId,
+        mut orders: Vec<NewOrderArgs>,
+        sub_account_index: Option<u8>,
+    ) -> SDKResult<OrderGuard> {
+        let client_order_ids = orders
+            .iter_mut()
+            .map(|order| {
+                *order
+                    .client_order_id
+                    .get_or_insert_with(|| ClientOrderId(rand::random()))
+            })
+            .collect();
+        let response = self
+            .place_orders(market_id, orders, false, sub_account_index)
+            .await?;
+        Ok(OrderGuard {
+            client: self.clone(),
+            market_id,
+            sub_account_index,
+            client_order_ids,
+            response,
+            armed: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bullet_exchange_interface::types::OrderType;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use bullet_exchange_interface::decimals::PositiveDecimal;
+
+    use super::*;
+    use crate::generated::types::Symbol;
+    use crate::testing::{
+        account_json, decode_submitted, mock_signing_client, mount_submit_ok, open_order_json,
+        submit_count, symbol,
+    };
+    use crate::types::ClientMessage;
+    use crate::{NewOrderExt, SignedTransaction, Transaction};
+
+    async fn keyed_client(server_symbols: Vec<Symbol>) -> (MockServer, Arc<Client>) {
+        let (server, client) = mock_signing_client(server_symbols).await;
+        mount_submit_ok(&server).await;
+        (server, Arc::new(client))
+    }
+
+    fn mount_open_orders(address: &str, orders: serde_json::Value) -> Mock {
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/openOrders"))
+            .and(query_param("address", address))
+            .respond_with(ResponseTemplate::new(200).set_body_json(orders))
+    }
+
+    #[tokio::test]
+    async fn missed_heartbeat_cancels_every_scope_once() {
+        let (server, client) = keyed_client(vec![]).await;
+        let config = DeadMansSwitchConfig::builder()
+            .window(Duration::from_millis(50))
+            .markets(vec![MarketId(0), MarketId(1)])
+            .sub_accounts(vec![None, Some(2)])
+            .build();
+        let mut switch = DeadMansSwitch::spawn(client, config);
+
+        let report = switch.recv().await.unwrap();
+
+        assert!(switch.is_tripped());
+        assert!(report.all_succeeded(), "{report:?}");
+        assert!(report.silent_for >= Duration::from_millis(50));
+        let scopes: Vec<_> = report
+            .cancels
+            .iter()
+            .map(|c| (c.sub_account_index, c.market_id))
+            .collect();
+        assert_eq!(
+            scopes,
+            vec![
+                (None, Some(MarketId(0))),
+                (None, Some(MarketId(1))),
+                (Some(2), Some(MarketId(0))),
+                (Some(2), Some(MarketId(1))),
+            ]
+        );
+        // Tripped switches stay quiet until re-armed.
+        Delay::new(Duration::from_millis(150)).await;
+        assert_eq!(submit_count(&server).await, 4);
+    }
+
+    #[tokio::test]
+    async fn missed_heartbeat_cancels_over_the_websocket() {
+        let (server, client) = keyed_client(vec![]).await;
+        let (ws, mut messages) = ManagedWsSender::detached();
+        let config = DeadMansSwitchConfig::builder()
+            .window(Duration::from_millis(50))
+            .markets(vec![MarketId(3), MarketId(4)])
+            .websocket(ws)
+            .build();
+        let mut switch = DeadMansSwitch::spawn(client.clone(), config);
+
+        let report = switch.recv().await.unwrap();
+
+        assert!(matches!(
+            report.cancels[..],
+            [
+                CancelOutcome {
+                    market_id: Some(MarketId(3)),
+                    status: CancelStatus::Sent,
+                    ..
+                },
+                CancelOutcome {
+                    market_id: Some(MarketId(4)),
+                    status: CancelStatus::Sent,
+                    ..
+                }
+            ]
+        ));
+        for market_id in [MarketId(3), MarketId(4)] {
+            let Some(ClientMessage::OrderCancel { params, .. }) = messages.next().await else {
+                panic!("expected a market-scoped cancel, not a cancel-all");
+            };
+            let SignedTransaction::V0(signed) = Transaction::from_base64(&params.tx).unwrap()
+            else {
+                unreachable!()
+            };
+            assert_eq!(
+                signed.runtime_call,
+                RuntimeCall::Exchange(CallMessage::User(UserAction::CancelMarketOrders {
+                    market_id,
+                    sub_account_index: None,
+                }))
+            );
+        }
+        assert_eq!(submit_count(&server).await, 0);
+    }
+
+    #[tokio::test]
+    async fn heartbeats_keep_the_switch_armed_and_rearm_it() {
+        let (server, client) = keyed_client(vec![]).await;
+        let config = DeadMansSwitchConfig::builder()
+            .window(Duration::from_millis(80))
+            .build();
+        let mut switch = DeadMansSwitch::spawn(client, config);
+
+        for _ in 0..10 {
+            Delay::new(Duration::from_millis(20)).await;
+            switch.heartbeat();
+        }
+        assert!(!switch.is_tripped());
+        assert_eq!(submit_count(&server).await, 0);
+
+        let report = switch.recv().await.unwrap();
+        assert_eq!(report.cancels.len(), 1);
+        assert_eq!(report.cancels[0].market_id, None);
+        switch.heartbeat();
+        let report = switch.recv().await.unwrap();
+        assert_eq!(report.cancels.len(), 1);
+        assert_eq!(submit_count(&server).await, 2);
+    }
+
+    #[tokio::test]
+    async fn failed_cancels_are_reported() {
+        let (_server, client) = crate::testing::mock_client().await;
+        let config = DeadMansSwitchConfig::builder()
+            .window(Duration::from_millis(10))
+            .build();
+        let mut switch = DeadMansSwitch::spawn(Arc::new(client), config);
+
+        let report = switch.recv().await.unwrap();
+
+        assert!(!report.all_succeeded());
+        assert!(matches!(report.cancels[0].status, CancelStatus::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn kill_switch_retries_until_no_orders_remain() {
+        let (server, client) = keyed_client(vec![]).await;
+        let master = client.address().unwrap();
+        let sub = derive_sub_account_address(&master, 1).unwrap();
+        mount_open_orders(&master, serde_json::json!([open_order_json(1, 0)]))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        mount_open_orders(&master, serde_json::json!([]))
+            .mount(&server)
+            .await;
+        mount_open_orders(&sub, serde_json::json!([]))
+            .mount(&server)
+            .await;
+
+        let report = client
+            .kill_switch()
+            .sub_accounts(vec![1])
+            .retry_delay(Duration::from_millis(1))
+            .call()
+            .await
+            .unwrap();
+
+        assert!(report.is_clean(), "{report:#?}");
+        let rounds: Vec<_> = report
+            .accounts
+            .iter()
+            .map(|a| (a.sub_account_index, a.attempts, a.cancels.len()))
+            .collect();
+        assert_eq!(rounds, vec![(None, 2, 2), (Some(1), 1, 1)]);
+        assert_eq!(submit_count(&server).await, 3);
+    }
+
+    #[tokio::test]
+    async fn kill_switch_discovers_only_busy_sub_accounts() {
+        let (server, client) = keyed_client(vec![]).await;
+        let master = client.address().unwrap();
+        let busy = derive_sub_account_address(&master, 3).unwrap();
+        mount_open_orders(&busy, serde_json::json!([open_order_json(7, 0)]))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        for address in [&master, &busy] {
+            mount_open_orders(address, serde_json::json!([]))
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/openOrders"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "status": 400,
+                "message": "account not found",
+            })))
+            .with_priority(10)
+            .mount(&server)
+            .await;
+
+        let report = client
+            .kill_switch()
+            .retry_delay(Duration::from_millis(1))
+            .call()
+            .await
+            .unwrap();
+
+        assert!(report.is_clean(), "{report:#?}");
+        let accounts: Vec<_> = report
+            .accounts
+            .iter()
+            .map(|a| a.sub_account_index)
+            .collect();
+        assert_eq!(accounts, vec![None, Some(3)]);
+    }
+
+    #[tokio::test]
+    async fn kill_switch_retries_throttled_discovery_probes() {
+        let (server, client) = keyed_client(vec![]).await;
+        let master = client.address().unwrap();
+        let idle = derive_sub_account_address(&master, 5).unwrap();
+        let throttled = derive_sub_account_address(&master, 6).unwrap();
+        let too_many_requests = || {
+            ResponseTemplate::new(429).set_body_json(serde_json::json!({
+                "status": 429,
+                "message": "too many requests",
+            }))
+        };
+        // Throttled twice, then reported idle: skipped once the retry lands.
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/openOrders"))
+            .and(query_param("address", idle.as_str()))
+            .respond_with(too_many_requests())
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        // Throttled on every try: kept, since it may still hold orders.
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/openOrders"))
+            .and(query_param("address", throttled.as_str()))
+            .respond_with(too_many_requests())
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        for address in [&master, &idle] {
+            mount_open_orders(address, serde_json::json!([]))
+                .with_priority(2)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/openOrders"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "status": 400,
+                "message": "account not found",
+            })))
+            .with_priority(10)
+            .mount(&server)
+            .await;
+
+        let report = client
+            .kill_switch()
+            .max_attempts(1)
+            .retry_delay(Duration::from_millis(1))
+            .call()
+            .await
+            .unwrap();
+
+        let accounts: Vec<_> = report
+            .accounts
+            .iter()
+            .map(|a| a.sub_account_index)
+            .collect();
+        assert_eq!(accounts, vec![None, Some(6)]);
+    }
+
+    #[tokio::test]
+    async fn kill_switch_flattens_with_reduce_only_ioc() {
+        let (server, client) = keyed_client(vec![symbol("BTC-USD", 0, "TRADING")]).await;
+        let master = client.address().unwrap();
+        mount_open_orders(&master, serde_json::json!([]))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v3/account"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(account_json(&[("BTC-USD", 0, "0.5")])),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v3/account"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(account_json(&[])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/depth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "lastUpdateId": 1,
+                "E": 0,
+                "T": 0,
+                "bids": [["100.05", "2"]],
+                "asks": [["100.10", "2"]],
+            })))
+            .mount(&server)
+            .await;
+
+        let report = client
+            .kill_switch()
+            .flatten(true)
+            .sub_accounts(vec![])
+            .max_slippage_bps(100)
+            .retry_delay(Duration::from_millis(1))
+            .call()
+            .await
+            .unwrap();
+
+        assert!(report.is_clean(), "{report:#?}");
+        let flatten = &report.accounts[0].flattens[0];
+        assert_eq!(flatten.side, Side::Ask);
+        assert_eq!(flatten.size, Decimal::from_str("0.5").unwrap());
+        // 100.05 less 1% is 99.0495, snapped up to the 0.01 tick.
+        let order = &flatten.result.as_ref().unwrap().order;
+        assert_eq!(
+            Decimal::from(order.price),
+            Decimal::from_str("99.05").unwrap()
+        );
+        let orders: Vec<_> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/tx/submit")
+            .filter_map(|request| match decode_submitted(request) {
+                SignedTransaction::V0(v0) => match v0.runtime_call {
+                    RuntimeCall::Exchange(CallMessage::User(UserAction::PlaceOrders {
+                        orders,
+                        ..
+                    })) => Some(orders),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        assert_eq!(orders.len(), 1);
+        assert!(orders[0].reduce_only);
+        assert_eq!(orders[0].order_type, OrderType::ImmediateOrCancel);
+    }
+
+    async fn guard_client() -> (MockServer, Arc<Client>) {
+        keyed_client(vec![symbol("BTC-USD", 0, "TRADING")]).await
+    }
+
+    fn quotes() -> Vec<NewOrderArgs> {
+        let price = PositiveDecimal::try_from(Decimal::from(100)).unwrap();
+        let size = PositiveDecimal::try_from(Decimal::ONE).unwrap();
+        let mut ask = NewOrderArgs::post_only(price, size, Side::Ask);
+        ask.client_order_id = Some(ClientOrderId(42));
+        vec![NewOrderArgs::post_only(price, size, Side::Bid), ask]
+    }
+
+    /// Client order IDs cancelled by `/tx/submit` requests so far.
+    async fn cancelled_client_ids(server: &MockServer) -> Vec<ClientOrderId> {
+        server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/tx/submit")
+            .filter_map(|request| match decode_submitted(request) {
+                SignedTransaction::V0(v0) => match v0.runtime_call {
+                    RuntimeCall::Exchange(CallMessage::User(UserAction::CancelOrders {
+                        orders,
+                        ..
+                    })) => Some(orders),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|args| args.client_order_id)
+            .collect()
+    }
+
+    async fn wait_for_submits(server: &MockServer, count: usize) {
+        for _ in 0..100 {
+            if submit_count(server).await >= count {
+                return;
+            }
+            Delay::new(Duration::from_millis(10)).await;
+        }
+        panic!("expected {count} submits");
+    }
+
+    #[tokio::test]
+    async fn dropped_guard_cancels_its_orders_by_client_id() {
+        let (server, client) = guard_client().await;
+
+        let guard = client
+            .place_orders_guarded(MarketId(0), quotes(), None)
+            .await
+            .unwrap();
+        let ids = guard.client_order_ids().to_vec();
+        drop(guard);
+        wait_for_submits(&server, 2).await;
+
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[1], ClientOrderId(42));
+        assert_eq!(cancelled_client_ids(&server).await, ids);
+    }
+
+    #[tokio::test]
+    async fn disarmed_guard_leaves_orders_resting() {
+        let (server, client) = guard_client().await;
+
+        let guard = client
+            .place_orders_guarded(MarketId(0), quotes(), None)
+            .await
+            .unwrap();
+        assert_eq!(guard.disarm().len(), 2);
+        Delay::new(Duration::from_millis(50)).await;
+
+        assert_eq!(submit_count(&server).await, 1);
+    }
+
+    #[tokio::test]
+    async fn guard_cancels_when_owning_task_panics() {
+        let (server, client) = guard_client().await;
+        let guard = client
+            .place_orders_guarded(MarketId(0), quotes(), None)
+            .await
+            .unwrap();
+        let ids = guard.client_order_ids().to_vec();
+
+        let task = tokio::spawn(async move {
+            let _guard = guard;
+            panic!("strategy bug");
+        });
+        assert!(task.await.unwrap_err().is_panic());
+        wait_for_submits(&server, 2).await;
+
+        assert_eq!(cancelled_client_ids(&server).await, ids);
+    }
+}
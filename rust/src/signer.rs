@@ -0,0 +1,243 @@
+//! Pluggable transaction signing.
+//!
+//! Everything that signs — [`Transaction::builder`](crate::Transaction::builder),
+//! [`SolanaOffchainTransaction::sign`](crate::SolanaOffchainTransaction::sign),
+//! and the [`Client`](crate::Client) trading helpers — goes through the
+//! [`Signer`] trait, so a remote service or hardware wallet can stand in for
+//! an in-memory [`Keypair`](crate::Keypair):
+//!
+//! ```ignore
+//! struct Vault { pubkey: [u8; 32], http: reqwest::Client }
+//!
+//! impl Signer for Vault {
+//!     fn pubkey(&self) -> [u8; 32] {
+//!         self.pubkey
+//!     }
+//!
+//!     fn sign_message(&self, _: &[u8]) -> SDKResult<[u8; 64]> {
+//!         Err(SDKError::AsyncSignerRequired)
+//!     }
+//!
+//!     fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+//!         Box::pin(async move { self.request_signature(message).await })
+//!     }
+//! }
+//!
+//! let client = Client::builder()
+//!     .network(Network::Mainnet)
+//!     .signer(Arc::new(vault))
+//!     .build()
+//!     .await?;
+//! client.place_orders(market_id, orders, false, None).await?; // signed by the vault
+//! ```
+//!
+//! Signers that only implement the async flavour work with every async path:
+//! `send_call_message` and the helpers built on it, and
+//! [`Transaction::sign_async`](crate::Transaction::sign_async). Synchronous
+//! builders fail with [`SDKError::AsyncSignerRequired`](crate::SDKError::AsyncSignerRequired).
+
+use std::sync::Arc;
+
+use crate::SDKResult;
+
+/// Future returned by [`Signer::sign_message_async`].
+///
+/// `Send` on native targets; on wasm it need not be, so signers can await
+/// JS promises.
+#[cfg(not(target_arch = "wasm32"))]
+pub type SignFuture<'a> = futures::future::BoxFuture<'a, SDKResult<[u8; 64]>>;
+
+/// Future returned by [`Signer::sign_message_async`].
+///
+/// `Send` on native targets; on wasm it need not be, so signers can await
+/// JS promises.
+#[cfg(target_arch = "wasm32")]
+pub type SignFuture<'a> = futures::future::LocalBoxFuture<'a, SDKResult<[u8; 64]>>;
+
+/// An Ed25519 signer for transactions.
+///
+/// Implement [`sign_message`](Self::sign_message) for signers that can sign
+/// in place, or [`sign_message_async`](Self::sign_message_async) for ones
+/// that must wait on I/O (returning [`SDKError::AsyncSignerRequired`](crate::SDKError::AsyncSignerRequired) from
+/// the synchronous method).
+pub trait Signer: Send + Sync {
+    /// The 32-byte Ed25519 public key the signatures verify against.
+    fn pubkey(&self) -> [u8; 32];
+
+    /// Sign `message`, returning the 64-byte Ed25519 signature.
+    fn sign_message(&self, message: &[u8]) -> SDKResult<[u8; 64]>;
+
+    /// Sign `message` asynchronously. Defaults to
+    /// [`sign_message`](Self::sign_message).
+    fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+        Box::pin(futures::future::ready(self.sign_message(message)))
+    }
+
+    /// The on-chain address (base58-encoded public key).
+    fn address(&self) -> String {
+        bullet_exchange_interface::address::Address(self.pubkey()).to_string()
+    }
+}
+
+impl<S: Signer + ?Sized> Signer for Arc<S> {
+    fn pubkey(&self) -> [u8; 32] {
+        (**self).pubkey()
+    }
+
+    fn sign_message(&self, message: &[u8]) -> SDKResult<[u8; 64]> {
+        (**self).sign_message(message)
+    }
+
+    fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+        (**self).sign_message_async(message)
+    }
+}
+
+impl<S: Signer + ?Sized> Signer for &S {
+    fn pubkey(&self) -> [u8; 32] {
+        (**self).pubkey()
+    }
+
+    fn sign_message(&self, message: &[u8]) -> SDKResult<[u8; 64]> {
+        (**self).sign_message(message)
+    }
+
+    fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+        (**self).sign_message_async(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_exchange_interface::transaction::{
+        Transaction as SignedTransaction, UnsignedTransaction as RawUnsignedTransaction,
+    };
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    use super::*;
+    use crate::testing::{decode_submitted, mount_connect_mocks, mount_submit_ok, unsigned};
+    use crate::types::{CallMessage, UserAction};
+    use crate::{
+        Client, Keypair, SDKError, SolanaOffchainTransaction, Transaction, UnsignedTransaction,
+    };
+
+    /// Signs only through the async flavour, like a remote signing service.
+    struct RemoteSigner(Keypair);
+
+    impl Signer for RemoteSigner {
+        fn pubkey(&self) -> [u8; 32] {
+            self.0.pubkey()
+        }
+
+        fn sign_message(&self, _: &[u8]) -> SDKResult<[u8; 64]> {
+            Err(SDKError::AsyncSignerRequired)
+        }
+
+        fn sign_message_async<'a>(&'a self, message: &'a [u8]) -> SignFuture<'a> {
+            Box::pin(async move {
+                futures_timer::Delay::new(std::time::Duration::from_millis(1)).await;
+                self.0.sign_message(message)
+            })
+        }
+    }
+
+    fn verifies(pubkey: [u8; 32], message: &[u8], signature: [u8; 64]) -> bool {
+        VerifyingKey::from_bytes(&pubkey)
+            .unwrap()
+            .verify(message, &Signature::from_bytes(&signature))
+            .is_ok()
+    }
+
+    async fn remote_client() -> (wiremock::MockServer, Client, Keypair) {
+        let server = wiremock::MockServer::start().await;
+        mount_connect_mocks(&server, serde_json::json!([])).await;
+        mount_submit_ok(&server).await;
+        let keypair = Keypair::generate();
+        let client = Client::builder()
+            .network(server.uri())
+            .signer(Arc::new(RemoteSigner(keypair.clone())))
+            .build()
+            .await
+            .unwrap();
+        (server, client, keypair)
+    }
+
+    fn cancel_all() -> CallMessage {
+        CallMessage::User(UserAction::CancelAllOrders {
+            sub_account_index: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn client_helpers_sign_with_an_async_only_signer() {
+        let (server, client, keypair) = remote_client().await;
+
+        client.cancel_all_orders(None).await.unwrap();
+
+        assert!(client.keypair().is_none());
+        assert_eq!(client.address().unwrap(), keypair.address());
+        let requests = server.received_requests().await.unwrap();
+        let submit = requests
+            .iter()
+            .find(|request| request.url.path() == "/tx/submit")
+            .unwrap();
+        let SignedTransaction::V0(v0) = decode_submitted(submit) else {
+            unreachable!()
+        };
+        assert_eq!(v0.pub_key, keypair.pubkey());
+        let unsigned = UnsignedTransaction {
+            inner: RawUnsignedTransaction {
+                runtime_call: v0.runtime_call,
+                uniqueness: v0.uniqueness,
+                details: v0.details,
+            },
+            chain_hash: client.chain_hash(),
+            chain_name: client.chain_name(),
+        };
+        assert!(verifies(
+            v0.pub_key,
+            &unsigned.to_bytes().unwrap(),
+            v0.signature
+        ));
+    }
+
+    #[tokio::test]
+    async fn sync_builders_reject_async_only_signers() {
+        let (_server, client, _keypair) = remote_client().await;
+
+        let err = Transaction::builder()
+            .call_message(cancel_all())
+            .client(&client)
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(err, SDKError::AsyncSignerRequired), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn explicit_signers_override_the_client_signer() {
+        let (_server, client, _keypair) = remote_client().await;
+        let other = Keypair::generate();
+
+        let signed = Transaction::builder()
+            .call_message(cancel_all())
+            .signer(&other)
+            .client(&client)
+            .build()
+            .unwrap();
+        let SignedTransaction::V0(v0) = signed else {
+            unreachable!()
+        };
+        assert_eq!(v0.pub_key, other.pubkey());
+
+        let unsigned = unsigned(cancel_all(), &client);
+        let message = unsigned.to_message_bytes().unwrap();
+        let offchain =
+            SolanaOffchainTransaction::sign_async(unsigned, &RemoteSigner(other.clone()))
+                .await
+                .unwrap();
+        assert_eq!(offchain.signed_message, message);
+        assert_eq!(offchain.pubkey, other.pubkey());
+        assert!(verifies(offchain.pubkey, &message, offchain.signature));
+    }
+}
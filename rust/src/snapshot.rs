@@ -0,0 +1,187 @@
+//! Chain snapshots for offline (air-gapped) signing.
+//!
+//! A [`ChainSnapshot`] captures everything [`Client`] normally fetches at
+//! connect time — the chain identity, the exchange's symbols, and the raw
+//! `/rollup/schema` — so that a client can be rebuilt on a machine with no
+//! network access via [`Client::offline`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Online machine
+//! let client = Client::mainnet().await?;
+//! client.chain_snapshot().await?.write_to_file("chain.json")?;
+//!
+//! // Air-gapped machine
+//! let snapshot = ChainSnapshot::read_from_file("chain.json")?;
+//! let client = Client::offline().snapshot(snapshot).keypair(keypair).build()?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::generated::types::Symbol;
+use crate::{Client, SDKError, SDKResult};
+
+/// Chain identity, symbols, and schema exported from an online [`Client`].
+///
+/// Serializes to JSON with the chain hash as a `0x`-prefixed hex string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainSnapshot {
+    /// REST URL the snapshot was taken from. An offline client reports it
+    /// from [`Client::url`] but never contacts it on its own.
+    pub url: String,
+    pub chain_id: u64,
+    #[serde(with = "chain_hash_hex")]
+    pub chain_hash: [u8; 32],
+    pub chain_name: String,
+    /// Exchange symbols as returned by `exchange_info`.
+    pub symbols: Vec<Symbol>,
+    /// The raw `/rollup/schema` response body.
+    pub schema: serde_json::Value,
+}
+
+impl ChainSnapshot {
+    /// Serialize the snapshot to a JSON string.
+    pub fn to_json(&self) -> SDKResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Parse a snapshot from a JSON string.
+    pub fn from_json(json: &str) -> SDKResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write the snapshot to a JSON file.
+    pub fn write_to_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json)
+    }
+
+    /// Read a snapshot from a JSON file written by
+    /// [`write_to_file`](Self::write_to_file).
+    pub fn read_from_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        serde_json::from_str(&data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl Client {
+    /// Export a [`ChainSnapshot`] for building an offline client.
+    ///
+    /// Fetches the schema and exchange info fresh rather than reusing the
+    /// values cached at connect time, so the snapshot reflects the chain as it
+    /// is now.
+    pub async fn chain_snapshot(&self) -> SDKResult<ChainSnapshot> {
+        let schema = self
+            .with_failover(|api| async move { api.schema().await })
+            .await?
+            .into_inner();
+        let schema = serde_json::to_value(schema)
+            .map_err(|_| SDKError::InvalidSchemaResponse("failed to serialize schema"))?;
+        let chain_data = Self::chain_data_from_schema(&schema, self.user_actions(), None)?;
+        let symbols = self
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await?
+            .into_inner()
+            .symbols;
+
+        Ok(ChainSnapshot {
+            url: self.url().to_string(),
+            chain_id: chain_data.chain_id,
+            chain_hash: chain_data.chain_hash,
+            chain_name: chain_data.chain_name,
+            symbols,
+            schema,
+        })
+    }
+}
+
+pub(crate) mod chain_hash_hex {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(hash: &[u8; 32], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&format!("0x{}", hex::encode(hash)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<[u8; 32], D::Error> {
+        let s = String::deserialize(d)?;
+        let bytes = hex::decode(s.trim_start_matches("0x")).map_err(D::Error::custom)?;
+        bytes
+            .try_into()
+            .map_err(|v: Vec<u8>| D::Error::custom(format!("expected 32 bytes, got {}", v.len())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_exchange_interface::message::PublicAction;
+
+    use super::*;
+    use crate::testing::{mock_client, unsigned};
+    use crate::types::CallMessage;
+    use crate::{Keypair, Transaction, UnsignedTransaction};
+
+    #[tokio::test]
+    async fn offline_client_matches_online_client() {
+        let (server, online) = mock_client().await;
+        let json = online.chain_snapshot().await.unwrap().to_json().unwrap();
+        drop(server);
+
+        let offline = Client::offline()
+            .snapshot(ChainSnapshot::from_json(&json).unwrap())
+            .keypair(Keypair::generate())
+            .build()
+            .unwrap();
+
+        assert_eq!(offline.chain_hash(), online.chain_hash());
+        assert_eq!(offline.chain_id(), online.chain_id());
+        assert_eq!(offline.chain_name(), online.chain_name());
+        assert_eq!(offline.url(), online.url());
+    }
+
+    #[tokio::test]
+    async fn offline_client_builds_decodes_and_signs() {
+        let (_server, online) = mock_client().await;
+        let snapshot = online.chain_snapshot().await.unwrap();
+        let offline = Client::offline()
+            .snapshot(snapshot)
+            .keypair(Keypair::generate())
+            .build()
+            .unwrap();
+        let call_message = CallMessage::Public(PublicAction::ApplyFunding { addresses: vec![] });
+
+        let unsigned = unsigned(call_message.clone(), &offline);
+        let decoded = UnsignedTransaction::from_bytes(&unsigned.to_bytes().unwrap(), &offline);
+        let signed = Transaction::builder()
+            .call_message(call_message)
+            .client(&offline)
+            .build();
+
+        assert!(decoded.is_ok());
+        assert!(signed.is_ok());
+    }
+
+    #[tokio::test]
+    async fn offline_rejects_snapshot_with_tampered_chain_hash() {
+        let (_server, online) = mock_client().await;
+        let mut snapshot = online.chain_snapshot().await.unwrap();
+        snapshot.chain_hash = [8; 32];
+
+        let result = Client::offline().snapshot(snapshot).build();
+
+        assert!(matches!(result, Err(SDKError::InvalidChainHash(_))));
+    }
+
+    #[tokio::test]
+    async fn chain_hash_serializes_as_prefixed_hex() {
+        let (_server, online) = mock_client().await;
+        let snapshot = online.chain_snapshot().await.unwrap();
+
+        let value = serde_json::to_value(&snapshot).unwrap();
+
+        assert_eq!(value["chain_hash"], format!("0x{}", hex::encode([7u8; 32])));
+    }
+}
@@ -0,0 +1,587 @@
+//! One-line, human-readable descriptions of transactions.
+//!
+//! Meant for approval prompts, hardware-wallet review screens and audit logs,
+//! where the raw payload from
+//! [`UnsignedTransaction::to_display_message`] is too noisy. Market ids are
+//! resolved to symbols, and numbers are shown exactly as signed, padded with
+//! zeros to each market's precision but never rounded:
+//!
+//! ```ignore
+//! let unsigned = UnsignedTransaction::builder()
+//!     .call_message(call_msg)
+//!     .max_fee(10_000_000)
+//!     .client(&client)
+//!     .build()?;
+//! println!("{}", client.summarize(&unsigned));
+//! // Place 2 orders on BTC-USD: BUY 0.010 @ 50,000.00 post-only, SELL 0.010 @ 51,000.00
+//! ```
+//!
+//! Calls without a dedicated description fall back to their type name
+//! (e.g. `User/ClaimReferralRewards`).
+
+use bullet_exchange_interface::address::Address;
+use bullet_exchange_interface::decimals::PositiveDecimal;
+use bullet_exchange_interface::message::{
+    NewOrderArgs, NewTriggerOrderArgs, TpslPair, TransferEndpoint, UserAction,
+};
+use bullet_exchange_interface::transaction::{BankCall, WarpCall};
+use bullet_exchange_interface::types::{
+    BalanceBucket, MarketId, OrderType, Side, TriggerDirection, TriggerPriceCondition,
+};
+use rust_decimal::Decimal;
+
+use crate::policy::call_name;
+use crate::types::CallMessage;
+use crate::{Client, RuntimeCall, SymbolInfo, UnsignedTransaction};
+
+impl UnsignedTransaction {
+    /// Describe this transaction in one line, resolving markets against
+    /// `symbols` (e.g. [`Client::symbols`]).
+    ///
+    /// Numbers are shown exactly as signed; unknown markets are shown by id.
+    pub fn summary(&self, symbols: &[SymbolInfo]) -> String {
+        summarize(self.runtime_call(), symbols)
+    }
+}
+
+impl Client {
+    /// [`UnsignedTransaction::summary`] against the client's cached symbols.
+    pub fn summarize(&self, unsigned: &UnsignedTransaction) -> String {
+        unsigned.summary(&self.symbols())
+    }
+}
+
+fn summarize(runtime_call: &RuntimeCall, symbols: &[SymbolInfo]) -> String {
+    match runtime_call {
+        RuntimeCall::Exchange(CallMessage::User(action)) => {
+            Summarizer { symbols }.user_action(action)
+        }
+        RuntimeCall::Bank(BankCall::TransferWithMemo { to, coins, .. }) => {
+            format!(
+                "Bank transfer of {} base units to {to}",
+                group_thousands(&coins.amount.0.to_string())
+            )
+        }
+        RuntimeCall::Warp(WarpCall::TransferRemote {
+            destination_domain,
+            recipient,
+            amount,
+            ..
+        }) => format!(
+            "Bridge {} base units to 0x{} on domain {destination_domain}",
+            group_thousands(&amount.0.to_string()),
+            hex::encode(recipient.0)
+        ),
+        other => call_name(other),
+    }
+}
+
+struct Summarizer<'a> {
+    symbols: &'a [SymbolInfo],
+}
+
+impl Summarizer<'_> {
+    #[allow(deprecated)]
+    fn user_action(&self, action: &UserAction<Address>) -> String {
+        let (text, sub_account_index) = match action {
+            UserAction::PlaceOrders {
+                market_id,
+                orders,
+                replace,
+                sub_account_index,
+            } => {
+                let mut text = format!(
+                    "Place {} on {}: {}",
+                    count(orders.len(), "order"),
+                    self.market(*market_id),
+                    self.orders(*market_id, orders)
+                );
+                if *replace {
+                    text.push_str(" (replacing open orders)");
+                }
+                (text, sub_account_index)
+            }
+            UserAction::CancelAndPlaceOrders {
+                market_id,
+                cancels,
+                places,
+                sub_account_index,
+                ..
+            } => (
+                format!(
+                    "Cancel {} and place {} on {}: {}",
+                    count(cancels.len(), "order"),
+                    count(places.len(), "order"),
+                    self.market(*market_id),
+                    self.orders(*market_id, places)
+                ),
+                sub_account_index,
+            ),
+            UserAction::AmendOrders {
+                market_id,
+                orders,
+                sub_account_index,
+            } => {
+                let places: Vec<_> = orders.iter().map(|amend| amend.place.clone()).collect();
+                (
+                    format!(
+                        "Amend {} on {}: {}",
+                        count(orders.len(), "order"),
+                        self.market(*market_id),
+                        self.orders(*market_id, &places)
+                    ),
+                    sub_account_index,
+                )
+            }
+            UserAction::CancelOrders {
+                market_id,
+                orders,
+                sub_account_index,
+            } => (
+                format!(
+                    "Cancel {} on {}",
+                    count(orders.len(), "order"),
+                    self.market(*market_id)
+                ),
+                sub_account_index,
+            ),
+            UserAction::CancelMarketOrders {
+                market_id,
+                sub_account_index,
+            } => (
+                format!("Cancel all orders on {}", self.market(*market_id)),
+                sub_account_index,
+            ),
+            UserAction::CancelAllOrders { sub_account_index } => {
+                ("Cancel all orders".to_string(), sub_account_index)
+            }
+            UserAction::CreateTriggerOrders {
+                market_id,
+                trigger_orders,
+                sub_account_index,
+            } => {
+                let orders: Vec<_> = trigger_orders
+                    .iter()
+                    .map(|trigger| self.trigger_order(*market_id, trigger))
+                    .collect();
+                (
+                    format!(
+                        "Create {} on {}: {}",
+                        count(trigger_orders.len(), "trigger order"),
+                        self.market(*market_id),
+                        orders.join(", ")
+                    ),
+                    sub_account_index,
+                )
+            }
+            UserAction::CreatePositionTpsl {
+                market_id,
+                tpsl_pair,
+                size,
+                sub_account_index,
+            } => {
+                let size = match size {
+                    Some(size) => self.size(*market_id, *size),
+                    None => "whole position".to_string(),
+                };
+                (
+                    format!(
+                        "Set TP/SL on {} ({size}): {}",
+                        self.market(*market_id),
+                        self.tpsl(*market_id, tpsl_pair)
+                    ),
+                    sub_account_index,
+                )
+            }
+            UserAction::CancelTriggerOrders {
+                market_id,
+                trigger_order_ids,
+                sub_account_index,
+            } => (
+                format!(
+                    "Cancel {} on {}",
+                    count(trigger_order_ids.len(), "trigger order"),
+                    self.market(*market_id)
+                ),
+                sub_account_index,
+            ),
+            UserAction::CreateTwapOrder {
+                market_id,
+                twap_order_args,
+                sub_account_index,
+            } => {
+                let mut text = format!(
+                    "TWAP {} {} on {} over {}s",
+                    side(twap_order_args.side),
+                    self.size(*market_id, twap_order_args.total_size),
+                    self.market(*market_id),
+                    group_thousands(&twap_order_args.total_duration_seconds.to_string())
+                );
+                if twap_order_args.reduce_only {
+                    text.push_str(" reduce-only");
+                }
+                (text, sub_account_index)
+            }
+            UserAction::CancelTwapOrder {
+                market_id,
+                twap_id,
+                sub_account_index,
+            } => (
+                format!("Cancel TWAP {} on {}", twap_id.0, self.market(*market_id)),
+                sub_account_index,
+            ),
+            UserAction::UpdateMaxLeverage {
+                market_id,
+                max_leverage,
+                sub_account_index,
+            } => (
+                format!(
+                    "Set max leverage on {} to {max_leverage}x",
+                    self.market(*market_id)
+                ),
+                sub_account_index,
+            ),
+            UserAction::Transfer {
+                from,
+                to,
+                to_address,
+                asset_id,
+                amount,
+                ..
+            } => {
+                let amount = format_decimal((*amount).into(), None);
+                let text = match to_address {
+                    Some(address) => format!(
+                        "Transfer {amount} of asset {} from {} to {address} ({})",
+                        asset_id.0,
+                        self.endpoint(from),
+                        self.endpoint(to)
+                    ),
+                    None => format!(
+                        "Move {amount} of asset {} from {} to {}",
+                        asset_id.0,
+                        self.endpoint(from),
+                        self.endpoint(to)
+                    ),
+                };
+                (text, &None)
+            }
+            UserAction::Deposit { asset_id, amount } => (
+                format!(
+                    "Deposit {} of asset {}",
+                    format_decimal((*amount).into(), None),
+                    asset_id.0
+                ),
+                &None,
+            ),
+            UserAction::Withdraw { asset_id, amount } => (
+                format!(
+                    "Withdraw {} of asset {}",
+                    format_decimal((*amount).into(), None),
+                    asset_id.0
+                ),
+                &None,
+            ),
+            UserAction::DelegateUser { delegate, name } => {
+                (format!("Delegate trading to {delegate} ({name})"), &None)
+            }
+            UserAction::RevokeDelegation { delegate } => {
+                (format!("Revoke delegation for {delegate}"), &None)
+            }
+            other => return CallMessage::User(other.clone()).msg_type(),
+        };
+        match sub_account_index {
+            Some(index) => format!("{text} [sub-account {index}]"),
+            None => text,
+        }
+    }
+
+    fn info(&self, market_id: MarketId) -> Option<&SymbolInfo> {
+        self.symbols.iter().find(|info| info.market_id == market_id)
+    }
+
+    fn market(&self, market_id: MarketId) -> String {
+        match self.info(market_id) {
+            Some(info) => info.symbol.clone(),
+            None => format!("market {}", market_id.0),
+        }
+    }
+
+    fn price(&self, market_id: MarketId, price: PositiveDecimal) -> String {
+        let precision = self.info(market_id).map(|info| info.price_precision);
+        format_decimal(price.into(), precision)
+    }
+
+    fn size(&self, market_id: MarketId, size: PositiveDecimal) -> String {
+        let precision = self.info(market_id).map(|info| info.quantity_precision);
+        format_decimal(size.into(), precision)
+    }
+
+    fn orders(&self, market_id: MarketId, orders: &[NewOrderArgs]) -> String {
+        let orders: Vec<_> = orders
+            .iter()
+            .map(|order| self.order(market_id, order))
+            .collect();
+        orders.join(", ")
+    }
+
+    /// `BUY 0.010 @ 50,000.00 post-only reduce-only`
+    fn order(&self, market_id: MarketId, order: &NewOrderArgs) -> String {
+        let mut text = format!(
+            "{} {} @ {}",
+            side(order.side),
+            self.size(market_id, order.size),
+            self.price(market_id, order.price)
+        );
+        if let Some(label) = order_type(order.order_type) {
+            text.push(' ');
+            text.push_str(label);
+        }
+        if order.reduce_only {
+            text.push_str(" reduce-only");
+        }
+        if let Some(pending) = &order.pending_tpsl_pair {
+            text.push_str(&format!(
+                " with {}",
+                self.tpsl(market_id, &pending.tpsl_pair)
+            ));
+        }
+        text
+    }
+
+    /// `SELL 0.1 @ 48,000 when mark <= 49,000`
+    fn trigger_order(&self, market_id: MarketId, trigger: &NewTriggerOrderArgs) -> String {
+        let size = match trigger.size {
+            Some(size) => self.size(market_id, size),
+            None => "position".to_string(),
+        };
+        let mut text = format!(
+            "{} {size} @ {} when {} {} {}",
+            side(trigger.side),
+            self.price(market_id, trigger.order_price),
+            price_condition(trigger.price_condition),
+            direction(trigger.trigger_direction),
+            self.price(market_id, trigger.trigger_price)
+        );
+        if let Some(label) = order_type(trigger.order_type) {
+            text.push(' ');
+            text.push_str(label);
+        }
+        text
+    }
+
+    /// `TP 55,000 / SL 45,000`, by trigger price.
+    fn tpsl(&self, market_id: MarketId, pair: &TpslPair) -> String {
+        let legs: Vec<_> = [("TP", &pair.tp), ("SL", &pair.sl)]
+            .into_iter()
+            .filter_map(|(label, leg)| {
+                leg.as_ref()
+                    .map(|leg| format!("{label} {}", self.price(market_id, leg.trigger_price)))
+            })
+            .collect();
+        if legs.is_empty() {
+            "no TP/SL".to_string()
+        } else {
+            legs.join(" / ")
+        }
+    }
+
+    fn endpoint(&self, endpoint: &TransferEndpoint) -> String {
+        let balance = match endpoint.balance {
+            BalanceBucket::Bank => "bank".to_string(),
+            BalanceBucket::Cross => "cross margin".to_string(),
+            BalanceBucket::Spot => "spot".to_string(),
+            BalanceBucket::Iso(market_id) => format!("{} isolated margin", self.market(market_id)),
+            _ => "balance".to_string(),
+        };
+        match endpoint.sub_account_index {
+            Some(index) => format!("sub-account {index} {balance}"),
+            None => balance,
+        }
+    }
+}
+
+fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
+
+fn side(side: Side) -> &'static str {
+    match side {
+        Side::Bid => "BUY",
+        Side::Ask => "SELL",
+    }
+}
+
+/// Plain limit orders get no label.
+fn order_type(order_type: OrderType) -> Option<&'static str> {
+    match order_type {
+        OrderType::Limit => None,
+        OrderType::PostOnly => Some("post-only"),
+        OrderType::FillOrKill => Some("fill-or-kill"),
+        OrderType::ImmediateOrCancel => Some("IOC"),
+        OrderType::PostOnlySlide => Some("post-only slide"),
+        OrderType::PostOnlyFront => Some("post-only front"),
+        OrderType::Liquidation => Some("liquidation"),
+    }
+}
+
+fn price_condition(condition: TriggerPriceCondition) -> &'static str {
+    match condition {
+        TriggerPriceCondition::Mark => "mark",
+        TriggerPriceCondition::Oracle => "oracle",
+        TriggerPriceCondition::LastTrade => "last",
+    }
+}
+
+fn direction(direction: TriggerDirection) -> &'static str {
+    match direction {
+        TriggerDirection::GreaterThanOrEqual => ">=",
+        TriggerDirection::LessThanOrEqual => "<=",
+    }
+}
+
+/// Show `value` exactly, padded with zeros to at least `precision` decimal
+/// places (when known), and group the integer digits: `50000.1` with
+/// precision 2 → `50,000.10`. Never rounds, so the summary shows what is
+/// signed.
+fn format_decimal(value: Decimal, precision: Option<u8>) -> String {
+    let mut value = value.normalize();
+    if let Some(precision) = precision
+        && value.scale() < precision.into()
+    {
+        value.rescale(precision.into());
+    }
+    group_thousands(&value.to_string())
+}
+
+fn group_thousands(number: &str) -> String {
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    let mut grouped = String::with_capacity(integer.len() + integer.len() / 3);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    match fraction {
+        Some(fraction) => format!("{sign}{grouped}.{fraction}"),
+        None => format!("{sign}{grouped}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_exchange_interface::message::{CancelOrderArgs, PendingTpslPair, Tpsl};
+    use bullet_exchange_interface::types::{AssetId, OrderId};
+
+    use super::*;
+    use crate::testing::{mock_client_with_symbols, positive, symbol, unsigned};
+    use crate::{Keypair, NewOrderExt, Signer};
+
+    #[test]
+    fn formats_numbers() {
+        assert_eq!(
+            format_decimal(Decimal::new(5000000, 2), Some(2)),
+            "50,000.00"
+        );
+        assert_eq!(
+            format_decimal(Decimal::new(123456789, 3), None),
+            "123,456.789"
+        );
+        assert_eq!(format_decimal(Decimal::new(10_123, 6), Some(2)), "0.010123");
+        assert_eq!(format_decimal(Decimal::new(1_500, 3), Some(0)), "1.5");
+        assert_eq!(group_thousands("-1234567"), "-1,234,567");
+        assert_eq!(group_thousands("999"), "999");
+    }
+
+    #[tokio::test]
+    async fn summarizes_orders_with_market_symbols() {
+        let (_server, client) =
+            mock_client_with_symbols(vec![symbol("BTC-USD", 0, "TRADING")]).await;
+
+        let mut sell = NewOrderArgs::limit(positive(51_000, 0), positive(2, 2), Side::Ask);
+        sell.reduce_only = true;
+        sell.pending_tpsl_pair = Some(PendingTpslPair {
+            tpsl_pair: TpslPair {
+                tp: None,
+                sl: Some(Tpsl {
+                    order_price: positive(52_000, 0),
+                    trigger_price: positive(52_500, 0),
+                    price_condition: TriggerPriceCondition::Mark,
+                    order_type: OrderType::ImmediateOrCancel,
+                }),
+            },
+            dynamic_size: false,
+        });
+        let place = CallMessage::User(UserAction::PlaceOrders {
+            market_id: MarketId(0),
+            orders: vec![
+                NewOrderArgs::post_only(positive(50_000, 0), positive(1, 2), Side::Bid),
+                sell,
+            ],
+            replace: false,
+            sub_account_index: None,
+        });
+        assert_eq!(
+            client.summarize(&unsigned(place, &client)),
+            "Place 2 orders on BTC-USD: BUY 0.010 @ 50,000.00 post-only, \
+             SELL 0.020 @ 51,000.00 reduce-only with SL 52,500.00"
+        );
+
+        let cancel = CallMessage::User(UserAction::CancelOrders {
+            market_id: MarketId(7),
+            orders: vec![CancelOrderArgs {
+                order_id: Some(OrderId(1)),
+                client_order_id: None,
+            }],
+            sub_account_index: Some(2),
+        });
+        assert_eq!(
+            client.summarize(&unsigned(cancel, &client)),
+            "Cancel 1 order on market 7 [sub-account 2]"
+        );
+    }
+
+    #[tokio::test]
+    async fn summarizes_transfers_and_falls_back_to_call_names() {
+        let (_server, client) = mock_client_with_symbols(vec![]).await;
+        let recipient = Address(Keypair::generate().pubkey());
+        let transfer = CallMessage::User(UserAction::Transfer {
+            from: TransferEndpoint {
+                sub_account_index: None,
+                balance: BalanceBucket::Cross,
+            },
+            to: TransferEndpoint {
+                sub_account_index: None,
+                balance: BalanceBucket::Bank,
+            },
+            to_address: Some(recipient),
+            asset_id: AssetId(0),
+            amount: positive(25_005, 1),
+            memo: "".into(),
+        });
+        assert_eq!(
+            unsigned(transfer, &client).summary(&[]),
+            format!("Transfer 2,500.5 of asset 0 from cross margin to {recipient} (bank)")
+        );
+
+        let claim = CallMessage::User(UserAction::ClaimReferralRewards {
+            asset_id: AssetId(0),
+        });
+        assert_eq!(
+            unsigned(claim, &client).summary(&[]),
+            "User/ClaimReferralRewards"
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! Shared fixtures for unit tests that need a connected [`Client`].
+
+use bullet_exchange_interface::decimals::PositiveDecimal;
+use bullet_exchange_interface::event::{Event, FillType};
+use bullet_exchange_interface::schema::Schema;
+use bullet_exchange_interface::time::UnixTimestampMicros;
+use bullet_exchange_interface::transaction::Transaction as InterfaceTransaction;
+use bullet_exchange_interface::types::{AssetId, ClientOrderId, MarketId, OrderId, Side, TradeId};
+use rust_decimal::Decimal;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+use crate::generated::types::Symbol;
+use crate::types::CallMessage;
+use crate::{Client, Keypair, SignedTransaction, UnsignedTransaction};
+
+/// `/rollup/schema` body for the SDK's own schema with chain hash
+/// `[chain_byte; 32]`.
+pub(crate) fn schema_response(chain_byte: u8) -> serde_json::Value {
+    let schema = Schema::of_single_type::<InterfaceTransaction>().unwrap();
+    serde_json::json!({
+        "chain_hash": format!("0x{}", hex::encode([chain_byte; 32])),
+        "schema": schema,
+    })
+}
+
+/// `/fapi/v1/exchangeInfo` body listing the given symbols.
+pub(crate) fn exchange_info_response(symbols: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "assets": [],
+        "rateLimits": [],
+        "symbols": symbols,
+        "globalConfig": {
+            "maxOrdersPerUser": 0,
+            "maxTriggerOrdersPerUser": 0,
+            "maxTriggerOrdersToExecutePerMsg": 0,
+            "minNotionalTwapValue": "0",
+            "minNotionalTwapValuePerOrder": "0",
+            "twapExecutionIntervalSeconds": 0,
+        },
+        "globalVaultConfig": {
+            "leaderMinimumHoldingPercentage": 0,
+            "creationFeeUsdc": "0",
+            "minDepositValue": "0",
+        },
+    })
+}
+
+/// Mount the schema and exchange-info mocks `Client::builder().build()` needs.
+pub(crate) async fn mount_connect_mocks(server: &MockServer, symbols: serde_json::Value) {
+    mount_chain_mocks(server, 7, symbols).await;
+}
+
+/// Like [`mount_connect_mocks`] but serving the chain hash `[chain_byte; 32]`.
+pub(crate) async fn mount_chain_mocks(
+    server: &MockServer,
+    chain_byte: u8,
+    symbols: serde_json::Value,
+) {
+    Mock::given(method("GET"))
+        .and(path("/rollup/schema"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(schema_response(chain_byte)))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/fapi/v1/exchangeInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(exchange_info_response(symbols)))
+        .mount(server)
+        .await;
+}
+
+/// Start a mock server and connect a [`Client`] to it with no symbols listed.
+pub(crate) async fn mock_client() -> (MockServer, Client) {
+    mock_client_with_symbols(vec![]).await
+}
+
+/// Start a mock server and connect a [`Client`] to it listing `symbols`.
+pub(crate) async fn mock_client_with_symbols(symbols: Vec<Symbol>) -> (MockServer, Client) {
+    let server = MockServer::start().await;
+    mount_connect_mocks(&server, serde_json::to_value(symbols).unwrap()).await;
+    let client = Client::builder()
+        .network(server.uri())
+        .build()
+        .await
+        .unwrap();
+    (server, client)
+}
+
+/// A keyed [`Client`] over two mock endpoints, with the active one first.
+///
+/// The servers are unpooled, so dropping one closes its port.
+pub(crate) async fn two_endpoint_client() -> (MockServer, MockServer, Client) {
+    let a = MockServer::builder().start().await;
+    let b = MockServer::builder().start().await;
+    for server in [&a, &b] {
+        mount_connect_mocks(server, serde_json::json!([])).await;
+    }
+    let client = Client::builder()
+        .network(vec![a.uri(), b.uri()])
+        .keypair(Keypair::generate())
+        .build()
+        .await
+        .unwrap();
+    if client.url() == a.uri() {
+        (a, b, client)
+    } else {
+        (b, a, client)
+    }
+}
+
+/// `value * 10^-scale` as a [`PositiveDecimal`].
+pub(crate) fn positive(value: i64, scale: u32) -> PositiveDecimal {
+    PositiveDecimal::try_from(Decimal::new(value, scale)).unwrap()
+}
+
+/// An [`UnsignedTransaction`] for `call_message` with a generous fee cap.
+pub(crate) fn unsigned(call_message: CallMessage, client: &Client) -> UnsignedTransaction {
+    UnsignedTransaction::builder()
+        .call_message(call_message)
+        .max_fee(10_000_000)
+        .priority_fee_bips(0)
+        .client(client)
+        .build()
+        .unwrap()
+}
+
+/// A perpetual `{base}-USD` symbol with the given market ID and status.
+pub(crate) fn symbol(name: &str, market_id: u16, status: &str) -> Symbol {
+    let base = name.split('-').next().unwrap_or(name);
+    Symbol {
+        symbol: name.into(),
+        market_id,
+        status: status.into(),
+        base_asset: base.into(),
+        base_asset_id: market_id,
+        quote_asset: "USD".into(),
+        price_precision: 2,
+        quantity_precision: 3,
+        pair: name.replace('-', ""),
+        contract_type: "PERPETUAL".into(),
+        delivery_date: 0,
+        onboard_date: 0,
+        margin_asset: "USD".into(),
+        base_asset_precision: 8,
+        quote_precision: 8,
+        underlying_type: "COIN".into(),
+        underlying_sub_type: vec![],
+        settle_plan: 0,
+        trigger_protect: Default::default(),
+        filters: vec![],
+        order_types: vec![],
+        time_in_force: vec![],
+        liquidation_fee: Default::default(),
+        market_take_bound: Default::default(),
+        maker_fee_bps: vec![],
+        taker_fee_bps: vec![],
+    }
+}
+
+/// Like [`mock_client_with_symbols`] but with a freshly generated keypair, so
+/// the client can sign.
+pub(crate) async fn mock_signing_client(symbols: Vec<Symbol>) -> (MockServer, Client) {
+    let server = MockServer::start().await;
+    mount_connect_mocks(&server, serde_json::to_value(symbols).unwrap()).await;
+    let client = Client::builder()
+        .network(server.uri())
+        .keypair(Keypair::generate())
+        .build()
+        .await
+        .unwrap();
+    (server, client)
+}
+
+/// Accept every `/tx/submit` with a processed response.
+pub(crate) async fn mount_submit_ok(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/tx/submit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "0xtx",
+            "status": "processed",
+            "events": [],
+        })))
+        .mount(server)
+        .await;
+}
+
+/// Number of `/tx/submit` requests the server has received.
+pub(crate) async fn submit_count(server: &MockServer) -> usize {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.url.path() == "/tx/submit")
+        .count()
+}
+
+/// A resting limit order row as returned by `/fapi/v1/openOrders`.
+pub(crate) fn open_order_json(order_id: u64, market_id: u16) -> serde_json::Value {
+    serde_json::json!({
+        "orderId": order_id,
+        "symbol": "BTC-USD",
+        "marketId": market_id,
+        "status": "NEW",
+        "price": "100",
+        "avgPrice": "0",
+        "origQty": "1",
+        "executedQty": "0",
+        "cumQty": "0",
+        "cumQuote": "0",
+        "timeInForce": "GTC",
+        "orderType": "LIMIT",
+        "reduceOnly": false,
+        "closePosition": false,
+        "side": "BUY",
+        "positionSide": "BOTH",
+        "workingType": "CONTRACT_PRICE",
+        "priceProtect": false,
+        "origType": "LIMIT",
+        "priceMatch": "NONE",
+        "selfTradePreventionMode": "NONE",
+        "goodTillDate": 0,
+        "updateTime": 0,
+        "isTrigger": false,
+    })
+}
+
+/// A `/fapi/v3/account` body holding `(symbol, market_id, position_amt)`
+/// positions.
+pub(crate) fn account_json(positions: &[(&str, u16, &str)]) -> serde_json::Value {
+    let positions: Vec<_> = positions
+        .iter()
+        .map(|(symbol, market_id, amount)| {
+            serde_json::json!({
+                "symbol": symbol,
+                "marketId": market_id,
+                "unrealizedProfit": "0",
+                "entryPrice": "100",
+                "initialMargin": "0",
+                "maintMargin": "0",
+                "positionSide": "BOTH",
+                "positionAmt": amount,
+                "updateTime": 0,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "totalInitialMargin": "0",
+        "totalMaintMargin": "0",
+        "totalWalletBalance": "0",
+        "totalUnrealizedProfit": "0",
+        "totalMarginBalance": "0",
+        "totalPositionInitialMargin": "0",
+        "totalOpenOrderInitialMargin": "0",
+        "totalCrossWalletBalance": "0",
+        "totalCrossUnPnl": "0",
+        "availableBalance": "0",
+        "maxWithdrawAmount": "0",
+        "feeTier": 0,
+        "feeDiscountBps": 0,
+        "assets": [],
+        "positions": positions,
+    })
+}
+
+/// Decode the signed transaction carried by a `/tx/submit` request.
+pub(crate) fn decode_submitted(request: &Request) -> SignedTransaction {
+    use base64::Engine;
+
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(body["body"].as_str().unwrap())
+        .unwrap();
+    borsh::from_slice(&bytes).unwrap()
+}
+
+/// A serialized exchange `TradeV1` event value for one side of a match.
+pub(crate) fn trade_event(
+    market_id: MarketId,
+    side: Side,
+    price: Decimal,
+    size: Decimal,
+    is_maker: bool,
+    client_order_id: Option<ClientOrderId>,
+) -> serde_json::Value {
+    let event: Event<String> = Event::TradeV1 {
+        user_address: "trader".to_string(),
+        market_id,
+        price: PositiveDecimal::try_from(price).unwrap(),
+        size: PositiveDecimal::try_from(size).unwrap(),
+        side,
+        order_id: OrderId(1),
+        is_maker,
+        is_full_fill: false,
+        realized_pnl: Decimal::ZERO,
+        fee: Decimal::ZERO,
+        net_fee: PositiveDecimal::try_from(Decimal::new(1, 2)).unwrap(),
+        trade_id: TradeId(1),
+        client_order_id,
+        execution_timestamp: UnixTimestampMicros(0),
+        fee_asset: AssetId(0),
+        fill_type: FillType::Orderbook,
+        cumulative_filled_size: None,
+        cumulative_filled_cot: None,
+        remaining_size: None,
+    };
+    serde_json::to_value(event).unwrap()
+}
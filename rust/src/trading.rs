@@ -30,7 +30,7 @@ use bullet_exchange_interface::types::{MarketId, OrderType, Side};
 
 use crate::generated::types::SubmitTxResponse;
 use crate::types::{CallMessage, UserAction};
-use crate::{Client, SDKError, SDKResult};
+use crate::{Client, SDKError, SDKResult, derive_sub_account_address};
 
 // ── Order construction helpers ──────────────────────────────────────────────
 
@@ -98,6 +98,12 @@ impl Client {
     /// * `replace` — If `true`, cancel existing orders before placing new ones
     /// * `sub_account_index` — `None` for the main account, `Some(n)` for a sub-account
     ///
+    /// Unless the client was built with `market_status_guard(false)`, returns
+    /// `Err(SDKError::MarketNotTrading)` without submitting if the market's
+    /// cached status isn't `TRADING`. Use
+    /// [`place_orders_unchecked`](Self::place_orders_unchecked) to bypass the
+    /// check for a single call.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -120,6 +126,22 @@ impl Client {
         orders: Vec<NewOrderArgs>,
         replace: bool,
         sub_account_index: Option<u8>,
+    ) -> SDKResult<SubmitTxResponse> {
+        if self.market_status_guard() {
+            self.ensure_market_trading(market_id)?;
+        }
+        self.place_orders_unchecked(market_id, orders, replace, sub_account_index)
+            .await
+    }
+
+    /// Like [`place_orders`](Self::place_orders), but never checks the cached
+    /// market status.
+    pub async fn place_orders_unchecked(
+        &self,
+        market_id: MarketId,
+        orders: Vec<NewOrderArgs>,
+        replace: bool,
+        sub_account_index: Option<u8>,
     ) -> SDKResult<SubmitTxResponse> {
         let call_msg = CallMessage::User(UserAction::PlaceOrders {
             market_id,
@@ -201,9 +223,10 @@ impl Client {
     // These derive the account address from the client's keypair so you
     // don't have to format it manually on every call.
 
-    /// Get the base58 address derived from the client's keypair.
+    /// Get the base58 address of the client's signer.
     ///
-    /// Returns `Err(SDKError::MissingKeypair)` if no keypair is configured.
+    /// Returns `Err(SDKError::MissingKeypair)` if no keypair or signer is
+    /// configured.
     ///
     /// # Example
     ///
@@ -212,8 +235,18 @@ impl Client {
     /// println!("My address: {address}"); // e.g. "5Hq3...xyz"
     /// ```
     pub fn address(&self) -> SDKResult<String> {
-        let kp = self.keypair().ok_or(SDKError::MissingKeypair)?;
-        Ok(kp.address())
+        let signer = self.signer().ok_or(SDKError::MissingKeypair)?;
+        Ok(signer.address())
+    }
+
+    /// The address of the client's main account (`None`) or of one of its
+    /// sub-accounts.
+    pub(crate) fn account_address(&self, sub_account_index: Option<u8>) -> SDKResult<String> {
+        let address = self.address()?;
+        match sub_account_index {
+            Some(index) => derive_sub_account_address(&address, index.into()),
+            None => Ok(address),
+        }
     }
 
     /// Query open orders for the client's own account on a symbol.
@@ -237,7 +270,10 @@ impl Client {
         // The generated client made `symbol` optional (None = all markets);
         // this wrapper preserves the single-symbol semantics by always
         // passing `Some`.
-        let resp = self.query_open_orders(&address, Some(symbol)).await?;
+        let address = address.as_str();
+        let resp = self
+            .with_failover(|api| async move { api.query_open_orders(address, Some(symbol)).await })
+            .await?;
         Ok(resp.into_inner())
     }
 
@@ -247,7 +283,10 @@ impl Client {
     /// from the client's keypair and unwraps the response.
     pub async fn my_account(&self) -> SDKResult<crate::generated::types::Account> {
         let address = self.address()?;
-        let resp = self.account_info(&address).await?;
+        let address = address.as_str();
+        let resp = self
+            .with_failover(|api| async move { api.account_info(address).await })
+            .await?;
         Ok(resp.into_inner())
     }
 
@@ -257,7 +296,10 @@ impl Client {
     /// from the client's keypair and unwraps the response.
     pub async fn my_balances(&self) -> SDKResult<Vec<crate::generated::types::Balance>> {
         let address = self.address()?;
-        let resp = self.account_balance(&address).await?;
+        let address = address.as_str();
+        let resp = self
+            .with_failover(|api| async move { api.account_balance(address).await })
+            .await?;
         Ok(resp.into_inner())
     }
 
@@ -268,6 +310,10 @@ impl Client {
     /// Each [`AmendOrderArgs`] pairs a [`CancelOrderArgs`] with a [`NewOrderArgs`],
     /// atomically replacing the cancelled order with a new one.
     ///
+    /// Subject to the same market status check as
+    /// [`place_orders`](Self::place_orders); use
+    /// [`amend_orders_unchecked`](Self::amend_orders_unchecked) to bypass it.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -290,6 +336,21 @@ impl Client {
         market_id: MarketId,
         orders: Vec<AmendOrderArgs>,
         sub_account_index: Option<u8>,
+    ) -> SDKResult<SubmitTxResponse> {
+        if self.market_status_guard() {
+            self.ensure_market_trading(market_id)?;
+        }
+        self.amend_orders_unchecked(market_id, orders, sub_account_index)
+            .await
+    }
+
+    /// Like [`amend_orders`](Self::amend_orders), but never checks the cached
+    /// market status.
+    pub async fn amend_orders_unchecked(
+        &self,
+        market_id: MarketId,
+        orders: Vec<AmendOrderArgs>,
+        sub_account_index: Option<u8>,
     ) -> SDKResult<SubmitTxResponse> {
         let call_msg = CallMessage::User(UserAction::AmendOrders {
             market_id,
@@ -298,6 +359,22 @@ impl Client {
         });
         self.send_call_message(call_msg).await
     }
+
+    /// Check that a market's cached status is `TRADING`.
+    ///
+    /// Returns `Err(SDKError::MarketNotTrading)` otherwise. Markets missing
+    /// from the cached metadata (e.g. listed after the last refresh) pass, and
+    /// the exchange remains the final authority either way. Cancels are never
+    /// checked, so resting orders can always be pulled from a halted market.
+    pub fn ensure_market_trading(&self, market_id: MarketId) -> SDKResult<()> {
+        match self.symbol_info(market_id) {
+            Some(info) if info.status != "TRADING" => Err(SDKError::MarketNotTrading {
+                symbol: info.symbol,
+                status: info.status,
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -307,6 +384,7 @@ mod tests {
     use rust_decimal::Decimal;
 
     use super::*;
+    use crate::testing::{mock_client_with_symbols, symbol};
 
     fn dec(s: &str) -> PositiveDecimal {
         PositiveDecimal::try_from(Decimal::from_str(s).unwrap()).unwrap()
@@ -338,4 +416,65 @@ mod tests {
         assert!(!order.reduce_only);
         assert!(order.client_order_id.is_none());
     }
+
+    fn bid() -> Vec<NewOrderArgs> {
+        vec![NewOrderArgs::limit(dec("50000"), dec("0.1"), Side::Bid)]
+    }
+
+    // The mock client has no keypair, so `MissingKeypair` shows the guard
+    // let the order through to signing.
+
+    #[tokio::test]
+    async fn guard_rejects_orders_on_halted_market() {
+        let (_server, client) = mock_client_with_symbols(vec![symbol("BTC-USD", 0, "HALT")]).await;
+
+        let placed = client.place_orders(MarketId(0), bid(), false, None).await;
+        let amended = client.amend_orders(MarketId(0), vec![], None).await;
+
+        assert!(matches!(
+            placed,
+            Err(SDKError::MarketNotTrading { ref symbol, ref status })
+                if symbol == "BTC-USD" && status == "HALT"
+        ));
+        assert!(matches!(amended, Err(SDKError::MarketNotTrading { .. })));
+    }
+
+    #[tokio::test]
+    async fn guard_passes_trading_and_unknown_markets() {
+        let (_server, client) =
+            mock_client_with_symbols(vec![symbol("BTC-USD", 0, "TRADING")]).await;
+
+        let trading = client.place_orders(MarketId(0), bid(), false, None).await;
+        let unknown = client.place_orders(MarketId(9), bid(), false, None).await;
+
+        assert!(matches!(trading, Err(SDKError::MissingKeypair)));
+        assert!(matches!(unknown, Err(SDKError::MissingKeypair)));
+    }
+
+    #[tokio::test]
+    async fn unchecked_call_bypasses_guard() {
+        let (_server, client) = mock_client_with_symbols(vec![symbol("BTC-USD", 0, "HALT")]).await;
+
+        let result = client
+            .place_orders_unchecked(MarketId(0), bid(), false, None)
+            .await;
+
+        assert!(matches!(result, Err(SDKError::MissingKeypair)));
+    }
+
+    #[tokio::test]
+    async fn guard_can_be_disabled_per_client() {
+        let (server, _) = mock_client_with_symbols(vec![symbol("BTC-USD", 0, "HALT")]).await;
+        let client = Client::builder()
+            .network(server.uri())
+            .market_status_guard(false)
+            .build()
+            .await
+            .unwrap();
+
+        let result = client.place_orders(MarketId(0), bid(), false, None).await;
+
+        assert!(!client.market_status_guard());
+        assert!(matches!(result, Err(SDKError::MissingKeypair)));
+    }
 }
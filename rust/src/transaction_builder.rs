@@ -25,6 +25,9 @@
 //! let pub_key: [u8; 32] = external_signer.public_key();
 //! let signed = Transaction::from_parts(unsigned, signature, pub_key);
 //!
+//! // Or let any `Signer` (e.g. a remote one) do it
+//! let signed = Transaction::sign_async(unsigned, &remote_signer).await?;
+//!
 //! // Submit later
 //! client.send_transaction(&signed).await?;
 //! ```
@@ -40,12 +43,11 @@ use bullet_exchange_interface::transaction::{
 };
 use serde_json::Value;
 
-use crate::codegen::Error::ErrorResponse;
 use crate::generated::types::{
     ApiErrorResponse, SubmitSolanaOffchainTxRequest, SubmitTxRequest, SubmitTxResponse,
 };
 use crate::types::CallMessage;
-use crate::{Client, Keypair, SDKError, SDKResult};
+use crate::{Client, SDKError, SDKResult, Signer};
 
 // ── UnsignedTransaction ──────────────────────────────────────────────────────
 
@@ -89,30 +91,36 @@ impl UnsignedTransaction {
     /// byte-identical to what was signed; the stored bytes, not a separate JSON
     /// representation, are the source of truth.
     pub fn from_bytes(bytes: &[u8], client: &Client) -> SDKResult<UnsignedTransaction> {
-        const CHAIN_HASH_LEN: usize = 32;
-        if bytes.len() < CHAIN_HASH_LEN {
-            return Err(SDKError::SerializationError(format!(
-                "unsigned transaction bytes too short: {} (need at least {CHAIN_HASH_LEN} for the chain hash)",
-                bytes.len()
-            )));
-        }
-        let (payload, chain_hash_bytes) = bytes.split_at(bytes.len() - CHAIN_HASH_LEN);
-        let mut chain_hash = [0u8; CHAIN_HASH_LEN];
-        chain_hash.copy_from_slice(chain_hash_bytes);
-
-        if chain_hash != client.chain_hash() {
+        let unsigned = Self::decode_bytes(bytes)?;
+        if unsigned.chain_hash != client.chain_hash() {
             return Err(SDKError::InvalidChainHash(
                 "does not match the connected client (built for a different network?)".to_string(),
             ));
         }
-
-        let inner = RawUnsignedTransaction::try_from_slice(payload)
-            .map_err(|e| SDKError::SerializationError(e.to_string()))?;
-
         Ok(UnsignedTransaction {
-            inner,
-            chain_hash,
             chain_name: client.chain_name(),
+            ..unsigned
+        })
+    }
+
+    /// Reconstruct an [`UnsignedTransaction`] from the JSON message produced
+    /// by [`to_message_bytes`](UnsignedTransaction::to_message_bytes) — the
+    /// inverse of that method.
+    ///
+    /// The embedded `chain_name` must match `client`'s. The message must be
+    /// canonical: re-encoding the decoded transaction has to reproduce
+    /// `bytes` exactly, so whatever is inspected is what gets signed.
+    pub fn from_message_bytes(bytes: &[u8], client: &Client) -> SDKResult<UnsignedTransaction> {
+        let unsigned = Self::decode_message_bytes(bytes)?;
+        if unsigned.chain_name != client.chain_name() {
+            return Err(SDKError::InvalidChainHash(
+                "chain_name does not match the connected client (built for a different network?)"
+                    .to_string(),
+            ));
+        }
+        Ok(UnsignedTransaction {
+            chain_hash: client.chain_hash(),
+            ..unsigned
         })
     }
 
@@ -269,6 +277,125 @@ impl UnsignedTransaction {
     }
 }
 
+impl UnsignedTransaction {
+    /// The call this transaction executes.
+    pub fn runtime_call(&self) -> &RuntimeCall {
+        &self.inner.runtime_call
+    }
+
+    /// The most this transaction may pay in fees.
+    pub fn max_fee(&self) -> u128 {
+        self.inner.details.max_fee.0
+    }
+
+    /// Replay protection: a time window (the default), a nonce, or a
+    /// generation.
+    pub fn uniqueness(&self) -> &UniquenessData {
+        &self.inner.uniqueness
+    }
+
+    /// Fee settings and chain id.
+    pub fn details(&self) -> &TxDetails {
+        &self.inner.details
+    }
+
+    /// The chain hash the signable bytes are bound to. Zero for
+    /// transactions decoded from an offchain message alone.
+    pub fn chain_hash(&self) -> [u8; 32] {
+        self.chain_hash
+    }
+
+    /// The chain name the offchain message is bound to. Empty for
+    /// transactions decoded from Borsh bytes alone.
+    pub fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+
+    /// Decode either form of signable bytes — [`to_bytes`](Self::to_bytes)
+    /// or [`to_message_bytes`](Self::to_message_bytes) — without checking
+    /// which chain they were built for. Only the fields carried by the
+    /// chosen form are filled in; the other chain identifier is left empty.
+    pub(crate) fn decode_signable(bytes: &[u8]) -> SDKResult<UnsignedTransaction> {
+        Self::decode_bytes(bytes).or_else(|_| Self::decode_message_bytes(bytes))
+    }
+
+    /// `borsh(payload) ++ chain_hash`, with an empty `chain_name`.
+    pub(crate) fn decode_bytes(bytes: &[u8]) -> SDKResult<UnsignedTransaction> {
+        const CHAIN_HASH_LEN: usize = 32;
+        if bytes.len() < CHAIN_HASH_LEN {
+            return Err(SDKError::SerializationError(format!(
+                "unsigned transaction bytes too short: {} (need at least {CHAIN_HASH_LEN} for the chain hash)",
+                bytes.len()
+            )));
+        }
+        let (payload, chain_hash_bytes) = bytes.split_at(bytes.len() - CHAIN_HASH_LEN);
+        let mut chain_hash = [0u8; CHAIN_HASH_LEN];
+        chain_hash.copy_from_slice(chain_hash_bytes);
+
+        let inner = RawUnsignedTransaction::try_from_slice(payload)
+            .map_err(|e| SDKError::SerializationError(e.to_string()))?;
+
+        Ok(UnsignedTransaction {
+            inner,
+            chain_hash,
+            chain_name: String::new(),
+        })
+    }
+
+    /// The canonical offchain JSON message, with a zero `chain_hash`.
+    fn decode_message_bytes(bytes: &[u8]) -> SDKResult<UnsignedTransaction> {
+        let Value::Object(mut message) = serde_json::from_slice(bytes)? else {
+            return Err(SDKError::SerializationError(
+                "offchain message is not a JSON object".to_string(),
+            ));
+        };
+        let Some(Value::String(chain_name)) = message.remove("chain_name") else {
+            return Err(SDKError::SerializationError(
+                "offchain message missing string field chain_name".to_string(),
+            ));
+        };
+        if let Some(max_fee) = message
+            .get_mut("details")
+            .and_then(|details| details.get_mut("max_fee"))
+            && let Value::String(digits) = max_fee
+        {
+            *max_fee = serde_json::from_str(digits)?;
+        }
+
+        let unsigned = UnsignedTransaction {
+            inner: serde_json::from_value(Value::Object(message))?,
+            chain_hash: [0; 32],
+            chain_name,
+        };
+        if unsigned.to_message_bytes()? != bytes {
+            return Err(SDKError::SerializationError(
+                "offchain message is not in canonical form".to_string(),
+            ));
+        }
+        Ok(unsigned)
+    }
+
+    /// [`from_runtime_call`](Self::from_runtime_call) with unset fee
+    /// settings taken from the client.
+    pub(crate) fn with_client_defaults(
+        runtime_call: RuntimeCall,
+        max_fee: Option<u128>,
+        priority_fee_bips: Option<u64>,
+        gas_limit: Option<Gas>,
+        uniqueness: Option<UniquenessData>,
+        client: &Client,
+    ) -> SDKResult<UnsignedTransaction> {
+        Self::from_runtime_call(
+            runtime_call,
+            max_fee.unwrap_or_else(|| client.max_fee().0),
+            priority_fee_bips.unwrap_or_else(|| client.max_priority_fee_bips().0),
+            gas_limit.or_else(|| client.gas_limit()),
+            uniqueness,
+            client,
+        )
+    }
+}
+
 // ── Solana preamble ──────────────────────────────────────────────────────────
 
 // Solana off-chain signing domain: 0xff followed by "solana offchain" (15 bytes).
@@ -337,6 +464,28 @@ impl SolanaOffchainTransaction {
         })
     }
 
+    /// Sign [`UnsignedTransaction::to_message_bytes`] with `signer` and
+    /// assemble the result.
+    ///
+    /// Fails with [`SDKError::AsyncSignerRequired`] for signers that only
+    /// sign asynchronously; use [`sign_async`](Self::sign_async) for those.
+    pub fn sign(tx: UnsignedTransaction, signer: &dyn Signer) -> SDKResult<Self> {
+        let signature = signer.sign_message(&tx.to_message_bytes()?)?;
+        Self::from_parts(tx, signature, signer.pubkey())
+    }
+
+    /// Like [`sign`](Self::sign), awaiting the signer's async flavour.
+    pub async fn sign_async(tx: UnsignedTransaction, signer: &dyn Signer) -> SDKResult<Self> {
+        let message = tx.to_message_bytes()?;
+        let signature = signer.sign_message_async(&message).await?;
+        Ok(Self {
+            signed_message: message,
+            chain_hash: tx.chain_hash,
+            pubkey: signer.pubkey(),
+            signature,
+        })
+    }
+
     /// Borsh-serialize a Solana offchain transaction to bytes.
     pub fn to_bytes(&self) -> SDKResult<Vec<u8>> {
         borsh::to_vec(self).map_err(|e| SDKError::SerializationError(e.to_string()))
@@ -347,6 +496,38 @@ impl SolanaOffchainTransaction {
         let bytes = self.to_bytes()?;
         Ok(BASE64.encode(&bytes))
     }
+
+    /// Parse the Borsh bytes from [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> SDKResult<Self> {
+        Self::try_from_slice(bytes).map_err(|e| SDKError::SerializationError(e.to_string()))
+    }
+
+    /// Parse the base64 string from [`to_base64`](Self::to_base64).
+    pub fn from_base64(encoded: &str) -> SDKResult<Self> {
+        Self::from_bytes(&decode_base64(encoded)?)
+    }
+
+    /// Decode the signed JSON message. Does not check the signature; use
+    /// [`verify`](Self::verify) for that.
+    pub fn decode(&self) -> SDKResult<DecodedTransaction> {
+        let unsigned = UnsignedTransaction::decode_message_bytes(&self.signed_message)?;
+        Ok(DecodedTransaction {
+            pubkey: self.pubkey,
+            signature: self.signature,
+            signed_message: self.signed_message.clone(),
+            unsigned: UnsignedTransaction {
+                chain_hash: self.chain_hash,
+                ..unsigned
+            },
+        })
+    }
+
+    /// Decode the transaction and check its signature.
+    pub fn verify(&self) -> SDKResult<DecodedTransaction> {
+        let decoded = self.decode()?;
+        decoded.verify()?;
+        Ok(decoded)
+    }
 }
 
 // ── Solana Ledger transaction ────────────────────────────────────────────────
@@ -399,6 +580,132 @@ impl SolanaLedgerTransaction {
     pub fn to_base64(&self) -> String {
         BASE64.encode(self.to_bytes())
     }
+
+    /// Parse the wire format from [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> SDKResult<Self> {
+        let truncated = || {
+            SDKError::SerializationError(format!(
+                "Ledger transaction truncated: {} bytes",
+                bytes.len()
+            ))
+        };
+        let (length, rest) = bytes.split_first_chunk::<4>().ok_or_else(truncated)?;
+        let length = u32::from_le_bytes(*length) as usize;
+        let expected = length.checked_add(64).ok_or_else(|| {
+            SDKError::SerializationError(format!(
+                "Ledger transaction declares an oversized {length}-byte message"
+            ))
+        })?;
+        if rest.len() != expected {
+            return Err(SDKError::SerializationError(format!(
+                "Ledger transaction declares a {length}-byte message but carries {} bytes",
+                rest.len().saturating_sub(64)
+            )));
+        }
+        let (signed_message, signature) = rest.split_at(length);
+        Ok(Self {
+            signed_message: signed_message.to_vec(),
+            signature: signature.try_into().map_err(|_| truncated())?,
+        })
+    }
+
+    /// Parse the base64 string from [`to_base64`](Self::to_base64).
+    pub fn from_base64(encoded: &str) -> SDKResult<Self> {
+        Self::from_bytes(&decode_base64(encoded)?)
+    }
+
+    /// Decode the preamble and JSON message. Only single-signer messages are
+    /// accepted. Does not check the signature; use [`verify`](Self::verify)
+    /// for that.
+    pub fn decode(&self) -> SDKResult<DecodedTransaction> {
+        const HEADER_LEN: usize = 16 + 1 + 32 + 1 + 1 + 32 + 2;
+        let invalid = |reason: &str| {
+            SDKError::SerializationError(format!("invalid Ledger message: {reason}"))
+        };
+        let message = &self.signed_message;
+        if message.len() < HEADER_LEN {
+            return Err(invalid("shorter than the preamble"));
+        }
+        if message[..16] != SOLANA_SIGNING_DOMAIN {
+            return Err(invalid("missing the Solana off-chain signing domain"));
+        }
+        if message[16] != 0 || message[49] != 0 {
+            return Err(invalid("unsupported header version or message format"));
+        }
+        if message[50] != 1 {
+            return Err(invalid("expected exactly one signer"));
+        }
+        let mut chain_hash = [0u8; 32];
+        chain_hash.copy_from_slice(&message[17..49]);
+        let mut pubkey = [0u8; 32];
+        pubkey.copy_from_slice(&message[51..83]);
+        let length = u16::from_le_bytes([message[83], message[84]]) as usize;
+        let json = &message[HEADER_LEN..];
+        if json.len() != length {
+            return Err(invalid("message length does not match the preamble"));
+        }
+
+        let unsigned = UnsignedTransaction::decode_message_bytes(json)?;
+        Ok(DecodedTransaction {
+            pubkey,
+            signature: self.signature,
+            signed_message: message.clone(),
+            unsigned: UnsignedTransaction {
+                chain_hash,
+                ..unsigned
+            },
+        })
+    }
+
+    /// Decode the transaction and check its signature.
+    pub fn verify(&self) -> SDKResult<DecodedTransaction> {
+        let decoded = self.decode()?;
+        decoded.verify()?;
+        Ok(decoded)
+    }
+}
+
+// ── Decoded transaction ──────────────────────────────────────────────────────
+
+/// A signed transaction decoded from any of the submission formats.
+///
+/// Produced by [`Transaction::decode`], [`SolanaOffchainTransaction::decode`]
+/// and [`SolanaLedgerTransaction::decode`]. The call, uniqueness and details
+/// are read through [`unsigned`](Self::unsigned).
+#[derive(Debug)]
+pub struct DecodedTransaction {
+    /// Ed25519 public key of the signer.
+    pub pubkey: [u8; 32],
+    /// Ed25519 signature over `signed_message`.
+    pub signature: [u8; 64],
+    /// The exact bytes the signature covers.
+    pub signed_message: Vec<u8>,
+    /// The transaction that was signed.
+    pub unsigned: UnsignedTransaction,
+}
+
+impl DecodedTransaction {
+    /// The signer's on-chain address (base58-encoded public key).
+    pub fn address(&self) -> String {
+        bullet_exchange_interface::address::Address(self.pubkey).to_string()
+    }
+
+    /// Check the signature over `signed_message` against `pubkey`.
+    pub fn verify(&self) -> SDKResult<()> {
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&self.pubkey)
+            .map_err(|e| SDKError::InvalidSignature(e.to_string()))?;
+        verifying_key
+            .verify_strict(
+                &self.signed_message,
+                &ed25519_dalek::Signature::from_bytes(&self.signature),
+            )
+            .map_err(|_| {
+                SDKError::InvalidSignature(format!(
+                    "signature does not match the transaction and signer {}",
+                    self.address()
+                ))
+            })
+    }
 }
 
 // ── Transaction ──────────────────────────────────────────────────────────────
@@ -414,7 +721,10 @@ impl Transaction {
     /// Build a signed transaction.
     ///
     /// Internally builds an unsigned transaction, serializes it,
-    /// signs with the provided keypair, and assembles the result.
+    /// signs with the provided [`Signer`] (or the client's), and assembles
+    /// the result. Signers that only sign asynchronously fail with
+    /// [`SDKError::AsyncSignerRequired`]; build an [`UnsignedTransaction`]
+    /// and use [`Transaction::sign_async`] for those.
     ///
     /// # Example
     ///
@@ -435,7 +745,7 @@ impl Transaction {
         priority_fee_bips: Option<u64>,
         gas_limit: Option<Gas>,
         uniqueness: Option<UniquenessData>,
-        signer: Option<&Keypair>,
+        signer: Option<&dyn Signer>,
         client: &Client,
     ) -> SDKResult<SignedTransaction> {
         Self::from_runtime_call(
@@ -454,7 +764,7 @@ impl Transaction {
     /// The signed-transaction counterpart of
     /// [`UnsignedTransaction::from_runtime_call`]: builds the unsigned
     /// transaction, signs `to_bytes()` with `signer` (falling back to the
-    /// client's keypair), and assembles the result. Use this to sign a call
+    /// client's signer), and assembles the result. Use this to sign a call
     /// assembled dynamically rather than via the typed factories.
     #[allow(clippy::too_many_arguments)]
     pub fn from_runtime_call(
@@ -463,19 +773,13 @@ impl Transaction {
         priority_fee_bips: Option<u64>,
         gas_limit: Option<Gas>,
         uniqueness: Option<UniquenessData>,
-        signer: Option<&Keypair>,
+        signer: Option<&dyn Signer>,
         client: &Client,
     ) -> SDKResult<SignedTransaction> {
         let signer = signer
-            .or_else(|| client.keypair())
+            .or_else(|| client.signer())
             .ok_or(SDKError::MissingKeypair)?;
-
-        let max_fee = max_fee.unwrap_or_else(|| client.max_fee().0);
-        let priority_fee_bips =
-            priority_fee_bips.unwrap_or_else(|| client.max_priority_fee_bips().0);
-        let gas_limit = gas_limit.or_else(|| client.gas_limit());
-
-        let unsigned = UnsignedTransaction::from_runtime_call(
+        let unsigned = UnsignedTransaction::with_client_defaults(
             runtime_call,
             max_fee,
             priority_fee_bips,
@@ -483,18 +787,42 @@ impl Transaction {
             uniqueness,
             client,
         )?;
+        client.check_signing_policy(&unsigned)?;
 
-        let data = unsigned.to_bytes()?;
-        let sig_bytes: [u8; 64] = signer
-            .sign(&data)
-            .try_into()
-            .map_err(|v: Vec<u8>| SDKError::InvalidSignatureLength(v.len()))?;
-        let pub_key: [u8; 32] = signer
-            .public_key()
-            .try_into()
-            .map_err(|v: Vec<u8>| SDKError::InvalidPublicKeyLength(v.len()))?;
+        Self::sign(unsigned, signer)
+    }
+
+    /// Sign `unsigned.to_bytes()` with `signer` and assemble the result.
+    ///
+    /// Fails with [`SDKError::AsyncSignerRequired`] for signers that only
+    /// sign asynchronously; use [`sign_async`](Self::sign_async) for those.
+    pub fn sign(
+        unsigned: UnsignedTransaction,
+        signer: &dyn Signer,
+    ) -> SDKResult<SignedTransaction> {
+        let signature = signer.sign_message(&unsigned.to_bytes()?)?;
+        Ok(Self::from_parts(unsigned, signature, signer.pubkey()))
+    }
 
-        Ok(Self::from_parts(unsigned, sig_bytes, pub_key))
+    /// Like [`sign`](Self::sign), awaiting the signer's async flavour.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let unsigned = UnsignedTransaction::builder()
+    ///     .call_message(call_msg)
+    ///     .max_fee(10_000_000)
+    ///     .priority_fee_bips(0)
+    ///     .client(&client)
+    ///     .build()?;
+    /// let signed = Transaction::sign_async(unsigned, &remote_signer).await?;
+    /// ```
+    pub async fn sign_async(
+        unsigned: UnsignedTransaction,
+        signer: &dyn Signer,
+    ) -> SDKResult<SignedTransaction> {
+        let signature = signer.sign_message_async(&unsigned.to_bytes()?).await?;
+        Ok(Self::from_parts(unsigned, signature, signer.pubkey()))
     }
 
     /// Assemble a signed transaction from an unsigned transaction, a 64-byte
@@ -532,6 +860,59 @@ impl Transaction {
         let bytes = Self::to_bytes(signed)?;
         Ok(BASE64.encode(&bytes))
     }
+
+    /// Parse the Borsh bytes from [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> SDKResult<SignedTransaction> {
+        SignedTransaction::try_from_slice(bytes)
+            .map_err(|e| SDKError::SerializationError(e.to_string()))
+    }
+
+    /// Parse the base64 string from [`to_base64`](Self::to_base64).
+    pub fn from_base64(encoded: &str) -> SDKResult<SignedTransaction> {
+        Self::from_bytes(&decode_base64(encoded)?)
+    }
+
+    /// Split a signed transaction into its signer, signature and the
+    /// transaction they cover.
+    ///
+    /// The signed bytes include the chain hash, which a signed transaction
+    /// doesn't carry, so it must be supplied (e.g. `client.chain_hash()`).
+    /// Does not check the signature; use [`verify`](Self::verify) for that.
+    pub fn decode(
+        signed: &SignedTransaction,
+        chain_hash: [u8; 32],
+    ) -> SDKResult<DecodedTransaction> {
+        let SignedTransaction::V0(v0) = signed else {
+            return Err(SDKError::SerializationError(
+                "unsupported transaction version".to_string(),
+            ));
+        };
+        let unsigned = UnsignedTransaction {
+            inner: RawUnsignedTransaction {
+                runtime_call: v0.runtime_call.clone(),
+                uniqueness: v0.uniqueness.clone(),
+                details: v0.details.clone(),
+            },
+            chain_hash,
+            chain_name: String::new(),
+        };
+        Ok(DecodedTransaction {
+            pubkey: v0.pub_key,
+            signature: v0.signature,
+            signed_message: unsigned.to_bytes()?,
+            unsigned,
+        })
+    }
+
+    /// Check that `signed` carries a valid signature for `chain_hash`.
+    ///
+    /// Recomputes the signable bytes and verifies the Ed25519 signature
+    /// against the embedded public key. Fails with
+    /// [`SDKError::InvalidSignature`] if the signature doesn't match, e.g.
+    /// because the transaction was altered or signed for another chain.
+    pub fn verify(signed: &SignedTransaction, chain_hash: [u8; 32]) -> SDKResult<()> {
+        Self::decode(signed, chain_hash)?.verify()
+    }
 }
 
 // ── Client methods ───────────────────────────────────────────────────────────
@@ -543,15 +924,18 @@ impl Client {
         &self,
         signed: &SignedTransaction,
     ) -> SDKResult<SubmitTxResponse> {
-        let body = Transaction::to_base64(signed)?;
-        let response = self.client().submit_tx(&SubmitTxRequest { body }).await;
+        let request = &SubmitTxRequest {
+            body: Transaction::to_base64(signed)?,
+        };
+        let response = self
+            .with_submit_failover(|api| async move { api.submit_tx(request).await })
+            .await;
         match response {
-            Err(ErrorResponse(response)) if response.status() == 401 => {
-                let inner = response.into_inner();
-                Err(self.submit_tx_api_error(inner).await?)
+            Err(SDKError::ApiError(error)) if error.status == 401 => {
+                Err(self.submit_tx_api_error(*error).await?)
             }
             Ok(r) => Ok(r.into_inner()),
-            Err(e) => Err(e.into()),
+            Err(e) => Err(e),
         }
     }
 
@@ -587,13 +971,14 @@ impl Client {
     /// that's the spec's `400` chain-hash mismatch (the chain hash is a
     /// validated field), as well as a `401` invalid-signature.
     pub(crate) async fn submit_offchain(&self, body: String) -> SDKResult<SubmitTxResponse> {
-        let request = SubmitSolanaOffchainTxRequest { body };
-        match self.client().submit_solana_offchain_tx(&request).await {
+        let request = &SubmitSolanaOffchainTxRequest { body };
+        let response = self
+            .with_submit_failover(|api| async move { api.submit_solana_offchain_tx(request).await })
+            .await;
+        match response {
             Ok(r) => Ok(r.into_inner()),
-            Err(ErrorResponse(response)) => {
-                Err(self.submit_tx_api_error(response.into_inner()).await?)
-            }
-            Err(e) => Err(e.into()),
+            Err(SDKError::ApiError(error)) => Err(self.submit_tx_api_error(*error).await?),
+            Err(e) => Err(e),
         }
     }
 
@@ -613,43 +998,152 @@ impl Client {
 
     /// Build, sign, and submit a runtime call in one step, retrying once if
     /// the chain hash changed since startup.
+    ///
+    /// Signs with the client's [`Signer`] through its async flavour, so
+    /// remote signers work here.
     pub async fn send_runtime_call(
         &self,
         runtime_call: RuntimeCall,
     ) -> SDKResult<SubmitTxResponse> {
-        let signed = Transaction::from_runtime_call(
-            runtime_call.clone(),
-            None,
-            None,
-            None,
-            None,
-            None,
-            self,
-        )?;
+        let signed = self.sign_runtime_call(runtime_call.clone()).await?;
         match self.send_transaction(&signed).await {
             Err(SDKError::TransactionOutdated) => {
                 // chain hash was refreshed; re-sign with the new hash and retry once.
-                // submit directly so a second 401 comes back as ApiError, not TransactionOutdated
-                let signed = Transaction::from_runtime_call(
+                // submit directly so a second 401 comes back as ApiError, not TransactionOutdated.
+                // The call already passed the signing policy; don't count it twice.
+                let signer = self.signer().ok_or(SDKError::MissingKeypair)?;
+                let unsigned = UnsignedTransaction::with_client_defaults(
                     runtime_call,
                     None,
                     None,
                     None,
                     None,
-                    None,
                     self,
                 )?;
-                let body = Transaction::to_base64(&signed)?;
-                match self.client().submit_tx(&SubmitTxRequest { body }).await {
-                    Ok(r) => Ok(r.into_inner()),
-                    Err(ErrorResponse(r)) => Err(SDKError::ApiError(Box::new(r.into_inner()))),
-                    Err(e) => Err(e.into()),
-                }
+                let signed = Transaction::sign_async(unsigned, signer).await?;
+                self.submit_resigned(&signed).await
+            }
+            other => other,
+        }
+    }
+
+    /// Resubmit a stuck transaction with a higher priority fee.
+    ///
+    /// Rebuilds `signed`'s call with the same uniqueness data, `max_fee` and
+    /// gas limit but `new_priority_bips`, re-signs it with the client's
+    /// [`Signer`], and submits it. Both versions share their uniqueness data,
+    /// so at most one of them can land. The priority fee is still paid out of
+    /// the unchanged `max_fee`.
+    ///
+    /// `signed` must have been signed by the client's signer with
+    /// [`UniquenessData::Nonce`] or [`UniquenessData::Window`] uniqueness
+    /// (generations are deduplicated by hash, so both versions could land),
+    /// and `new_priority_bips` must exceed its current priority fee. The bumped
+    /// transaction is checked against the client's signing policy.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let signed = Transaction::builder().call_message(msg).client(&client).build()?;
+    /// if client.send_transaction(&signed).await.is_err() {
+    ///     client.bump_fee(&signed, 50).await?;
+    /// }
+    /// ```
+    pub async fn bump_fee(
+        &self,
+        signed: &SignedTransaction,
+        new_priority_bips: u64,
+    ) -> SDKResult<SubmitTxResponse> {
+        let signer = self.signer().ok_or(SDKError::MissingKeypair)?;
+        let SignedTransaction::V0(v0) = signed else {
+            return Err(SDKError::SerializationError(
+                "unsupported transaction version".to_string(),
+            ));
+        };
+        if v0.pub_key != signer.pubkey() {
+            return Err(SDKError::SigningFailed(format!(
+                "transaction was signed by {}, not the client's signer {}",
+                bullet_exchange_interface::address::Address(v0.pub_key),
+                signer.address()
+            )));
+        }
+        // Generation uniqueness is deduplicated by transaction hash, which the
+        // bump changes, so both versions could land.
+        if !matches!(
+            v0.uniqueness,
+            UniquenessData::Nonce(_) | UniquenessData::Window(_)
+        ) {
+            return Err(SDKError::RequestError(
+                "only transactions with nonce or window uniqueness can be fee-bumped".to_string(),
+            ));
+        }
+        let current_bips = v0.details.max_priority_fee_bips.0;
+        if new_priority_bips <= current_bips {
+            return Err(SDKError::RequestError(format!(
+                "new priority fee of {new_priority_bips} bips must exceed the current {current_bips} bips"
+            )));
+        }
+        // Read the chain hash on each call so the retry picks up a refresh.
+        let bumped = || UnsignedTransaction {
+            inner: RawUnsignedTransaction {
+                runtime_call: v0.runtime_call.clone(),
+                uniqueness: v0.uniqueness.clone(),
+                details: TxDetails {
+                    max_priority_fee_bips: PriorityFeeBips(new_priority_bips),
+                    ..v0.details.clone()
+                },
+            },
+            chain_hash: self.chain_hash(),
+            chain_name: self.chain_name(),
+        };
+
+        let unsigned = bumped();
+        self.check_signing_policy(&unsigned)?;
+        let resigned = Transaction::sign_async(unsigned, signer).await?;
+        match self.send_transaction(&resigned).await {
+            Err(SDKError::TransactionOutdated) => {
+                let resigned = Transaction::sign_async(bumped(), signer).await?;
+                self.submit_resigned(&resigned).await
             }
             other => other,
         }
     }
 
+    /// Build a runtime call with the client's defaults, check it against the
+    /// signing policy, and sign it with the client's signer.
+    pub(crate) async fn sign_runtime_call(
+        &self,
+        runtime_call: RuntimeCall,
+    ) -> SDKResult<SignedTransaction> {
+        let signer = self.signer().ok_or(SDKError::MissingKeypair)?;
+        let unsigned =
+            UnsignedTransaction::with_client_defaults(runtime_call, None, None, None, None, self)?;
+        self.check_signing_policy(&unsigned)?;
+        Transaction::sign_async(unsigned, signer).await
+    }
+
+    /// Reject `unsigned` if the client's signing policy doesn't allow it.
+    pub(crate) fn check_signing_policy(&self, unsigned: &UnsignedTransaction) -> SDKResult<()> {
+        match self.signing_policy() {
+            Some(policy) => Ok(policy.check(unsigned)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Submit a transaction that was just re-signed after `TransactionOutdated`,
+    /// without mapping a second 401 to `TransactionOutdated` again.
+    pub(crate) async fn submit_resigned(
+        &self,
+        signed: &SignedTransaction,
+    ) -> SDKResult<SubmitTxResponse> {
+        let request = &SubmitTxRequest {
+            body: Transaction::to_base64(signed)?,
+        };
+        self.with_submit_failover(|api| async move { api.submit_tx(request).await })
+            .await
+            .map(|r| r.into_inner())
+    }
+
     async fn submit_tx_api_error(&self, error: ApiErrorResponse) -> SDKResult<SDKError> {
         // A stale chain hash surfaces differently per submission path: the borsh
         // path bakes it into the signed bytes (→ 401 invalid signature), while
@@ -668,6 +1162,12 @@ impl Client {
     }
 }
 
+fn decode_base64(encoded: &str) -> SDKResult<Vec<u8>> {
+    BASE64
+        .decode(encoded.trim())
+        .map_err(|e| SDKError::SerializationError(format!("invalid base64: {e}")))
+}
+
 fn stringify_offchain_max_fee(
     message: &mut serde_json::Map<String, Value>,
     max_fee: u128,
@@ -705,6 +1205,7 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
+    use crate::Keypair;
 
     fn warp_bytes32(value: &str) -> WarpBytes32 {
         let raw = value.strip_prefix("0x").unwrap_or(value);
@@ -878,6 +1379,42 @@ mod tests {
         assert!(matches!(err, SDKError::SerializationError(_)), "{err:?}");
     }
 
+    #[tokio::test]
+    async fn from_message_bytes_roundtrips_to_message_bytes() {
+        let (_server, client) =
+            mock_client_for_offchain_submission(ResponseTemplate::new(200)).await;
+        let mut original = test_unsigned_tx();
+        original.inner.details.max_fee = Amount(u128::MAX);
+        original.chain_hash = client.chain_hash();
+        original.chain_name = client.chain_name();
+        let bytes = original.to_message_bytes().unwrap();
+
+        let restored = UnsignedTransaction::from_message_bytes(&bytes, &client).unwrap();
+
+        assert_eq!(restored.to_message_bytes().unwrap(), bytes);
+        assert_eq!(restored.to_bytes().unwrap(), original.to_bytes().unwrap());
+        assert_eq!(restored.max_fee(), u128::MAX);
+    }
+
+    #[tokio::test]
+    async fn from_message_bytes_rejects_foreign_or_non_canonical_messages() {
+        let (_server, client) =
+            mock_client_for_offchain_submission(ResponseTemplate::new(200)).await;
+
+        let foreign = test_unsigned_tx().to_message_bytes().unwrap();
+        let err = UnsignedTransaction::from_message_bytes(&foreign, &client).unwrap_err();
+        assert!(matches!(err, SDKError::InvalidChainHash(_)), "{err:?}");
+
+        let mut unsigned = test_unsigned_tx();
+        unsigned.chain_name = client.chain_name();
+        let mut message: serde_json::Value =
+            serde_json::from_slice(&unsigned.to_message_bytes().unwrap()).unwrap();
+        message["extra"] = serde_json::json!(true);
+        let bytes = serde_json::to_vec(&message).unwrap();
+        let err = UnsignedTransaction::from_message_bytes(&bytes, &client).unwrap_err();
+        assert!(matches!(err, SDKError::SerializationError(_)), "{err:?}");
+    }
+
     #[test]
     fn to_display_message_renders_unsigned_payload_without_chain_hash() {
         let unsigned = test_unsigned_tx();
@@ -1507,6 +2044,144 @@ mod tests {
         assert!(!Transaction::to_base64(&signed).unwrap().is_empty());
     }
 
+    #[test]
+    fn signed_transactions_decode_and_verify() {
+        let keypair = Keypair::generate();
+        let signed = Transaction::sign(test_unsigned_tx(), &keypair).unwrap();
+
+        let parsed = Transaction::from_base64(&Transaction::to_base64(&signed).unwrap()).unwrap();
+        Transaction::verify(&parsed, [42; 32]).unwrap();
+        let decoded = Transaction::decode(&parsed, [42; 32]).unwrap();
+        assert_eq!(decoded.address(), keypair.address());
+        assert_eq!(
+            decoded.unsigned.uniqueness(),
+            &UniquenessData::Generation(12345)
+        );
+        assert_eq!(decoded.unsigned.details().max_fee, Amount(10_000_000));
+        assert!(matches!(
+            decoded.unsigned.runtime_call(),
+            RuntimeCall::Exchange(CallMessage::Public(PublicAction::ApplyFunding { .. }))
+        ));
+
+        assert!(matches!(
+            Transaction::verify(&parsed, [0; 32]),
+            Err(SDKError::InvalidSignature(_))
+        ));
+        let SignedTransaction::V0(mut tampered) = parsed else {
+            unreachable!()
+        };
+        tampered.details.max_fee = Amount(1);
+        assert!(matches!(
+            Transaction::verify(&SignedTransaction::V0(tampered), [42; 32]),
+            Err(SDKError::InvalidSignature(_))
+        ));
+        assert!(Transaction::from_base64("not base64!").is_err());
+    }
+
+    #[test]
+    fn solana_transactions_decode_and_verify() {
+        let keypair = Keypair::generate();
+        let offchain = SolanaOffchainTransaction::sign(test_unsigned_tx(), &keypair).unwrap();
+        let parsed =
+            SolanaOffchainTransaction::from_base64(&offchain.to_base64().unwrap()).unwrap();
+        assert_eq!(parsed, offchain);
+        let decoded = parsed.verify().unwrap();
+        assert_eq!(decoded.address(), keypair.address());
+        assert_eq!(decoded.unsigned.chain_name(), "TestChain");
+        assert_eq!(decoded.unsigned.chain_hash(), [42; 32]);
+
+        let pubkey = keypair.pubkey();
+        let signable = test_unsigned_tx()
+            .to_ledger_signable_bytes(&pubkey)
+            .unwrap();
+        let signature = keypair.sign_message(&signable).unwrap();
+        let ledger =
+            SolanaLedgerTransaction::from_parts(test_unsigned_tx(), pubkey, signature).unwrap();
+        let mut parsed = SolanaLedgerTransaction::from_base64(&ledger.to_base64()).unwrap();
+        let decoded = parsed.verify().unwrap();
+        assert_eq!(decoded.pubkey, pubkey);
+        assert_eq!(decoded.unsigned.chain_hash(), [42; 32]);
+        assert_eq!(decoded.unsigned.chain_name(), "TestChain");
+        assert_eq!(
+            decoded.unsigned.to_bytes().unwrap(),
+            test_unsigned_tx().to_bytes().unwrap()
+        );
+
+        parsed.signature[0] ^= 1;
+        assert!(matches!(
+            parsed.verify(),
+            Err(SDKError::InvalidSignature(_))
+        ));
+        let bytes = ledger.to_bytes();
+        assert!(SolanaLedgerTransaction::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut oversized = bytes.clone();
+        oversized[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            SolanaLedgerTransaction::from_bytes(&oversized),
+            Err(SDKError::SerializationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn bump_fee_resubmits_with_the_same_uniqueness() {
+        use crate::testing::{
+            decode_submitted, mock_signing_client, mount_submit_ok, submit_count,
+        };
+
+        let (server, client) = mock_signing_client(vec![]).await;
+        mount_submit_ok(&server).await;
+        let signed = Transaction::builder()
+            .call_message(CallMessage::User(UserAction::CancelAllOrders {
+                sub_account_index: None,
+            }))
+            .priority_fee_bips(0)
+            .client(&client)
+            .build()
+            .unwrap();
+
+        client.bump_fee(&signed, 50).await.unwrap();
+        let requests = server.received_requests().await.unwrap();
+        let SignedTransaction::V0(bumped) = decode_submitted(&requests[requests.len() - 1]) else {
+            unreachable!()
+        };
+        let SignedTransaction::V0(original) = &signed else {
+            unreachable!()
+        };
+        assert_eq!(bumped.uniqueness, original.uniqueness);
+        assert_eq!(bumped.runtime_call, original.runtime_call);
+        assert_eq!(bumped.details.max_fee, original.details.max_fee);
+        assert_eq!(bumped.details.max_priority_fee_bips, PriorityFeeBips(50));
+        Transaction::verify(&SignedTransaction::V0(bumped), client.chain_hash()).unwrap();
+
+        let err = client.bump_fee(&signed, 0).await.unwrap_err();
+        assert!(matches!(err, SDKError::RequestError(_)), "{err:?}");
+        let decoded = Transaction::decode(&signed, client.chain_hash()).unwrap();
+        let foreign = Transaction::sign(decoded.unsigned, &Keypair::generate()).unwrap();
+        let err = client.bump_fee(&foreign, 50).await.unwrap_err();
+        assert!(matches!(err, SDKError::SigningFailed(_)), "{err:?}");
+        assert_eq!(submit_count(&server).await, 1);
+    }
+
+    #[tokio::test]
+    async fn bump_fee_refuses_generation_uniqueness() {
+        use crate::testing::{mock_signing_client, mount_submit_ok, submit_count};
+
+        let (server, client) = mock_signing_client(vec![]).await;
+        mount_submit_ok(&server).await;
+        let signed = Transaction::builder()
+            .call_message(CallMessage::User(UserAction::CancelAllOrders {
+                sub_account_index: None,
+            }))
+            .uniqueness(UniquenessData::Generation(7))
+            .client(&client)
+            .build()
+            .unwrap();
+
+        let err = client.bump_fee(&signed, 50).await.unwrap_err();
+        assert!(matches!(err, SDKError::RequestError(_)), "{err:?}");
+        assert_eq!(submit_count(&server).await, 0);
+    }
+
     #[cfg(feature = "integration")]
     mod integration {
         use bullet_exchange_interface::message::PublicAction;
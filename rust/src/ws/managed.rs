@@ -34,6 +34,8 @@
 //! ```
 
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use bon::bon;
@@ -49,7 +51,9 @@ use super::client::{WebsocketConfig, WebsocketHandle};
 use super::models::ServerMessage;
 use super::topics::Topic;
 use crate::Client;
+use crate::endpoints::Endpoints;
 use crate::errors::WSErrors;
+use crate::runtime::spawn;
 use crate::types::{ClientMessage, OrderParams, RequestId};
 
 /// Errors from [`ManagedWebsocket`] operations.
@@ -242,18 +246,13 @@ impl ManagedWebsocket {
         client: &Client,
         config: ManagedWsConfig,
     ) -> Result<ManagedWebsocket, WSErrors> {
-        let ws = client
-            .connect_ws()
-            .maybe_config(config.ws_config.clone())
-            .call()
-            .await?;
+        let inner = ManagedWsClient::from_client(client);
+        let ws = inner.connect_any(&config.ws_config).await?;
 
         let (event_tx, event_rx) = mpsc::channel(config.channel_capacity);
         let (cmd_tx, cmd_rx) = mpsc::channel(CMD_CHANNEL_CAPACITY);
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
-        let inner = ManagedWsClient::from_client(client);
-
         spawn(async move {
             run_managed_ws(inner, ws, config, event_tx, cmd_rx, shutdown_rx).await;
         });
@@ -379,10 +378,7 @@ impl ManagedWebsocket {
         signed: &crate::SignedTransaction,
         id: Option<RequestId>,
     ) -> Result<(), WSErrors> {
-        let base64 =
-            crate::Transaction::to_base64(signed).map_err(|e| WSErrors::WsError(e.to_string()))?;
-        self.order_place(base64, id)
-            .map_err(|e| WSErrors::WsError(e.to_string()))
+        self.sender().place_order(signed, id)
     }
 
     /// Cancel an order using a signed [`Transaction`]. Base64-encodes internally.
@@ -393,10 +389,7 @@ impl ManagedWebsocket {
         signed: &crate::SignedTransaction,
         id: Option<RequestId>,
     ) -> Result<(), WSErrors> {
-        let base64 =
-            crate::Transaction::to_base64(signed).map_err(|e| WSErrors::WsError(e.to_string()))?;
-        self.order_cancel(base64, id)
-            .map_err(|e| WSErrors::WsError(e.to_string()))
+        self.sender().cancel_order(signed, id)
     }
 
     /// Amend an order using a signed [`Transaction`]. Base64-encodes internally.
@@ -407,10 +400,7 @@ impl ManagedWebsocket {
         signed: &crate::SignedTransaction,
         id: Option<RequestId>,
     ) -> Result<(), WSErrors> {
-        let base64 =
-            crate::Transaction::to_base64(signed).map_err(|e| WSErrors::WsError(e.to_string()))?;
-        self.order_amend(base64, id)
-            .map_err(|e| WSErrors::WsError(e.to_string()))
+        self.sender().amend_order(signed, id)
     }
 
     /// Cancel all open orders using a signed [`Transaction`]. Base64-encodes internally.
@@ -421,10 +411,7 @@ impl ManagedWebsocket {
         signed: &crate::SignedTransaction,
         id: Option<RequestId>,
     ) -> Result<(), WSErrors> {
-        let base64 =
-            crate::Transaction::to_base64(signed).map_err(|e| WSErrors::WsError(e.to_string()))?;
-        self.order_cancel_all(base64, id)
-            .map_err(|e| WSErrors::WsError(e.to_string()))
+        self.sender().cancel_all_orders(signed, id)
     }
 
     /// Stop the managed WebSocket and its background task.
@@ -437,18 +424,122 @@ impl ManagedWebsocket {
         // the signal and exits without going through the cmd queue.
     }
 
+    /// A cloneable handle for sending orders over this connection from
+    /// another task while this handle keeps receiving events.
+    pub fn sender(&self) -> ManagedWsSender {
+        ManagedWsSender {
+            cmd_tx: self.cmd_tx.clone(),
+        }
+    }
+
     fn try_send_cmd(&self, cmd: WsCommand) -> Result<(), ManagedWsError> {
-        // `Sender::clone` is an Arc bump, so try_send (which needs &mut self)
-        // can be called without requiring `&mut self` on the public API.
-        let mut tx = self.cmd_tx.clone();
-        tx.try_send(cmd).map_err(|e| {
-            if e.is_full() {
-                ManagedWsError::Busy
-            } else {
-                ManagedWsError::Stopped
-            }
+        try_send_cmd(&self.cmd_tx, cmd)
+    }
+}
+
+/// Order-sending half of a [`ManagedWebsocket`], obtained via
+/// [`ManagedWebsocket::sender`].
+///
+/// Cheap to clone and `Send + Sync`. A sender does not keep the connection
+/// alive: once the [`ManagedWebsocket`] is dropped or stopped, every send
+/// fails with [`ManagedWsError::Stopped`].
+#[derive(Clone)]
+pub struct ManagedWsSender {
+    cmd_tx: mpsc::Sender<WsCommand>,
+}
+
+impl ManagedWsSender {
+    /// Place an order using a signed [`Transaction`]. Base64-encodes internally.
+    ///
+    /// [`Transaction`]: crate::SignedTransaction
+    pub fn place_order(
+        &self,
+        signed: &crate::SignedTransaction,
+        id: Option<RequestId>,
+    ) -> Result<(), WSErrors> {
+        self.send_signed(signed, |params| ClientMessage::OrderPlace { id, params })
+    }
+
+    /// Cancel an order using a signed [`Transaction`]. Base64-encodes internally.
+    ///
+    /// [`Transaction`]: crate::SignedTransaction
+    pub fn cancel_order(
+        &self,
+        signed: &crate::SignedTransaction,
+        id: Option<RequestId>,
+    ) -> Result<(), WSErrors> {
+        self.send_signed(signed, |params| ClientMessage::OrderCancel { id, params })
+    }
+
+    /// Amend an order using a signed [`Transaction`]. Base64-encodes internally.
+    ///
+    /// [`Transaction`]: crate::SignedTransaction
+    pub fn amend_order(
+        &self,
+        signed: &crate::SignedTransaction,
+        id: Option<RequestId>,
+    ) -> Result<(), WSErrors> {
+        self.send_signed(signed, |params| ClientMessage::OrderAmend { id, params })
+    }
+
+    /// Cancel all open orders using a signed [`Transaction`]. Base64-encodes internally.
+    ///
+    /// [`Transaction`]: crate::SignedTransaction
+    pub fn cancel_all_orders(
+        &self,
+        signed: &crate::SignedTransaction,
+        id: Option<RequestId>,
+    ) -> Result<(), WSErrors> {
+        self.send_signed(signed, |params| ClientMessage::OrderCancelAll {
+            id,
+            params,
         })
     }
+
+    fn send_signed(
+        &self,
+        signed: &crate::SignedTransaction,
+        message: impl FnOnce(OrderParams) -> ClientMessage,
+    ) -> Result<(), WSErrors> {
+        let tx =
+            crate::Transaction::to_base64(signed).map_err(|e| WSErrors::WsError(e.to_string()))?;
+        try_send_cmd(&self.cmd_tx, WsCommand::Send(message(OrderParams { tx })))
+            .map_err(|e| WSErrors::WsError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+impl ManagedWsSender {
+    /// A sender with no connection behind it, and the messages sent on it.
+    pub(crate) fn detached() -> (Self, impl futures::Stream<Item = ClientMessage>) {
+        let (cmd_tx, cmd_rx) = mpsc::channel(8);
+        let messages = cmd_rx.filter_map(|cmd| {
+            future::ready(match cmd {
+                WsCommand::Send(message) => Some(message),
+                _ => None,
+            })
+        });
+        (ManagedWsSender { cmd_tx }, messages)
+    }
+}
+
+impl std::fmt::Debug for ManagedWsSender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManagedWsSender").finish_non_exhaustive()
+    }
+}
+
+fn try_send_cmd(cmd_tx: &mpsc::Sender<WsCommand>, cmd: WsCommand) -> Result<(), ManagedWsError> {
+    // `Sender::clone` is an Arc bump, so try_send (which needs &mut self)
+    // can be called without requiring `&mut self` on the public API.
+    let mut tx = cmd_tx.clone();
+    tx.try_send(cmd).map_err(|e| {
+        if e.is_full() {
+            ManagedWsError::Busy
+        } else {
+            ManagedWsError::Stopped
+        }
+    })
 }
 
 // `mpsc::Sender`/`Receiver` are `Send`, and `oneshot::Sender<()>` is `Send + Sync`.
@@ -479,14 +570,33 @@ impl Client {
 /// compile-time dependency on this struct.
 struct ManagedWsClient {
     ws_client: reqwest::Client,
-    ws_url: String,
+    /// The client's endpoints, shared so each connect attempt sees the
+    /// currently verified ones, starting with the active one.
+    endpoints: Arc<Endpoints>,
+    /// Offset into the verified endpoints. A failed connect attempt moves it
+    /// on to the next one.
+    current: AtomicUsize,
 }
 
 impl ManagedWsClient {
     fn from_client(client: &Client) -> Self {
         Self {
             ws_client: client.ws_client.clone(),
-            ws_url: client.ws_url().to_string(),
+            endpoints: client.endpoints.clone(),
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    fn ws_url(&self) -> String {
+        let ws_urls = self.endpoints.ws_urls();
+        ws_urls[self.current.load(Ordering::Relaxed) % ws_urls.len()].clone()
+    }
+
+    /// Fail over to the next endpoint for the following connect attempt.
+    fn rotate(&self) {
+        if self.endpoints.ws_urls().len() > 1 {
+            self.current.fetch_add(1, Ordering::Relaxed);
+            info!(url = self.ws_url(), "failing over websocket endpoint");
         }
     }
 
@@ -498,29 +608,28 @@ impl ManagedWsClient {
             .as_ref()
             .map(|c| c.connection_timeout)
             .unwrap_or(web_time::Duration::from_secs(10));
-        WebsocketHandle::connect(&self.ws_client, &self.ws_url, timeout).await
+        WebsocketHandle::connect(&self.ws_client, &self.ws_url(), timeout).await
     }
-}
-
-/// Spawn a background future on the target's executor.
-///
-/// Native uses [`tokio::spawn`] (requires `Send`); wasm uses
-/// [`wasm_bindgen_futures::spawn_local`] (no `Send` required since JS is
-/// single-threaded).
-#[cfg(not(target_arch = "wasm32"))]
-fn spawn<F>(fut: F)
-where
-    F: std::future::Future<Output = ()> + Send + 'static,
-{
-    tokio::spawn(fut);
-}
 
-#[cfg(target_arch = "wasm32")]
-fn spawn<F>(fut: F)
-where
-    F: std::future::Future<Output = ()> + 'static,
-{
-    wasm_bindgen_futures::spawn_local(fut);
+    /// Try each endpoint once, in order, returning the last error if none
+    /// accepts the connection.
+    async fn connect_any(
+        &self,
+        ws_config: &Option<WebsocketConfig>,
+    ) -> Result<WebsocketHandle, WSErrors> {
+        let mut last_err = None;
+        for _ in 0..self.endpoints.ws_urls().len() {
+            match self.connect(ws_config).await {
+                Ok(ws) => return Ok(ws),
+                Err(e) => {
+                    warn!(?e, url = self.ws_url(), "websocket connect failed");
+                    last_err = Some(e);
+                    self.rotate();
+                }
+            }
+        }
+        Err(last_err.expect("there is always a verified endpoint"))
+    }
 }
 
 /// Persistent state carried across reconnect cycles.
@@ -847,6 +956,7 @@ async fn reconnect(
             }
             Err(e) => {
                 warn!(?e, attempt = attempts, "reconnect failed");
+                client.rotate();
                 state.backoff = (state.backoff * 2).min(max_backoff);
             }
         }
@@ -858,3 +968,25 @@ async fn reconnect(
 fn shutdown_observed(rx: &mut oneshot::Receiver<()>) -> bool {
     !matches!(rx.try_recv(), Ok(None))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::two_endpoint_client;
+
+    #[tokio::test]
+    async fn reconnects_follow_rest_failover() {
+        let (primary, _backup, client) = two_endpoint_client().await;
+        let ws_client = ManagedWsClient::from_client(&client);
+        assert_eq!(ws_client.ws_url(), client.ws_url());
+
+        drop(primary);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client
+            .with_failover(|api| async move { api.exchange_info().await })
+            .await
+            .unwrap();
+
+        assert_eq!(ws_client.ws_url(), client.ws_url());
+    }
+}
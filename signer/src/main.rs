@@ -0,0 +1,145 @@
+//! `bullet-signer`: holds a Bullet signing key and signs transactions for
+//! trading hosts over HTTP, after checking them against a policy.
+//!
+//! ```text
+//! bullet-signer --keystore trader.json --network mainnet \
+//!     --policy policy.json --listen 127.0.0.1:7419
+//! ```
+//!
+//! The keystore password is read from `BULLET_SIGNER_PASSWORD` and the
+//! optional bearer token from `BULLET_SIGNER_TOKEN`. Clients connect with
+//! `bullet_rust_sdk::RemoteSigner`.
+
+mod server;
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use bullet_rust_sdk::{ChainSnapshot, Client, Keypair, SigningPolicy};
+use clap::Parser;
+
+use crate::server::SignerState;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Sign Bullet transactions without exposing the key")]
+struct Args {
+    /// Solana-style JSON keypair file (unencrypted).
+    #[arg(
+        long,
+        conflicts_with = "keystore",
+        required_unless_present = "keystore"
+    )]
+    keypair: Option<PathBuf>,
+
+    /// Password-encrypted keystore file.
+    #[arg(long)]
+    keystore: Option<PathBuf>,
+
+    /// Password for `--keystore`.
+    #[arg(long, env = "BULLET_SIGNER_PASSWORD", hide_env_values = true)]
+    password: Option<String>,
+
+    /// Network to fetch the chain identity from (`mainnet` or a REST URL).
+    #[arg(
+        long,
+        conflicts_with = "snapshot",
+        required_unless_present = "snapshot"
+    )]
+    network: Option<String>,
+
+    /// Chain snapshot file, for running without network access.
+    #[arg(long)]
+    snapshot: Option<PathBuf>,
+
+    /// `SigningPolicy` JSON file. Without one, only order placement, amends
+    /// and cancels are signed.
+    #[arg(long)]
+    policy: Option<PathBuf>,
+
+    /// Address to listen on. Anything but loopback requires `--token`.
+    #[arg(long, default_value = "127.0.0.1:7419")]
+    listen: SocketAddr,
+
+    /// Require `Authorization: Bearer <token>` on every request.
+    #[arg(long, env = "BULLET_SIGNER_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+}
+
+/// Calls signed when no `--policy` is given: placing, amending and
+/// cancelling orders, but nothing that moves funds.
+fn trading_only_policy() -> SigningPolicy {
+    let calls = [
+        "PlaceOrders",
+        "AmendOrders",
+        "CancelAndPlaceOrders",
+        "CancelOrders",
+        "CancelMarketOrders",
+        "CancelAllOrders",
+        "CancelTriggerOrders",
+        "CancelTwapOrder",
+    ];
+    SigningPolicy::builder()
+        .allowed_calls(calls.map(|call| format!("User/{call}")).to_vec())
+        .build()
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()),
+        )
+        .init();
+    let args = Args::parse();
+    if !args.listen.ip().is_loopback() && args.token.is_none() {
+        return Err(format!(
+            "refusing to listen on non-loopback {} without BULLET_SIGNER_TOKEN",
+            args.listen
+        )
+        .into());
+    }
+
+    let keypair = match (&args.keypair, &args.keystore) {
+        (Some(path), _) => Keypair::read_from_file(path)?,
+        (None, Some(path)) => {
+            let password = args
+                .password
+                .as_deref()
+                .ok_or("--keystore needs BULLET_SIGNER_PASSWORD")?;
+            Keypair::read_encrypted(path, password)?
+        }
+        (None, None) => unreachable!("clap requires a key"),
+    };
+    let client = match (&args.network, &args.snapshot) {
+        (Some(network), _) => Client::builder().network(network.as_str()).build().await?,
+        (None, Some(path)) => Client::offline()
+            .snapshot(ChainSnapshot::read_from_file(path)?)
+            .build()?,
+        (None, None) => unreachable!("clap requires a chain"),
+    };
+    let policy = match &args.policy {
+        Some(path) => serde_json::from_str(&std::fs::read_to_string(path)?)?,
+        None => trading_only_policy(),
+    };
+
+    let listener = tokio::net::TcpListener::bind(args.listen).await?;
+    tracing::info!(
+        address = %keypair.address(),
+        chain = %client.chain_name(),
+        listen = %args.listen,
+        ?policy,
+        "signer ready"
+    );
+    let app = server::router(SignerState {
+        keypair,
+        client,
+        policy,
+        token: args.token,
+    });
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
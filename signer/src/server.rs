@@ -0,0 +1,226 @@
+//! HTTP endpoints, as described in `bullet_rust_sdk::remote_signer`.
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bullet_rust_sdk::remote_signer::{ErrorResponse, PubkeyResponse, SignRequest, SignResponse};
+use bullet_rust_sdk::{Client, Keypair, Signer, SigningPolicy, UnsignedTransaction};
+
+/// Everything a request handler needs.
+pub(crate) struct SignerState {
+    pub keypair: Keypair,
+    /// Supplies the chain hash and name transactions are decoded against.
+    pub client: Client,
+    pub policy: SigningPolicy,
+    pub token: Option<String>,
+}
+
+pub(crate) fn router(state: SignerState) -> Router {
+    Router::new()
+        .route("/v1/pubkey", get(pubkey))
+        .route("/v1/sign", post(sign))
+        .with_state(Arc::new(state))
+}
+
+async fn pubkey(State(state): State<Arc<SignerState>>, headers: HeaderMap) -> Response {
+    if let Some(rejection) = unauthorized(&state, &headers) {
+        return rejection;
+    }
+    Json(PubkeyResponse {
+        address: state.keypair.address(),
+    })
+    .into_response()
+}
+
+async fn sign(
+    State(state): State<Arc<SignerState>>,
+    headers: HeaderMap,
+    Json(request): Json<SignRequest>,
+) -> Response {
+    if let Some(rejection) = unauthorized(&state, &headers) {
+        return rejection;
+    }
+    let Ok(message) = BASE64.decode(&request.message) else {
+        return error(StatusCode::BAD_REQUEST, "message is not valid base64");
+    };
+    // The message is what gets signed, so it must decode to a transaction
+    // for this chain: either the Borsh signable bytes or the offchain JSON.
+    let Ok(unsigned) = UnsignedTransaction::from_bytes(&message, &state.client)
+        .or_else(|_| UnsignedTransaction::from_message_bytes(&message, &state.client))
+    else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "message is not an unsigned transaction for this chain",
+        );
+    };
+
+    let summary = state.client.summarize(&unsigned);
+    if let Err(violation) = state.policy.check(&unsigned) {
+        tracing::warn!(%summary, %violation, "refused to sign");
+        return error(StatusCode::FORBIDDEN, &violation.to_string());
+    }
+    match state.keypair.sign_message(&message) {
+        Ok(signature) => {
+            tracing::info!(%summary, "signed");
+            Json(SignResponse {
+                signature: BASE64.encode(signature),
+            })
+            .into_response()
+        }
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    }
+}
+
+/// The rejection to send if the request lacks the configured token.
+fn unauthorized(state: &SignerState, headers: &HeaderMap) -> Option<Response> {
+    let token = state.token.as_ref()?;
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    (!constant_time_eq(presented.as_bytes(), token.as_bytes()))
+        .then(|| error(StatusCode::UNAUTHORIZED, "missing or invalid token"))
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(ErrorResponse {
+            error: message.to_string(),
+        }),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use bullet_rust_sdk::types::bullet_exchange_interface::schema::Schema;
+    use bullet_rust_sdk::types::{CallMessage, PublicAction, UserAction};
+    use bullet_rust_sdk::{
+        ChainSnapshot, RemoteSigner, SDKError, SignedTransaction, SolanaOffchainTransaction,
+        Transaction,
+    };
+
+    use super::*;
+
+    fn offline_client() -> Client {
+        let schema = Schema::of_single_type::<SignedTransaction>().unwrap();
+        let snapshot = ChainSnapshot {
+            url: "http://localhost".to_string(),
+            chain_id: schema.chain_data().chain_id,
+            chain_hash: [7; 32],
+            chain_name: schema.chain_data().chain_name.clone(),
+            symbols: vec![],
+            schema: serde_json::json!({
+                "chain_hash": format!("0x{}", "07".repeat(32)),
+                "schema": schema,
+            }),
+        };
+        Client::offline().snapshot(snapshot).build().unwrap()
+    }
+
+    /// Serve a daemon for `keypair` on an ephemeral port and connect to it.
+    async fn spawn_daemon(keypair: Keypair, policy: SigningPolicy) -> (String, RemoteSigner) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let app = router(SignerState {
+            keypair,
+            client: offline_client(),
+            policy,
+            token: Some("secret".to_string()),
+        });
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        let signer = RemoteSigner::builder()
+            .url(url.clone())
+            .token("secret")
+            .build()
+            .await
+            .unwrap();
+        (url, signer)
+    }
+
+    fn unsigned(call_message: CallMessage, client: &Client) -> UnsignedTransaction {
+        UnsignedTransaction::builder()
+            .call_message(call_message)
+            .max_fee(10_000_000)
+            .priority_fee_bips(0)
+            .client(client)
+            .build()
+            .unwrap()
+    }
+
+    fn cancel_all() -> CallMessage {
+        CallMessage::User(UserAction::CancelAllOrders {
+            sub_account_index: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn signs_allowed_transactions_in_both_formats() {
+        let keypair = Keypair::generate();
+        let (_url, signer) = spawn_daemon(keypair.clone(), SigningPolicy::default()).await;
+        let client = offline_client();
+
+        let signed = Transaction::sign_async(unsigned(cancel_all(), &client), &signer)
+            .await
+            .unwrap();
+        let offchain =
+            SolanaOffchainTransaction::sign_async(unsigned(cancel_all(), &client), &signer)
+                .await
+                .unwrap();
+
+        let SignedTransaction::V0(v0) = signed else {
+            unreachable!()
+        };
+        assert_eq!(v0.pub_key, keypair.pubkey());
+        assert_eq!(offchain.pubkey, keypair.pubkey());
+    }
+
+    #[tokio::test]
+    async fn refuses_transactions_outside_the_policy() {
+        let policy = SigningPolicy::builder()
+            .allowed_calls(vec!["User/PlaceOrders".to_string()])
+            .build();
+        let (url, signer) = spawn_daemon(Keypair::generate(), policy).await;
+        let client = offline_client();
+
+        let err = Transaction::sign_async(unsigned(cancel_all(), &client), &signer)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("User/CancelAllOrders is not allowed"),
+            "{err}"
+        );
+        let funding = CallMessage::Public(PublicAction::ApplyFunding { addresses: vec![] });
+        let err = Transaction::sign_async(unsigned(funding, &client), &signer)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SDKError::SigningFailed(_)), "{err:?}");
+        assert!(err.to_string().contains("403"), "{err}");
+
+        let err = signer
+            .sign_message_async(b"not a transaction")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("400"), "{err}");
+        let err = RemoteSigner::builder()
+            .url(url)
+            .token("wrong")
+            .build()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("401"), "{err}");
+    }
+}
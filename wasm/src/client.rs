@@ -129,6 +129,15 @@ impl WasmTradingApi {
         self.inner.ws_url().to_string()
     }
 
+    /// All REST API base URLs, in failover order.
+    pub fn endpoints(&self) -> Vec<String> {
+        self.inner
+            .endpoints()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
     /// Get the default max fee for transactions.
     #[wasm_bindgen(js_name = maxFee)]
     pub fn max_fee(&self) -> u64 {
@@ -169,8 +178,7 @@ impl WasmTradingApi {
     pub fn symbols(&self) -> Vec<crate::metadata::WasmSymbolInfo> {
         self.inner
             .symbols()
-            .iter()
-            .cloned()
+            .into_iter()
             .map(crate::metadata::WasmSymbolInfo)
             .collect()
     }
@@ -182,7 +190,6 @@ impl WasmTradingApi {
     pub fn symbol_info(&self, symbol: &str) -> Option<crate::metadata::WasmSymbolInfo> {
         self.inner
             .symbol_info_by_name(symbol)
-            .cloned()
             .map(crate::metadata::WasmSymbolInfo)
     }
 
@@ -193,7 +200,6 @@ impl WasmTradingApi {
     pub fn symbol_info_by_id(&self, market_id: u16) -> Option<crate::metadata::WasmSymbolInfo> {
         self.inner
             .symbol_info(bullet_exchange_interface::types::MarketId(market_id))
-            .cloned()
             .map(crate::metadata::WasmSymbolInfo)
     }
 
@@ -207,8 +213,43 @@ impl WasmTradingApi {
     /// Call this in long-running bots to pick up newly listed markets.
     /// @returns {Promise<void>}
     #[wasm_bindgen(js_name = refreshMetadata)]
-    pub async fn refresh_metadata(&mut self) -> WasmResult<()> {
-        Ok(self.inner.refresh_metadata().await?)
+    pub async fn refresh_metadata(&self) -> WasmResult<()> {
+        self.inner.refresh_metadata().await?;
+        Ok(())
+    }
+
+    // ── Health ───────────────────────────────────────────────────────────
+
+    /// Query `/health/ready`. A `503` is returned as a status with
+    /// `ready == false` rather than thrown.
+    /// @returns {Promise<ReadinessStatus>}
+    pub async fn readiness(&self) -> WasmResult<crate::generated::WasmReadinessStatus> {
+        let status = self.inner.readiness().await?;
+        Ok(crate::generated::WasmReadinessStatus(status))
+    }
+
+    /// Wait until the API reports ready, polling `/health/ready`.
+    /// Throws if the API is not ready within `timeoutMs`.
+    /// @param {number} timeoutMs - Maximum time to wait, in milliseconds.
+    /// @returns {Promise<ReadinessStatus>}
+    #[wasm_bindgen(js_name = waitUntilReady)]
+    pub async fn wait_until_ready(
+        &self,
+        timeout_ms: u32,
+    ) -> WasmResult<crate::generated::WasmReadinessStatus> {
+        let timeout = std::time::Duration::from_millis(timeout_ms.into());
+        let status = self.inner.wait_until_ready(timeout).await?;
+        Ok(crate::generated::WasmReadinessStatus(status))
+    }
+
+    // ── Offline Signing ──────────────────────────────────────────────────
+
+    /// Export the chain identity, symbols, and schema as a JSON string for
+    /// building an offline client with `ClientBuilder.buildOffline`.
+    /// @returns {Promise<string>}
+    #[wasm_bindgen(js_name = chainSnapshot)]
+    pub async fn chain_snapshot(&self) -> WasmResult<String> {
+        Ok(self.inner.chain_snapshot().await?.to_json()?)
     }
 
     // ── Trading Convenience Methods ──────────────────────────────────────
@@ -295,22 +336,26 @@ impl WasmTradingApi {
 #[wasm_bindgen(js_name = ClientBuilder)]
 pub struct WasmClientBuilder {
     network: Option<String>,
+    fallbacks: Vec<String>,
     keypair: Option<WasmKeypair>,
     max_fee: Option<u64>,
     max_priority_fee_bips: Option<u64>,
     gas_limit: Option<[u64; 2]>,
     user_actions: Option<Vec<UserActionDiscriminants>>,
+    market_status_guard: Option<bool>,
 }
 
 impl WasmClientBuilder {
     fn new() -> Self {
         WasmClientBuilder {
             network: None,
+            fallbacks: Vec::new(),
             keypair: None,
             max_fee: None,
             max_priority_fee_bips: None,
             gas_limit: None,
             user_actions: None,
+            market_status_guard: None,
         }
     }
 }
@@ -326,6 +371,15 @@ impl WasmClientBuilder {
         self
     }
 
+    /// Add fallback URLs, tried in order when the network's endpoint is unreachable.
+    ///
+    /// Every endpoint must serve the same chain; the client prefers whichever
+    /// responds fastest at connect time.
+    pub fn fallbacks(mut self, urls: Vec<String>) -> WasmClientBuilder {
+        self.fallbacks.extend(urls);
+        self
+    }
+
     /// Set the default keypair for signing transactions.
     pub fn keypair(mut self, keypair: WasmKeypair) -> WasmClientBuilder {
         self.keypair = Some(keypair);
@@ -375,9 +429,40 @@ impl WasmClientBuilder {
         Ok(self)
     }
 
+    /// Check the cached market status before placing or amending orders
+    /// (default `true`). Orders on a non-`TRADING` market fail with a
+    /// validation error instead of being submitted.
+    #[wasm_bindgen(js_name = marketStatusGuard)]
+    pub fn market_status_guard(mut self, enabled: bool) -> WasmClientBuilder {
+        self.market_status_guard = Some(enabled);
+        self
+    }
+
+    /// Build a client from a `chainSnapshot()` JSON string without any network
+    /// access. The network and fallback settings are ignored.
+    #[wasm_bindgen(js_name = buildOffline)]
+    pub fn build_offline(self, snapshot_json: &str) -> WasmResult<WasmTradingApi> {
+        let snapshot = bullet_rust_sdk::ChainSnapshot::from_json(snapshot_json)?;
+
+        let inner = Client::offline()
+            .snapshot(snapshot)
+            .maybe_keypair(self.keypair.map(|k| k.inner))
+            .maybe_max_fee(self.max_fee.map(|f| Amount(f as u128)))
+            .maybe_max_priority_fee_bips(self.max_priority_fee_bips.map(PriorityFeeBips))
+            .maybe_gas_limit(self.gas_limit.map(Gas))
+            .maybe_user_actions(self.user_actions)
+            .maybe_market_status_guard(self.market_status_guard)
+            .build()?;
+
+        Ok(WasmTradingApi { inner })
+    }
+
     /// Build the client and connect to the API.
     pub async fn build(self) -> WasmResult<WasmTradingApi> {
-        let network: Network = self.network.ok_or("network is required")?.as_str().into();
+        let mut network: Network = self.network.ok_or("network is required")?.as_str().into();
+        if !self.fallbacks.is_empty() {
+            network = network.with_fallbacks(self.fallbacks);
+        }
 
         let keypair = self.keypair.map(|k| k.inner);
         let max_fee = self.max_fee.map(|f| Amount(f as u128));
@@ -391,6 +476,7 @@ impl WasmClientBuilder {
             .maybe_max_priority_fee_bips(max_priority_fee_bips)
             .maybe_gas_limit(gas_limit)
             .maybe_user_actions(self.user_actions)
+            .maybe_market_status_guard(self.market_status_guard)
             .build()
             .await?;
 
@@ -102,17 +102,33 @@ impl From<SDKError> for WasmError {
     fn from(e: SDKError) -> Self {
         let retryable = e.is_retryable();
         let kind = match &e {
-            SDKError::InvalidNetwork | SDKError::InvalidNetworkUrl => WasmErrorKind::Network,
+            SDKError::InvalidNetwork
+            | SDKError::InvalidNetworkUrl
+            | SDKError::NotReady(_)
+            | SDKError::EndpointMismatch { .. } => WasmErrorKind::Network,
             SDKError::InvalidPrivateKey(_)
             | SDKError::MissingKeypair
             | SDKError::InvalidSchemaResponse(_)
             | SDKError::InvalidChainHash(_)
             | SDKError::InvalidSignatureLength(_)
             | SDKError::InvalidPublicKeyLength(_)
+            | SDKError::InvalidSignature(_)
+            | SDKError::InvalidEnvelope(_)
+            | SDKError::AsyncSignerRequired
+            | SDKError::SigningFailed(_)
+            | SDKError::KeystoreError(_)
+            | SDKError::IncorrectPassword
+            | SDKError::InvalidMnemonic(_)
+            | SDKError::PolicyViolation(_)
+            | SDKError::InvalidDerivationPath(_)
             | SDKError::UnsupportedCallMessage(_)
             | SDKError::TransactionOutdated
             | SDKError::InvalidMultisig(_)
             | SDKError::InvalidSubAccountIndex(_)
+            | SDKError::MarketNotTrading { .. }
+            | SDKError::NoLiquidity(_)
+            | SDKError::UnknownMarket(_)
+            | SDKError::SizeBelowMinimum { .. }
             | SDKError::RequestError(_) => WasmErrorKind::Validation,
             SDKError::JsonSerializeError(_)
             | SDKError::StringParseError(_)
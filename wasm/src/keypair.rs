@@ -35,6 +35,37 @@ impl WasmKeypair {
         })
     }
 
+    /// Parse a Solana CLI keypair (`id.json`): a JSON array of 64 numbers
+    /// (secret key + public key, which must match) or a bare 32-byte secret.
+    #[wasm_bindgen(js_name = fromSolanaJson)]
+    pub fn from_solana_json(json: &str) -> WasmResult<WasmKeypair> {
+        Ok(WasmKeypair {
+            inner: Keypair::from_solana_json(json)?,
+        })
+    }
+
+    /// Serialize as a Solana CLI keypair (`id.json`).
+    /// @returns {string}
+    #[wasm_bindgen(js_name = toSolanaJson)]
+    pub fn to_solana_json(&self) -> String {
+        self.inner.to_solana_json()
+    }
+
+    /// Parse a base58 secret key as exported by Phantom or Solflare.
+    #[wasm_bindgen(js_name = fromBase58)]
+    pub fn from_base58(encoded: &str) -> WasmResult<WasmKeypair> {
+        Ok(WasmKeypair {
+            inner: Keypair::from_base58(encoded)?,
+        })
+    }
+
+    /// The 64-byte secret + public key, base58-encoded.
+    /// @returns {string}
+    #[wasm_bindgen(js_name = toBase58)]
+    pub fn to_base58(&self) -> String {
+        self.inner.to_base58()
+    }
+
     /// 32-byte public key as `Uint8Array`.
     #[wasm_bindgen(js_name = publicKey)]
     pub fn public_key(&self) -> Vec<u8> {
@@ -4,8 +4,12 @@
 //! access to symbol lookups instead of parsing JSON strings.
 
 use bullet_rust_sdk::SymbolInfo;
+use rust_decimal::Decimal;
 use wasm_bindgen::prelude::*;
 
+use crate::errors::WasmResult;
+use crate::utils::decimal::WasmDecimal;
+
 /// Symbol information cached from the exchange.
 #[wasm_bindgen(js_name = SymbolInfo)]
 pub struct WasmSymbolInfo(pub(crate) SymbolInfo);
@@ -53,4 +57,34 @@ impl WasmSymbolInfo {
     pub fn quantity_precision(&self) -> u8 {
         self.0.quantity_precision
     }
+
+    /// Price increment as a decimal string.
+    #[wasm_bindgen(getter, js_name = tickSize)]
+    pub fn tick_size(&self) -> String {
+        self.0.tick_size.to_string()
+    }
+
+    /// Quantity increment as a decimal string.
+    #[wasm_bindgen(getter, js_name = stepSize)]
+    pub fn step_size(&self) -> String {
+        self.0.step_size.to_string()
+    }
+
+    /// Minimum order quantity as a decimal string.
+    #[wasm_bindgen(getter, js_name = minQty)]
+    pub fn min_qty(&self) -> String {
+        self.0.min_qty.to_string()
+    }
+
+    /// Base quantity worth `notional` of the quote asset at `price`, rounded
+    /// down to the lot size. Throws if that is below the minimum quantity.
+    #[wasm_bindgen(js_name = sizeForNotional)]
+    pub fn size_for_notional(
+        &self,
+        notional: &WasmDecimal,
+        price: &WasmDecimal,
+    ) -> WasmResult<WasmDecimal> {
+        let size = self.0.size_for_notional(notional.0, price.0)?;
+        Ok(WasmDecimal(Decimal::from(size)))
+    }
 }
@@ -48,7 +48,7 @@ use bullet_exchange_interface::types::{
 };
 use bullet_rust_sdk::types::CallMessage;
 use bullet_rust_sdk::{
-    SolanaLedgerTransaction as RustSolanaLedgerTransaction,
+    Signer, SolanaLedgerTransaction as RustSolanaLedgerTransaction,
     SolanaOffchainTransaction as RustSolanaOffchainTransaction, Transaction as RustTransaction,
     UniquenessData, UnsignedTransaction,
 };
@@ -483,6 +483,15 @@ impl WasmUnsignedTransaction {
         Ok(self.inner.to_display_message()?)
     }
 
+    /// Describe the transaction in one line for approval prompts, e.g.
+    /// `"Place 2 orders on BTC-USD: BUY 0.01 @ 50,000 post-only"`.
+    ///
+    /// @param {Client} client - Supplies the market symbols and precisions.
+    /// @returns {string}
+    pub fn summary(&self, client: &WasmTradingApi) -> String {
+        client.inner.summarize(&self.inner)
+    }
+
     /// Serialize into readable JSON bytes for offchain signing.
     ///
     /// External Solana wallets should sign these bytes when the backend uses
@@ -585,6 +594,22 @@ impl WasmSolanaLedgerTransaction {
     pub fn to_base64(&self) -> String {
         self.inner.to_base64()
     }
+
+    /// Parse the base64 wire format from `toBase64()`.
+    /// @param {string} encoded
+    /// @returns {SolanaLedgerTransaction}
+    #[wasm_bindgen(js_name = fromBase64)]
+    pub fn from_base64(encoded: &str) -> WasmResult<WasmSolanaLedgerTransaction> {
+        Ok(WasmSolanaLedgerTransaction {
+            inner: RustSolanaLedgerTransaction::from_base64(encoded)?,
+        })
+    }
+
+    /// Check the signature; throws if it doesn't match the message.
+    /// @returns {string} The signer's address.
+    pub fn verify(&self) -> WasmResult<String> {
+        Ok(self.inner.verify()?.address())
+    }
 }
 
 // ── WasmSolanaOffchainTransaction ────────────────────────────────────────────
@@ -643,6 +668,22 @@ impl WasmSolanaOffchainTransaction {
     pub fn to_base64(&self) -> WasmResult<String> {
         Ok(self.inner.to_base64()?)
     }
+
+    /// Parse the base64 string from `toBase64()`.
+    /// @param {string} encoded
+    /// @returns {SolanaOffchainTransaction}
+    #[wasm_bindgen(js_name = fromBase64)]
+    pub fn from_base64(encoded: &str) -> WasmResult<WasmSolanaOffchainTransaction> {
+        Ok(WasmSolanaOffchainTransaction {
+            inner: RustSolanaOffchainTransaction::from_base64(encoded)?,
+        })
+    }
+
+    /// Check the signature; throws if it doesn't match the message.
+    /// @returns {string} The signer's address.
+    pub fn verify(&self) -> WasmResult<String> {
+        Ok(self.inner.verify()?.address())
+    }
 }
 
 // ── WasmTransaction (SignedTransaction) ──────────────────────────────────────
@@ -695,6 +736,23 @@ impl WasmTransaction {
     pub fn to_base64(&self) -> WasmResult<String> {
         Ok(RustTransaction::to_base64(&self.inner)?)
     }
+
+    /// Parse the base64 string from `toBase64()`.
+    #[wasm_bindgen(js_name = fromBase64)]
+    pub fn from_base64(encoded: &str) -> WasmResult<WasmTransaction> {
+        Ok(WasmTransaction {
+            inner: RustTransaction::from_base64(encoded)?,
+        })
+    }
+
+    /// Check the signature against the 32-byte chain hash it was signed for
+    /// (e.g. `client.chainHash()`); throws if it doesn't match.
+    pub fn verify(&self, chain_hash: &[u8]) -> WasmResult<()> {
+        let chain_hash: [u8; 32] = chain_hash
+            .try_into()
+            .map_err(|_| format!("expected 32-byte chain hash, got {}", chain_hash.len()))?;
+        Ok(RustTransaction::verify(&self.inner, chain_hash)?)
+    }
 }
 
 // ── Transaction builder ──────────────────────────────────────────────────────
@@ -885,7 +943,7 @@ impl WasmTransactionBuilder {
 
         let max_fee = self.max_fee.map(|f| f as u128);
         let gas_limit = self.gas_limit.map(Gas);
-        let signer_ref = self.signer.as_ref().map(|s| &s.inner);
+        let signer_ref = self.signer.as_ref().map(|s| &s.inner as &dyn Signer);
 
         let signed = RustTransaction::from_runtime_call(
             call.inner,